@@ -66,4 +66,59 @@ pub mod v3 {
             Self {}
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            let mut req = super::Request::new("hunter2".to_owned());
+            let http_req = req
+                .clone()
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({ "new_password": "hunter2" })
+            );
+
+            req.logout_devices = false;
+            let http_req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({ "new_password": "hunter2", "logout_devices": false })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest as _;
+
+            let req = super::Request::try_from_http_request(
+                http::Request::post("http://localhost/_matrix/client/v3/account/password")
+                    .body(br#"{"new_password":"hunter2"}"#.as_slice())
+                    .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+
+            assert_eq!(req.new_password, "hunter2");
+            assert!(req.logout_devices);
+            assert!(req.auth.is_none());
+        }
+    }
 }
@@ -50,4 +50,71 @@ pub mod v1 {
             Self { valid }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+
+            let req = super::Request::new("fBVF/Ds&+".to_owned());
+
+            let http_req = req
+                .clone()
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::None,
+                    &[MatrixVersion::V1_2],
+                )
+                .unwrap();
+            assert_eq!(
+                http_req.uri().path(),
+                "/_matrix/client/v1/register/m.login.registration_token/validity"
+            );
+            assert_eq!(http_req.uri().query(), Some("token=fBVF%2FDs%26%2B"));
+            assert!(!http_req.headers().contains_key(http::header::AUTHORIZATION));
+
+            let http_req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::None,
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                http_req.uri().path(),
+                "/_matrix/client/unstable/org.matrix.msc3231/register/org.matrix.msc3231.login.registration_token/validity"
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest as _;
+
+            let req = super::Request::try_from_http_request(
+                http::Request::get(
+                    "https://homeserver.tld/_matrix/client/v1/register/m.login.registration_token/validity?token=fBVF%2FDs%26%2B",
+                )
+                .body(&[] as &[u8])
+                .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+            assert_eq!(req.token, "fBVF/Ds&+");
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse as _;
+
+            let res = super::Response::try_from_http_response(http::Response::new(
+                br#"{"valid":true}"#.as_slice(),
+            ))
+            .unwrap();
+            assert!(res.valid);
+        }
+    }
 }
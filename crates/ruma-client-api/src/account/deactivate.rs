@@ -68,4 +68,65 @@ pub mod v3 {
             Self { id_server_unbind_result }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            use crate::uiaa::{AuthData, Dummy};
+
+            let req = super::Request::new()
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(from_json_slice::<JsonValue>(req.body()).unwrap(), json!({}));
+
+            let mut dummy = Dummy::new();
+            dummy.session = Some("abcdef".to_owned());
+            let req = super::Request {
+                auth: Some(AuthData::Dummy(dummy)),
+                id_server: Some("identity.example.org".to_owned()),
+                erase: true,
+            }
+            .try_into_http_request::<Vec<u8>>(
+                "https://homeserver.tld",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({
+                    "auth": { "type": "m.login.dummy", "session": "abcdef" },
+                    "id_server": "identity.example.org",
+                    "erase": true,
+                })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest as _;
+
+            let req = super::Request::try_from_http_request(
+                http::Request::post("http://localhost/_matrix/client/v3/account/deactivate")
+                    .body(br#"{"id_server":"identity.example.org"}"#.as_slice())
+                    .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+
+            assert!(req.auth.is_none());
+            assert_eq!(req.id_server.as_deref(), Some("identity.example.org"));
+            assert!(!req.erase);
+        }
+    }
 }
@@ -10,7 +10,7 @@ pub mod v3 {
     use ruma_common::{
         api::{request, response, Metadata},
         metadata,
-        thirdparty::Medium,
+        thirdparty::ThirdPartyId,
     };
 
     use crate::account::ThirdPartyIdRemovalStatus;
@@ -32,11 +32,9 @@ pub mod v3 {
         #[serde(skip_serializing_if = "Option::is_none")]
         pub id_server: Option<String>,
 
-        /// Medium of the 3PID to be removed.
-        pub medium: Medium,
-
-        /// Third-party address being removed.
-        pub address: String,
+        /// The 3PID being removed.
+        #[serde(flatten)]
+        pub threepid: ThirdPartyId,
     }
 
     /// Response type for the `delete_3pid` endpoint.
@@ -47,9 +45,75 @@ pub mod v3 {
     }
 
     impl Request {
-        /// Creates a new `Request` with the given medium and address.
-        pub fn new(medium: Medium, address: String) -> Self {
-            Self { id_server: None, medium, address }
+        /// Creates a new `Request` with the given 3PID.
+        pub fn new(threepid: ThirdPartyId) -> Self {
+            Self { id_server: None, threepid }
+        }
+    }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                thirdparty::{Medium, ThirdPartyId},
+            };
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            let threepid =
+                ThirdPartyId::new(Medium::Email, "Alice@Example.org".to_owned()).unwrap();
+            let mut req = super::Request::new(threepid);
+            req.id_server = Some("identity.example.org".to_owned());
+
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({
+                    "id_server": "identity.example.org",
+                    "medium": "email",
+                    "address": "alice@example.org",
+                })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::{api::IncomingRequest as _, thirdparty::Medium};
+
+            let req = super::Request::try_from_http_request(
+                http::Request::post("http://localhost/_matrix/client/v3/account/3pid/delete")
+                    .body(br#"{"medium":"msisdn","address":"447700900123"}"#.as_slice())
+                    .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+
+            assert_eq!(req.id_server, None);
+            assert_eq!(req.threepid.medium(), Medium::Msisdn);
+            assert_eq!(req.threepid.address(), "447700900123");
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request_invalid_address() {
+            use ruma_common::api::IncomingRequest as _;
+
+            super::Request::try_from_http_request(
+                http::Request::post("http://localhost/_matrix/client/v3/account/3pid/delete")
+                    .body(br#"{"medium":"email","address":"not an email"}"#.as_slice())
+                    .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap_err();
         }
     }
 }
@@ -69,4 +69,33 @@ pub mod v3 {
             Self { access_token, token_type, matrix_server_name, expires_in }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use std::time::Duration;
+
+        use ruma_common::{api::IncomingResponse, authentication::TokenType};
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "access_token": "SomeT0kenHere",
+                "token_type": "Bearer",
+                "matrix_server_name": "example.com",
+                "expires_in": 3600,
+            });
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(res.access_token, "SomeT0kenHere");
+            assert_eq!(res.token_type, TokenType::Bearer);
+            assert_eq!(res.matrix_server_name, "example.com");
+            assert_eq!(res.expires_in, Duration::from_secs(3600));
+        }
+    }
 }
@@ -55,4 +55,34 @@ pub mod v3 {
             Self { user_id, device_id: None, is_guest }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::api::IncomingResponse as _;
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response() {
+            let res = Response::try_from_http_response(http::Response::new(
+                br#"{"user_id":"@alice:example.org","device_id":"ABCDEF","is_guest":true,"org.example.extra":1}"#
+                    .as_slice(),
+            ))
+            .unwrap();
+            assert_eq!(res.user_id, "@alice:example.org");
+            assert_eq!(res.device_id.as_deref().map(|d| d.as_str()), Some("ABCDEF"));
+            assert!(res.is_guest);
+        }
+
+        #[test]
+        fn deserialize_legacy_response() {
+            let res = Response::try_from_http_response(http::Response::new(
+                br#"{"user_id":"@alice:example.org"}"#.as_slice(),
+            ))
+            .unwrap();
+            assert_eq!(res.user_id, "@alice:example.org");
+            assert_eq!(res.device_id, None);
+            assert!(!res.is_guest);
+        }
+    }
 }
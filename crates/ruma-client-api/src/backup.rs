@@ -143,3 +143,76 @@ impl From<EncryptedSessionDataInit> for EncryptedSessionData {
         Self { ephemeral, ciphertext, mac }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert_matches2::assert_matches;
+    use js_int::uint;
+    use ruma_common::{owned_device_key_id, owned_user_id, serde::Base64};
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{BackupAlgorithm, EncryptedSessionDataInit, KeyBackupData, KeyBackupDataInit};
+
+    #[test]
+    fn backup_algorithm_serde() {
+        let json = json!({
+            "algorithm": "m.megolm_backup.v1.curve25519-aes-sha2",
+            "auth_data": {
+                "public_key": "cHVibGljX2tleQ",
+                "signatures": {
+                    "@alice:example.org": {
+                        "ed25519:DEVICE": "signature",
+                    },
+                },
+            },
+        });
+
+        let algorithm = from_json_value::<BackupAlgorithm>(json.clone()).unwrap();
+        assert_matches!(
+            &algorithm,
+            BackupAlgorithm::MegolmBackupV1Curve25519AesSha2 { public_key, signatures }
+        );
+        assert_eq!(public_key.as_bytes(), b"public_key");
+        assert_eq!(
+            signatures[&owned_user_id!("@alice:example.org")]
+                [&owned_device_key_id!("ed25519:DEVICE")],
+            "signature"
+        );
+
+        assert_eq!(to_json_value(&algorithm).unwrap(), json);
+    }
+
+    #[test]
+    fn key_backup_data_serde() {
+        let json = json!({
+            "first_message_index": 1,
+            "forwarded_count": 0,
+            "is_verified": true,
+            "session_data": {
+                "ephemeral": "ZXBoZW1lcmFs",
+                "ciphertext": "Y2lwaGVydGV4dA",
+                "mac": "bWFjX2J5dGVz",
+            },
+        });
+
+        let key_data = from_json_value::<KeyBackupData>(json.clone()).unwrap();
+        assert_eq!(key_data.first_message_index, uint!(1));
+        assert_eq!(key_data.forwarded_count, uint!(0));
+        assert!(key_data.is_verified);
+        assert_eq!(key_data.session_data.ciphertext.as_bytes(), b"ciphertext");
+
+        let key_data: KeyBackupData = KeyBackupDataInit {
+            first_message_index: uint!(1),
+            forwarded_count: uint!(0),
+            is_verified: true,
+            session_data: EncryptedSessionDataInit {
+                ephemeral: Base64::new(b"ephemeral".to_vec()),
+                ciphertext: Base64::new(b"ciphertext".to_vec()),
+                mac: Base64::new(b"mac_bytes".to_vec()),
+            }
+            .into(),
+        }
+        .into();
+        assert_eq!(to_json_value(&key_data).unwrap(), json);
+    }
+}
@@ -55,4 +55,45 @@ pub mod v3 {
             Self { rooms }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{api::IncomingResponse as _, room_id};
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "rooms": {
+                    "!room:example.org": {
+                        "sessions": {
+                            "session_a": {
+                                "first_message_index": 1,
+                                "forwarded_count": 0,
+                                "is_verified": true,
+                                "session_data": {
+                                    "ephemeral": "ZXBoZW1lcmFs",
+                                    "ciphertext": "Y2lwaGVydGV4dA",
+                                    "mac": "bWFjX2J5dGVz",
+                                },
+                            },
+                        },
+                    },
+                },
+            });
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(res.rooms.len(), 1);
+            let room = &res.rooms[room_id!("!room:example.org")];
+            assert_eq!(room.sessions.len(), 1);
+            let key_data = room.sessions["session_a"].deserialize().unwrap();
+            assert!(key_data.is_verified);
+            assert_eq!(key_data.session_data.mac.as_bytes(), b"mac_bytes");
+        }
+    }
 }
@@ -61,4 +61,40 @@ pub mod v3 {
             Self { sessions }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use js_int::uint;
+        use ruma_common::api::IncomingResponse as _;
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "sessions": {
+                    "session_a": {
+                        "first_message_index": 1,
+                        "forwarded_count": 2,
+                        "is_verified": false,
+                        "session_data": {
+                            "ephemeral": "ZXBoZW1lcmFs",
+                            "ciphertext": "Y2lwaGVydGV4dA",
+                            "mac": "bWFjX2J5dGVz",
+                        },
+                    },
+                },
+            });
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(res.sessions.len(), 1);
+            let key_data = res.sessions["session_a"].deserialize().unwrap();
+            assert_eq!(key_data.forwarded_count, uint!(2));
+            assert!(!key_data.is_verified);
+        }
+    }
 }
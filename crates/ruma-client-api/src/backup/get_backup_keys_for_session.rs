@@ -64,4 +64,35 @@ pub mod v3 {
             Self { key_data }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use js_int::uint;
+        use ruma_common::api::IncomingResponse as _;
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "first_message_index": 3,
+                "forwarded_count": 0,
+                "is_verified": true,
+                "session_data": {
+                    "ephemeral": "ZXBoZW1lcmFs",
+                    "ciphertext": "Y2lwaGVydGV4dA",
+                    "mac": "bWFjX2J5dGVz",
+                },
+            });
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            let key_data = res.key_data.deserialize().unwrap();
+            assert_eq!(key_data.first_message_index, uint!(3));
+            assert_eq!(key_data.session_data.ephemeral.as_bytes(), b"ephemeral");
+        }
+    }
 }
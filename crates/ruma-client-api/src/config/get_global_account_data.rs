@@ -13,7 +13,11 @@ pub mod v3 {
         serde::Raw,
         OwnedUserId,
     };
-    use ruma_events::{AnyGlobalAccountDataEventContent, GlobalAccountDataEventType};
+    use ruma_events::{
+        AnyGlobalAccountDataEventContent, GlobalAccountDataEventContent,
+        GlobalAccountDataEventType, StaticEventContent,
+    };
+    use serde::de::DeserializeOwned;
 
     const METADATA: Metadata = metadata! {
         method: GET,
@@ -57,6 +61,15 @@ pub mod v3 {
         pub fn new(user_id: OwnedUserId, event_type: GlobalAccountDataEventType) -> Self {
             Self { user_id, event_type }
         }
+
+        /// Creates a new `Request` with the given user ID, for the event type of the given
+        /// content type.
+        pub fn new_typed<C>(user_id: OwnedUserId) -> Self
+        where
+            C: GlobalAccountDataEventContent + StaticEventContent,
+        {
+            Self::new(user_id, C::TYPE.into())
+        }
     }
 
     impl Response {
@@ -64,5 +77,62 @@ pub mod v3 {
         pub fn new(account_data: Raw<AnyGlobalAccountDataEventContent>) -> Self {
             Self { account_data }
         }
+
+        /// Deserializes the account data as the given content type.
+        ///
+        /// This should be used with the same content type as [`Request::new_typed()`].
+        pub fn deserialize_as<C>(&self) -> serde_json::Result<C>
+        where
+            C: GlobalAccountDataEventContent + StaticEventContent + DeserializeOwned,
+        {
+            self.account_data.deserialize_as()
+        }
+    }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{IncomingResponse, MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_room_id, owned_user_id,
+        };
+        use ruma_events::direct::DirectEventContent;
+
+        use super::{Request, Response};
+
+        #[test]
+        fn serialize_typed_request() {
+            let req =
+                Request::new_typed::<DirectEventContent>(owned_user_id!("@alice:example.org"))
+                    .try_into_http_request::<Vec<u8>>(
+                        "https://matrix.org",
+                        SendAccessToken::IfRequired("tok"),
+                        &[MatrixVersion::V1_1],
+                    )
+                    .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/v3/user/@alice:example.org/account_data/m.direct"
+            );
+        }
+
+        #[test]
+        fn deserialize_typed_response() {
+            let res = Response::try_from_http_response(
+                http::Response::builder()
+                    .body(br#"{"@bob:example.org":["!dm:example.org"]}"#.as_slice())
+                    .unwrap(),
+            )
+            .unwrap();
+            let content = res.deserialize_as::<DirectEventContent>().unwrap();
+            let bob = owned_user_id!("@bob:example.org");
+            assert_eq!(content[&bob], [owned_room_id!("!dm:example.org")]);
+
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(br#"{"tags":{}}"#.as_slice()).unwrap(),
+            )
+            .unwrap();
+            res.deserialize_as::<DirectEventContent>().unwrap_err();
+        }
     }
 }
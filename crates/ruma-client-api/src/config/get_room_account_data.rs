@@ -13,7 +13,11 @@ pub mod v3 {
         serde::Raw,
         OwnedRoomId, OwnedUserId,
     };
-    use ruma_events::{AnyRoomAccountDataEventContent, RoomAccountDataEventType};
+    use ruma_events::{
+        AnyRoomAccountDataEventContent, RoomAccountDataEventContent, RoomAccountDataEventType,
+        StaticEventContent,
+    };
+    use serde::de::DeserializeOwned;
 
     const METADATA: Metadata = metadata! {
         method: GET,
@@ -61,6 +65,15 @@ pub mod v3 {
         ) -> Self {
             Self { user_id, room_id, event_type }
         }
+
+        /// Creates a new `Request` with the given user ID and room ID, for the event type of the
+        /// given content type.
+        pub fn new_typed<C>(user_id: OwnedUserId, room_id: OwnedRoomId) -> Self
+        where
+            C: RoomAccountDataEventContent + StaticEventContent,
+        {
+            Self::new(user_id, room_id, C::TYPE.into())
+        }
     }
 
     impl Response {
@@ -68,5 +81,63 @@ pub mod v3 {
         pub fn new(account_data: Raw<AnyRoomAccountDataEventContent>) -> Self {
             Self { account_data }
         }
+
+        /// Deserializes the account data as the given content type.
+        ///
+        /// This should be used with the same content type as [`Request::new_typed()`].
+        pub fn deserialize_as<C>(&self) -> serde_json::Result<C>
+        where
+            C: RoomAccountDataEventContent + StaticEventContent + DeserializeOwned,
+        {
+            self.account_data.deserialize_as()
+        }
+    }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{IncomingResponse, MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_room_id, owned_user_id,
+        };
+        use ruma_events::tag::TagEventContent;
+
+        use super::{Request, Response};
+
+        #[test]
+        fn serialize_typed_request() {
+            let req = Request::new_typed::<TagEventContent>(
+                owned_user_id!("@alice:example.org"),
+                owned_room_id!("!room:example.org"),
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://matrix.org",
+                SendAccessToken::IfRequired("tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/v3/user/@alice:example.org/rooms/!room:example.org/account_data/m.tag"
+            );
+        }
+
+        #[test]
+        fn deserialize_typed_response() {
+            let res = Response::try_from_http_response(
+                http::Response::builder()
+                    .body(br#"{"tags":{"u.work":{"order":0.5}}}"#.as_slice())
+                    .unwrap(),
+            )
+            .unwrap();
+            let content = res.deserialize_as::<TagEventContent>().unwrap();
+            assert_eq!(content.tags.len(), 1);
+
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(br#"{"tags":[]}"#.as_slice()).unwrap(),
+            )
+            .unwrap();
+            res.deserialize_as::<TagEventContent>().unwrap_err();
+        }
     }
 }
@@ -102,6 +102,12 @@ pub mod v3 {
         pub fn new() -> Self {
             Default::default()
         }
+
+        /// Returns an iterator over `events_before`, `event` and `events_after`, in chronological
+        /// order.
+        pub fn chronological_events(&self) -> impl Iterator<Item = &Raw<AnyTimelineEvent>> {
+            self.events_before.iter().rev().chain(&self.event).chain(&self.events_after)
+        }
     }
 
     fn default_limit() -> UInt {
@@ -112,4 +118,34 @@ pub mod v3 {
     fn is_default_limit(val: &UInt) -> bool {
         *val == default_limit()
     }
+
+    #[cfg(test)]
+    mod tests {
+        use ruma_common::serde::Raw;
+        use ruma_events::AnyTimelineEvent;
+        use serde_json::{json, value::to_raw_value as to_raw_json_value};
+
+        use super::Response;
+
+        fn event(event_id: &str) -> Raw<AnyTimelineEvent> {
+            Raw::from_json(to_raw_json_value(&json!({ "event_id": event_id })).unwrap())
+        }
+
+        #[test]
+        fn chronological_events() {
+            let mut response = Response::new();
+            response.events_before = vec![event("$before_1"), event("$before_2")];
+            response.event = Some(event("$event"));
+            response.events_after = vec![event("$after_1"), event("$after_2")];
+
+            let event_ids = response
+                .chronological_events()
+                .map(|event| event.get_field::<String>("event_id").unwrap().unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(event_ids, ["$before_2", "$before_1", "$event", "$after_1", "$after_2"]);
+
+            let response = Response::new();
+            assert_eq!(response.chronological_events().count(), 0);
+        }
+    }
 }
@@ -86,3 +86,43 @@ impl From<DehydratedDeviceData> for Helper {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert_matches2::assert_matches;
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{DehydratedDeviceData, DehydratedDeviceV1, DeviceDehydrationAlgorithm};
+
+    #[test]
+    fn dehydrated_device_data_serde() {
+        let json = json!({
+            "algorithm": "org.matrix.msc3814.v1.olm",
+            "device_pickle": "encrypted_pickle",
+        });
+
+        let data = from_json_value::<DehydratedDeviceData>(json.clone()).unwrap();
+        assert_eq!(data.algorithm(), DeviceDehydrationAlgorithm::V1);
+        assert_matches!(&data, DehydratedDeviceData::V1(device));
+        assert_eq!(device.device_pickle, "encrypted_pickle");
+        assert_eq!(to_json_value(data).unwrap(), json);
+
+        let data = DehydratedDeviceData::V1(DehydratedDeviceV1::new("other_pickle".to_owned()));
+        assert_eq!(
+            to_json_value(data).unwrap(),
+            json!({
+                "algorithm": "org.matrix.msc3814.v1.olm",
+                "device_pickle": "other_pickle",
+            })
+        );
+    }
+
+    #[test]
+    fn unsupported_algorithm() {
+        from_json_value::<DehydratedDeviceData>(json!({
+            "algorithm": "org.example.unknown",
+            "device_pickle": "encrypted_pickle",
+        }))
+        .unwrap_err();
+    }
+}
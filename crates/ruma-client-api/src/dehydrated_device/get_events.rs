@@ -38,7 +38,7 @@ pub mod unstable {
         pub next_batch: Option<String>,
     }
 
-    /// Request type for the `dehydrated_device/{device_id}/events` endpoint.
+    /// Response type for the `dehydrated_device/{device_id}/events` endpoint.
     #[response(error = crate::Error)]
     pub struct Response {
         /// The batch token to supply in the `since` param of the next `/events` request. Will be
@@ -62,4 +62,65 @@ pub mod unstable {
             Self { next_batch: None, events }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{IncomingResponse as _, MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            owned_device_id,
+        };
+        use ruma_events::AnyToDeviceEvent;
+        use serde_json::{
+            from_slice as from_json_slice, json, to_vec as to_json_vec, Value as JsonValue,
+        };
+
+        use super::{Request, Response};
+
+        #[test]
+        fn serialize_request() {
+            let mut req = Request::new(owned_device_id!("DEHYDRATED"));
+            req.next_batch = Some("token".to_owned());
+
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/unstable/org.matrix.msc3814.v1/dehydrated_device/DEHYDRATED/events"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({ "next_batch": "token" })
+            );
+        }
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "events": [
+                    {
+                        "content": {},
+                        "sender": "@alice:example.org",
+                        "type": "m.dummy",
+                    },
+                ],
+                "next_batch": "next",
+            });
+
+            let res =
+                Response::try_from_http_response(http::Response::new(to_json_vec(&body).unwrap()))
+                    .unwrap();
+
+            assert_eq!(res.next_batch.as_deref(), Some("next"));
+            assert_eq!(res.events.len(), 1);
+            let event = res.events[0].deserialize().unwrap();
+            assert!(matches!(event, AnyToDeviceEvent::Dummy(_)));
+            assert_eq!(event.sender(), "@alice:example.org");
+        }
+    }
 }
@@ -85,4 +85,80 @@ pub mod v3 {
             Default::default()
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use js_int::uint;
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                directory::{Filter, RoomNetwork, RoomTypeFilter},
+                server_name,
+            };
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            let mut filter = Filter::new();
+            filter.generic_search_term = Some("ruma".to_owned());
+            filter.room_types = vec![RoomTypeFilter::Default, RoomTypeFilter::Space];
+
+            let req = super::Request {
+                server: Some(server_name!("remote.tld").to_owned()),
+                limit: Some(uint!(20)),
+                since: Some("next".to_owned()),
+                filter,
+                room_network: RoomNetwork::ThirdParty("irc".to_owned()),
+            }
+            .try_into_http_request::<Vec<u8>>(
+                "https://homeserver.tld",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/publicRooms");
+            assert_eq!(req.uri().query(), Some("server=remote.tld"));
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({
+                    "limit": 20,
+                    "since": "next",
+                    "filter": {
+                        "generic_search_term": "ruma",
+                        "room_types": [null, "m.space"],
+                    },
+                    "third_party_instance_id": "irc",
+                })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use assert_matches2::assert_matches;
+            use ruma_common::{api::IncomingRequest as _, directory::RoomNetwork};
+            use serde_json::{json, to_vec as to_json_vec};
+
+            let body = json!({
+                "filter": { "generic_search_term": "ruma" },
+                "include_all_networks": true,
+            });
+            let req = super::Request::try_from_http_request(
+                http::Request::post(
+                    "http://localhost/_matrix/client/v3/publicRooms?server=remote.tld",
+                )
+                .body(to_json_vec(&body).unwrap())
+                .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+
+            assert_eq!(req.server.as_deref().map(|s| s.as_str()), Some("remote.tld"));
+            assert_eq!(req.filter.generic_search_term.as_deref(), Some("ruma"));
+            assert_matches!(req.room_network, RoomNetwork::All);
+            assert_eq!(req.limit, None);
+            assert_eq!(req.since, None);
+        }
+    }
 }
@@ -52,4 +52,34 @@ pub mod v3 {
             Self { visibility }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse as _;
+
+            use crate::room::Visibility;
+
+            let res = super::Response::try_from_http_response(http::Response::new(
+                br#"{"visibility":"public"}"#.as_slice(),
+            ))
+            .unwrap();
+            assert_eq!(res.visibility, Visibility::Public);
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_response() {
+            use ruma_common::api::OutgoingResponse as _;
+
+            use crate::room::Visibility;
+
+            let res = super::Response::new(Visibility::Private)
+                .try_into_http_response::<Vec<u8>>()
+                .unwrap();
+            assert_eq!(res.body(), br#"{"visibility":"private"}"#);
+        }
+    }
 }
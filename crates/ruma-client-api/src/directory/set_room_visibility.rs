@@ -53,4 +53,51 @@ pub mod v3 {
             Self {}
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_room_id,
+            };
+
+            use crate::room::Visibility;
+
+            let req = super::Request::new(owned_room_id!("!room:localhost"), Visibility::Public)
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(req.method(), http::Method::PUT);
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/directory/list/room/!room:localhost");
+            assert_eq!(req.body(), br#"{"visibility":"public"}"#);
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest as _;
+
+            use crate::room::Visibility;
+
+            let req = super::Request::try_from_http_request(
+                http::Request::put(
+                    "http://localhost/_matrix/client/v3/directory/list/room/!room:localhost",
+                )
+                .body(br#"{"visibility":"private"}"#.as_slice())
+                .unwrap(),
+                &["!room:localhost"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!room:localhost");
+            assert_eq!(req.visibility, Visibility::Private);
+        }
+    }
 }
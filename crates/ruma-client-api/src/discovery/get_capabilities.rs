@@ -297,9 +297,10 @@ mod tests {
     use std::borrow::Cow;
 
     use assert_matches2::assert_matches;
-    use serde_json::json;
+    use ruma_common::RoomVersionId;
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::Capabilities;
+    use super::{Capabilities, RoomVersionStability};
 
     #[test]
     fn capabilities_iter() -> serde_json::Result<()> {
@@ -340,4 +341,45 @@ mod tests {
         assert_matches!(caps_iter.next(), None);
         Ok(())
     }
+
+    #[test]
+    fn capabilities_serde_roundtrip() {
+        let json = json!({
+            "m.change_password": { "enabled": false },
+            "m.room_versions": {
+                "default": "11",
+                "available": {
+                    "10": "stable",
+                    "11": "stable",
+                    "org.example.v12": "unstable",
+                },
+            },
+            "org.example.custom": { "enabled": true, "max": 3 },
+        });
+
+        let mut caps = from_json_value::<Capabilities>(json.clone()).unwrap();
+        assert!(!caps.change_password.enabled);
+        assert!(caps.set_displayname.enabled);
+        assert_eq!(caps.room_versions.default, RoomVersionId::V11);
+        assert_eq!(caps.room_versions.available.len(), 3);
+        assert_eq!(
+            caps.room_versions.available[&RoomVersionId::try_from("org.example.v12").unwrap()],
+            RoomVersionStability::Unstable
+        );
+        assert_eq!(
+            caps.get("org.example.custom").unwrap().into_owned(),
+            json!({ "enabled": true, "max": 3 })
+        );
+        assert_eq!(
+            caps.get("m.change_password").unwrap().into_owned(),
+            json!({ "enabled": false })
+        );
+        assert_eq!(caps.get("org.example.missing"), None);
+
+        assert_eq!(to_json_value(&caps).unwrap(), json);
+
+        caps.set("m.set_displayname", json!({ "enabled": false })).unwrap();
+        assert!(!caps.set_displayname.enabled);
+        caps.set("m.set_avatar_url", json!({ "enabled": "no" })).unwrap_err();
+    }
 }
@@ -612,7 +612,10 @@ impl FromHttpResponseErrorExt for FromHttpResponseError<Error> {
 #[cfg(test)]
 mod tests {
     use assert_matches2::assert_matches;
-    use ruma_common::api::{EndpointError, OutgoingResponse};
+    use ruma_common::{
+        api::{EndpointError, OutgoingResponse},
+        RoomVersionId,
+    };
     use serde_json::{
         from_slice as from_json_slice, from_value as from_json_value, json, Value as JsonValue,
     };
@@ -652,6 +655,129 @@ mod tests {
         assert_eq!(deserialized.message, "Wrong backup version.");
     }
 
+    #[test]
+    fn custom_error_round_trip() {
+        let json = json!({
+            "errcode": "ORG_EXAMPLE_CUSTOM",
+            "error": "Something custom went wrong",
+            "org.example.detail": { "reason": "custom" },
+            "retry_count": 3,
+        });
+        let response = http::Response::builder()
+            .status(http::StatusCode::BAD_REQUEST)
+            .body(serde_json::to_vec(&json).unwrap())
+            .unwrap();
+
+        let error = Error::from_http_response(response);
+        assert_eq!(error.error_kind().unwrap().as_ref(), "ORG_EXAMPLE_CUSTOM");
+
+        let response = error.try_into_http_response::<Vec<u8>>().unwrap();
+        assert_eq!(response.status(), http::StatusCode::BAD_REQUEST);
+        let json_body: JsonValue = from_json_slice(response.body()).unwrap();
+        assert_eq!(json_body, json);
+    }
+
+    #[test]
+    fn limit_exceeded_round_trip() {
+        let response = http::Response::builder()
+            .status(http::StatusCode::TOO_MANY_REQUESTS)
+            .body(
+                serde_json::to_vec(&json!({
+                    "errcode": "M_LIMIT_EXCEEDED",
+                    "error": "Too many requests",
+                    "retry_after_ms": 2000,
+                }))
+                .unwrap(),
+            )
+            .unwrap();
+
+        let error = Error::from_http_response(response);
+        assert_matches!(
+            error.error_kind(),
+            Some(ErrorKind::LimitExceeded { retry_after: Some(retry_after) })
+        );
+        assert_eq!(*retry_after, RetryAfter::Delay(Duration::from_secs(2)));
+
+        let response = error.try_into_http_response::<Vec<u8>>().unwrap();
+        assert_eq!(response.headers().get(http::header::RETRY_AFTER).unwrap(), "2");
+        let json_body: JsonValue = from_json_slice(response.body()).unwrap();
+        assert_eq!(
+            json_body,
+            json!({
+                "errcode": "M_LIMIT_EXCEEDED",
+                "error": "Too many requests",
+                "retry_after_ms": 2000,
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_room_version_errors() {
+        let deserialized: StandardErrorBody = from_json_value(json!({
+            "errcode": "M_UNSUPPORTED_ROOM_VERSION",
+            "error": "Your homeserver does not support the features required to join this room",
+        }))
+        .unwrap();
+        assert_eq!(deserialized.kind, ErrorKind::UnsupportedRoomVersion);
+
+        let deserialized: StandardErrorBody = from_json_value(json!({
+            "errcode": "M_INCOMPATIBLE_ROOM_VERSION",
+            "error": "Your homeserver does not support the features required to join this room",
+            "room_version": "11",
+        }))
+        .unwrap();
+        assert_eq!(
+            deserialized.kind,
+            ErrorKind::IncompatibleRoomVersion { room_version: RoomVersionId::V11 }
+        );
+    }
+
+    #[test]
+    fn serialize_incompatible_room_version() {
+        let error = Error::new(
+            http::StatusCode::BAD_REQUEST,
+            ErrorBody::Standard {
+                kind: ErrorKind::IncompatibleRoomVersion { room_version: RoomVersionId::V10 },
+                message: "Incompatible room version".to_owned(),
+            },
+        );
+
+        let response = error.try_into_http_response::<Vec<u8>>().unwrap();
+        let json_body: JsonValue = from_json_slice(response.body()).unwrap();
+        assert_eq!(
+            json_body,
+            json!({
+                "errcode": "M_INCOMPATIBLE_ROOM_VERSION",
+                "error": "Incompatible room version",
+                "room_version": "10",
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_resource_limit_exceeded() {
+        let deserialized: StandardErrorBody = from_json_value(json!({
+            "errcode": "M_RESOURCE_LIMIT_EXCEEDED",
+            "error": "Monthly Active User limit exceeded",
+            "admin_contact": "mailto:admin@example.org",
+            "limit_type": "monthly_active_user",
+        }))
+        .unwrap();
+        assert_eq!(
+            deserialized.kind,
+            ErrorKind::ResourceLimitExceeded {
+                admin_contact: "mailto:admin@example.org".to_owned()
+            }
+        );
+
+        let deserialized: StandardErrorBody = from_json_value(json!({
+            "errcode": "M_CANNOT_LEAVE_SERVER_NOTICE_ROOM",
+            "error": "You cannot leave this room",
+        }))
+        .unwrap();
+        assert_eq!(deserialized.kind, ErrorKind::CannotLeaveServerNoticeRoom);
+    }
+
     #[cfg(feature = "unstable-msc2967")]
     #[test]
     fn custom_authenticate_error_sanity() {
@@ -927,4 +1053,62 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn deserialize_unknown_token() {
+        let deserialized: StandardErrorBody = from_json_value(json!({
+            "errcode": "M_UNKNOWN_TOKEN",
+            "error": "Access token has expired",
+            "soft_logout": true,
+        }))
+        .unwrap();
+        assert_eq!(deserialized.kind, ErrorKind::UnknownToken { soft_logout: true });
+
+        let deserialized: StandardErrorBody = from_json_value(json!({
+            "errcode": "M_UNKNOWN_TOKEN",
+            "error": "Invalid access token",
+        }))
+        .unwrap();
+        assert_eq!(deserialized.kind, ErrorKind::UnknownToken { soft_logout: false });
+    }
+
+    #[test]
+    fn serialize_unknown_token() {
+        let error = Error::new(
+            http::StatusCode::UNAUTHORIZED,
+            ErrorBody::Standard {
+                kind: ErrorKind::UnknownToken { soft_logout: true },
+                message: "Access token has expired".to_owned(),
+            },
+        );
+
+        let response = error.try_into_http_response::<Vec<u8>>().unwrap();
+        let json_body: JsonValue = from_json_slice(response.body()).unwrap();
+        assert_eq!(
+            json_body,
+            json!({
+                "errcode": "M_UNKNOWN_TOKEN",
+                "error": "Access token has expired",
+                "soft_logout": true,
+            })
+        );
+
+        let error = Error::new(
+            http::StatusCode::UNAUTHORIZED,
+            ErrorBody::Standard {
+                kind: ErrorKind::UnknownToken { soft_logout: false },
+                message: "Invalid access token".to_owned(),
+            },
+        );
+
+        let response = error.try_into_http_response::<Vec<u8>>().unwrap();
+        let json_body: JsonValue = from_json_slice(response.body()).unwrap();
+        assert_eq!(
+            json_body,
+            json!({
+                "errcode": "M_UNKNOWN_TOKEN",
+                "error": "Invalid access token",
+            })
+        );
+    }
 }
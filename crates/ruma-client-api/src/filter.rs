@@ -8,6 +8,8 @@ mod url;
 
 use js_int::UInt;
 use ruma_common::{serde::StringEnum, OwnedRoomId, OwnedUserId};
+#[cfg(feature = "unstable-msc3440")]
+use ruma_events::relation::RelationType;
 use serde::{Deserialize, Serialize};
 
 pub use self::{lazy_load::LazyLoadOptions, url::UrlFilter};
@@ -101,6 +103,26 @@ pub struct RoomEventFilter {
     /// [`sync_events`]: crate::sync::sync_events
     #[serde(default, skip_serializing_if = "ruma_common::serde::is_default")]
     pub unread_thread_notifications: bool,
+
+    /// A list of relation types to include, as defined in [MSC3440].
+    ///
+    /// An event A is included in the filter only if there exists another event B which relates
+    /// to A with a `rel_type` which is defined in the list.
+    ///
+    /// [MSC3440]: https://github.com/matrix-org/matrix-spec-proposals/pull/3440
+    #[cfg(feature = "unstable-msc3440")]
+    #[serde(alias = "io.element.relation_types", skip_serializing_if = "Option::is_none")]
+    pub related_by_rel_types: Option<Vec<RelationType>>,
+
+    /// A list of senders to include, as defined in [MSC3440].
+    ///
+    /// An event A is included in the filter only if there exists another event B which relates
+    /// to A, and which has a sender which is in the list.
+    ///
+    /// [MSC3440]: https://github.com/matrix-org/matrix-spec-proposals/pull/3440
+    #[cfg(feature = "unstable-msc3440")]
+    #[serde(alias = "io.element.relation_senders", skip_serializing_if = "Option::is_none")]
+    pub related_by_senders: Option<Vec<OwnedUserId>>,
 }
 
 impl RoomEventFilter {
@@ -140,6 +162,17 @@ impl RoomEventFilter {
             && self.url_filter.is_none()
             && self.lazy_load_options.is_disabled()
             && !self.unread_thread_notifications
+            && self.has_no_relation_filters()
+    }
+
+    #[cfg(feature = "unstable-msc3440")]
+    fn has_no_relation_filters(&self) -> bool {
+        self.related_by_rel_types.is_none() && self.related_by_senders.is_none()
+    }
+
+    #[cfg(not(feature = "unstable-msc3440"))]
+    fn has_no_relation_filters(&self) -> bool {
+        true
     }
 }
 
@@ -434,4 +467,33 @@ mod tests {
             LazyLoadOptions::Enabled { include_redundant_members: false }
         );
     }
+
+    #[test]
+    #[cfg(feature = "unstable-msc3440")]
+    fn related_by_filters_serde() {
+        use ruma_common::owned_user_id;
+        use ruma_events::relation::RelationType;
+
+        let filter = RoomEventFilter {
+            related_by_rel_types: Some(vec![RelationType::Thread]),
+            related_by_senders: Some(vec![owned_user_id!("@alice:example.org")]),
+            ..Default::default()
+        };
+        assert!(!filter.is_empty());
+        assert_eq!(
+            to_json_value(&filter).unwrap(),
+            json!({
+                "related_by_rel_types": ["m.thread"],
+                "related_by_senders": ["@alice:example.org"],
+            })
+        );
+
+        let filter = from_json_value::<RoomEventFilter>(json!({
+            "io.element.relation_types": ["m.thread"],
+            "io.element.relation_senders": ["@alice:example.org"],
+        }))
+        .unwrap();
+        assert_eq!(filter.related_by_rel_types, Some(vec![RelationType::Thread]));
+        assert_eq!(filter.related_by_senders, Some(vec![owned_user_id!("@alice:example.org")]));
+    }
 }
@@ -70,3 +70,102 @@ impl Response {
 
 /// The one-time keys for a given device.
 pub type OneTimeKeys = BTreeMap<OwnedDeviceId, BTreeMap<OwnedDeviceKeyId, Raw<OneTimeKey>>>;
+
+#[cfg(all(test, any(feature = "client", feature = "server")))]
+mod tests {
+    #[cfg(feature = "client")]
+    #[test]
+    fn serialize_request() {
+        use std::collections::BTreeMap;
+
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            owned_device_id, owned_user_id, DeviceKeyAlgorithm,
+        };
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        let req = super::Request::new(BTreeMap::from([(
+            owned_user_id!("@alice:example.org"),
+            BTreeMap::from([(
+                owned_device_id!("JLAFKJWSCS"),
+                DeviceKeyAlgorithm::SignedCurve25519,
+            )]),
+        )]))
+        .try_into_http_request::<Vec<u8>>(
+            "https://homeserver.tld",
+            SendAccessToken::IfRequired("auth_tok"),
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+
+        assert_eq!(
+            from_json_slice::<JsonValue>(req.body()).unwrap(),
+            json!({
+                "timeout": 10_000,
+                "one_time_keys": {
+                    "@alice:example.org": { "JLAFKJWSCS": "signed_curve25519" },
+                },
+            })
+        );
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn deserialize_response() {
+        use assert_matches2::assert_matches;
+        use ruma_common::{api::IncomingResponse as _, encryption::OneTimeKey};
+        use serde_json::{json, to_vec as to_json_vec};
+
+        let body = json!({
+            "failures": {
+                "remote.example.org": { "errcode": "M_UNKNOWN" },
+            },
+            "one_time_keys": {
+                "@alice:example.org": {
+                    "JLAFKJWSCS": {
+                        "signed_curve25519:AAAAHg": {
+                            "key": "zKbLg+NrIjpnagy+pIY6uPL4ZwEG2v+8F9lmgsnlZzs",
+                            "signatures": {
+                                "@alice:example.org": {
+                                    "ed25519:JLAFKJWSCS": "FLWxXqGbwrb8SM3Y795eB6OA8bwBcoMZFXBqnTn58AYWZSqiD45tlBVcDa2L7RwdKXebW/VzDlnfVJ+9jok1Bw",
+                                },
+                            },
+                        },
+                        "curve25519:AAAAHQ": "ETZ5U7AA2nPFvCu5fS4GJQRlV9h6TJTJ/9kgmPC2RRA",
+                    },
+                },
+            },
+        });
+
+        let res = super::Response::try_from_http_response(http::Response::new(
+            to_json_vec(&body).unwrap(),
+        ))
+        .unwrap();
+
+        assert_eq!(res.failures.len(), 1);
+        assert!(res.failures.contains_key("remote.example.org"));
+
+        let keys = &res.one_time_keys["@alice:example.org"]["JLAFKJWSCS"];
+        assert_eq!(keys.len(), 2);
+
+        let signed_key = keys
+            .iter()
+            .find(|(id, _)| id.as_str() == "signed_curve25519:AAAAHg")
+            .unwrap()
+            .1
+            .deserialize()
+            .unwrap();
+        assert_matches!(signed_key, OneTimeKey::SignedKey(signed_key));
+        assert_eq!(signed_key.key.encode(), "zKbLg+NrIjpnagy+pIY6uPL4ZwEG2v+8F9lmgsnlZzs");
+
+        let key = keys
+            .iter()
+            .find(|(id, _)| id.as_str() == "curve25519:AAAAHQ")
+            .unwrap()
+            .1
+            .deserialize()
+            .unwrap();
+        assert_matches!(key, OneTimeKey::Key(key));
+        assert_eq!(key, "ETZ5U7AA2nPFvCu5fS4GJQRlV9h6TJTJ/9kgmPC2RRA");
+    }
+}
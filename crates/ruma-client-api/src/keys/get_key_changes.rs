@@ -63,4 +63,40 @@ pub mod v3 {
             Self { changed, left }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+
+            let req =
+                super::Request::new("s72594_4483_1934".to_owned(), "s75689_5632_2435".to_owned())
+                    .try_into_http_request::<Vec<u8>>(
+                        "https://homeserver.tld",
+                        SendAccessToken::IfRequired("auth_tok"),
+                        &[MatrixVersion::V1_1],
+                    )
+                    .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/keys/changes");
+            assert_eq!(req.uri().query(), Some("from=s72594_4483_1934&to=s75689_5632_2435"));
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse as _;
+
+            let res = super::Response::try_from_http_response(http::Response::new(
+                br#"{"changed":["@alice:example.org","@bob:example.org"],"left":["@clearly:example.org"]}"#
+                    .as_slice(),
+            ))
+            .unwrap();
+
+            assert_eq!(res.changed, ["@alice:example.org", "@bob:example.org"]);
+            assert_eq!(res.left, ["@clearly:example.org"]);
+        }
+    }
 }
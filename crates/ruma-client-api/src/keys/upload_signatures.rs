@@ -96,13 +96,21 @@ pub mod v3 {
 
     /// A failure to process a signed key.
     #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
     pub struct Failure {
         /// Machine-readable error code.
-        errcode: FailureErrorCode,
+        pub errcode: FailureErrorCode,
 
         /// Human-readable error message.
         #[cfg_attr(feature = "compat-upload-signatures", serde(alias = "message"))]
-        error: String,
+        pub error: String,
+    }
+
+    impl Failure {
+        /// Creates a new `Failure` with the given error code and message.
+        pub fn new(errcode: FailureErrorCode, error: String) -> Self {
+            Self { errcode, error }
+        }
     }
 
     /// Error code for signed key processing failures.
@@ -147,6 +155,29 @@ pub mod v3 {
             assert_eq!(failure.error, "400: Invalid signature");
         }
 
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::user_id;
+
+            use super::FailureErrorCode;
+
+            const JSON: &str = r#"{
+                "failures": {
+                    "@alice:example.org": {
+                        "HIJKLMN": {
+                            "errcode": "M_INVALID_SIGNATURE",
+                            "error": "Invalid signature"
+                        }
+                    }
+                }
+            }"#;
+
+            let parsed: ResponseBody = serde_json::from_str(JSON).unwrap();
+            let failure = &parsed.failures[user_id!("@alice:example.org")]["HIJKLMN"];
+            assert_eq!(failure.errcode, FailureErrorCode::InvalidSignature);
+            assert_eq!(failure.error, "Invalid signature");
+        }
+
         #[test]
         fn deserialize_empty_response() {
             const JSON: &str = r#"{}"#;
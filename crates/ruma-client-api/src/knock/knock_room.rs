@@ -181,7 +181,7 @@ pub mod v3 {
     mod tests {
         use ruma_common::{
             api::{IncomingRequest as _, MatrixVersion, OutgoingRequest, SendAccessToken},
-            owned_room_id, owned_server_name,
+            owned_room_alias_id, owned_room_id, owned_server_name,
         };
 
         use super::Request;
@@ -201,6 +201,39 @@ pub mod v3 {
             assert_eq!(req.uri().query(), Some("via=f.oo&server_name=f.oo"));
         }
 
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request_with_alias() {
+            let req = Request::new(owned_room_alias_id!("#foo:b.ar").into())
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/knock/%23foo:b.ar");
+            assert_eq!(req.uri().query(), None);
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request_with_alias() {
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method(http::Method::POST)
+                    .uri("https://matrix.org/_matrix/client/v3/knock/%23foo:b.ar?via=f.oo")
+                    .body(b"{}" as &[u8])
+                    .unwrap(),
+                &["#foo:b.ar"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id_or_alias, "#foo:b.ar");
+            assert!(req.room_id_or_alias.is_room_alias_id());
+            assert_eq!(req.reason, None);
+            assert_eq!(req.via, vec![owned_server_name!("f.oo")]);
+        }
+
         #[cfg(feature = "server")]
         #[test]
         fn deserialize_request_wrong_method() {
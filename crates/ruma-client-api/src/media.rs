@@ -8,3 +8,110 @@ pub mod get_content_as_filename;
 pub mod get_content_thumbnail;
 pub mod get_media_config;
 pub mod get_media_preview;
+
+use std::fmt;
+
+use serde::{
+    de::{DeserializeOwned, IgnoredAny, MapAccess, Visitor},
+    Deserializer,
+};
+use serde_json::value::RawValue as RawJsonValue;
+
+/// Looks up the field with the given key in the OpenGraph-like data of a URL preview and
+/// deserializes its value.
+///
+/// Only the value of the requested field is deserialized, so an invalid value in another field
+/// doesn't prevent the lookup. Returns `None` if the data is not an object, or if the field is
+/// missing or has an unexpected type.
+pub(crate) fn preview_field<T: DeserializeOwned>(
+    data: Option<&RawJsonValue>,
+    key: &str,
+) -> Option<T> {
+    struct FieldVisitor<'k>(&'k str);
+
+    impl<'de> Visitor<'de> for FieldVisitor<'_> {
+        type Value = Option<&'de RawJsonValue>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("a JSON object")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut value = None;
+
+            while let Some(key) = map.next_key::<String>()? {
+                if value.is_none() && key == self.0 {
+                    value = Some(map.next_value()?);
+                } else {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+
+            Ok(value)
+        }
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_str(data?.get());
+    let value = deserializer.deserialize_map(FieldVisitor(key)).ok()??;
+    serde_json::from_str(value.get()).ok()
+}
+
+#[cfg(all(test, any(feature = "client", feature = "server")))]
+mod tests {
+    use js_int::uint;
+    use ruma_common::{owned_mxc_uri, OwnedMxcUri};
+    use serde_json::json;
+
+    use super::get_media_preview;
+    use crate::authenticated_media;
+
+    #[test]
+    fn preview_image() {
+        let data = json!({
+            "og:title": "Matrix",
+            "og:image": "mxc://example.org/ascERGshawAWawugaAcauga",
+            "matrix:image:size": 102_400,
+        });
+        let image = Some(owned_mxc_uri!("mxc://example.org/ascERGshawAWawugaAcauga"));
+
+        let response = get_media_preview::v3::Response::from_serialize(&data).unwrap();
+        assert_eq!(response.image(), image);
+        assert_eq!(response.image_size(), Some(uint!(102_400)));
+
+        let response =
+            authenticated_media::get_media_preview::v1::Response::from_serialize(&data).unwrap();
+        assert_eq!(response.image(), image);
+        assert_eq!(response.image_size(), Some(uint!(102_400)));
+    }
+
+    #[test]
+    fn preview_image_invalid_size() {
+        let data = json!({
+            "og:image": "mxc://example.org/ascERGshawAWawugaAcauga",
+            "matrix:image:size": "large",
+        });
+
+        let response = get_media_preview::v3::Response::from_serialize(&data).unwrap();
+        assert_eq!(
+            response.image(),
+            Some(owned_mxc_uri!("mxc://example.org/ascERGshawAWawugaAcauga"))
+        );
+        assert_eq!(response.image_size(), None);
+    }
+
+    #[test]
+    fn preview_image_missing() {
+        let response =
+            get_media_preview::v3::Response::from_serialize(&json!({ "og:title": "Matrix" }))
+                .unwrap();
+        assert_eq!(response.image(), None::<OwnedMxcUri>);
+        assert_eq!(response.image_size(), None);
+
+        let response =
+            get_media_preview::v3::Response::from_serialize(&json!(["og:image"])).unwrap();
+        assert_eq!(response.image(), None);
+
+        assert_eq!(get_media_preview::v3::Response::new().image(), None);
+        assert_eq!(authenticated_media::get_media_preview::v1::Response::new().image(), None);
+    }
+}
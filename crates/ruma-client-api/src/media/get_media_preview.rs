@@ -7,13 +7,16 @@ pub mod v3 {
     //!
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#get_matrixmediav3preview_url
 
+    use js_int::UInt;
     use ruma_common::{
         api::{request, response, Metadata},
-        metadata, MilliSecondsSinceUnixEpoch,
+        metadata, MilliSecondsSinceUnixEpoch, OwnedMxcUri,
     };
     use serde::Serialize;
     use serde_json::value::{to_raw_value as to_raw_json_value, RawValue as RawJsonValue};
 
+    use crate::media::preview_field;
+
     const METADATA: Metadata = metadata! {
         method: GET,
         rate_limited: true,
@@ -79,6 +82,17 @@ pub mod v3 {
         pub fn from_serialize<T: Serialize>(data: &T) -> serde_json::Result<Self> {
             Ok(Self { data: Some(to_raw_json_value(data)?) })
         }
+
+        /// The MXC URI of the preview image, from the `og:image` field of the data, if any.
+        pub fn image(&self) -> Option<OwnedMxcUri> {
+            preview_field(self.data.as_deref(), "og:image")
+        }
+
+        /// The size in bytes of the preview image, from the `matrix:image:size` field of the data,
+        /// if any.
+        pub fn image_size(&self) -> Option<UInt> {
+            preview_field(self.data.as_deref(), "matrix:image:size")
+        }
     }
 
     #[cfg(test)]
@@ -102,14 +102,39 @@ pub mod v3 {
         _Custom(PrivOwnedStr),
     }
 
-    #[cfg(all(test, feature = "server"))]
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
     mod tests {
-        use ruma_common::api::IncomingRequest as _;
-
         use super::{MembershipEventFilter, Request};
 
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request_with_sync_token() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_room_id,
+            };
+
+            let mut req = Request::new(owned_room_id!("!dummy:example.org"));
+            req.at = Some("s72594_4483_1934/AB+cd=".to_owned());
+            req.membership = Some(MembershipEventFilter::Join);
+
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/rooms/!dummy:example.org/members");
+            assert_eq!(req.uri().query(), Some("at=s72594_4483_1934%2FAB%2Bcd%3D&membership=join"));
+        }
+
+        #[cfg(feature = "server")]
         #[test]
         fn deserialization() {
+            use ruma_common::api::IncomingRequest as _;
+
             let uri = http::Uri::builder()
                 .scheme("https")
                 .authority("example.org")
@@ -132,5 +157,26 @@ pub mod v3 {
             assert_eq!(req.membership, None);
             assert_eq!(req.not_membership, Some(MembershipEventFilter::Leave));
         }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request_with_sync_token() {
+            use ruma_common::api::IncomingRequest as _;
+
+            let req = Request::try_from_http_request(
+                http::Request::get(
+                    "https://example.org/_matrix/client/v3/rooms/!dummy%3Aexample.org/members\
+                     ?at=s72594_4483_1934%2FAB%2Bcd%3D&membership=invite",
+                )
+                .body(&[] as &[u8])
+                .unwrap(),
+                &["!dummy:example.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.at.as_deref(), Some("s72594_4483_1934/AB+cd="));
+            assert_eq!(req.membership, Some(MembershipEventFilter::Invite));
+            assert_eq!(req.not_membership, None);
+        }
     }
 }
@@ -48,4 +48,20 @@ pub mod v3 {
             Self { joined_rooms }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::api::IncomingResponse as _;
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response() {
+            let res = Response::try_from_http_response(http::Response::new(
+                br#"{"joined_rooms":["!foo:example.org","!bar:example.org"]}"#.as_slice(),
+            ))
+            .unwrap();
+            assert_eq!(res.joined_rooms, ["!foo:example.org", "!bar:example.org"]);
+        }
+    }
 }
@@ -258,4 +258,102 @@ pub mod v3 {
             }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+                push::{Action, NewPatternedPushRule, NewPushRule},
+            };
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            use super::Request;
+            use crate::push::RuleScope;
+
+            let rule = NewPushRule::Content(NewPatternedPushRule::new(
+                "cake".to_owned(),
+                "cake*lie".to_owned(),
+                vec![Action::Notify],
+            ));
+            let mut req = Request::new(RuleScope::Global, rule);
+            req.before = Some("nocake".to_owned());
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/pushrules/global/content/cake");
+            assert_eq!(req.uri().query(), Some("before=nocake"));
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({
+                    "actions": ["notify"],
+                    "pattern": "cake*lie",
+                })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use assert_matches2::assert_matches;
+            use ruma_common::{
+                api::IncomingRequest as _,
+                push::{Action, NewPushRule, PushCondition},
+            };
+            use serde_json::{json, to_vec as to_json_vec};
+
+            use super::Request;
+            use crate::push::RuleScope;
+
+            let body = json!({
+                "actions": ["notify"],
+                "conditions": [
+                    {
+                        "kind": "event_match",
+                        "key": "type",
+                        "pattern": "m.room.message",
+                    },
+                ],
+            });
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method(http::Method::PUT)
+                    .uri("https://matrix.org/_matrix/client/v3/pushrules/global/override/messages?after=first")
+                    .body(to_json_vec(&body).unwrap())
+                    .unwrap(),
+                &["global", "override", "messages"],
+            )
+            .unwrap();
+
+            assert_eq!(req.scope, RuleScope::Global);
+            assert_eq!(req.before, None);
+            assert_eq!(req.after.as_deref(), Some("first"));
+            assert_matches!(req.rule, NewPushRule::Override(rule));
+            assert_eq!(rule.rule_id, "messages");
+            assert_matches!(rule.actions.as_slice(), [Action::Notify]);
+            assert_matches!(
+                rule.conditions.as_slice(),
+                [PushCondition::EventMatch { key, pattern }]
+            );
+            assert_eq!(key, "type");
+            assert_eq!(pattern, "m.room.message");
+
+            Request::try_from_http_request(
+                http::Request::builder()
+                    .method(http::Method::PUT)
+                    .uri("https://matrix.org/_matrix/client/v3/pushrules/global/unknown/messages")
+                    .body(to_json_vec(&body).unwrap())
+                    .unwrap(),
+                &["global", "unknown", "messages"],
+            )
+            .unwrap_err();
+        }
+    }
 }
@@ -85,4 +85,68 @@ pub mod v3 {
             Self {}
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+                owned_event_id, owned_room_id,
+            };
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            use super::Request;
+
+            let mut req = Request::new(owned_room_id!("!room:example.org"));
+            req.fully_read = Some(owned_event_id!("$fully_read"));
+            req.private_read_receipt = Some(owned_event_id!("$private"));
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({
+                    "m.fully_read": "$fully_read",
+                    "m.read.private": "$private",
+                })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest as _;
+            use serde_json::{json, to_vec as to_json_vec};
+
+            use super::Request;
+
+            let body = json!({
+                "m.fully_read": "$fully_read",
+                "m.read": "$read",
+            });
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method(http::Method::POST)
+                    .uri(
+                        "https://matrix.org/_matrix/client/v3/rooms/!room:example.org/read_markers",
+                    )
+                    .body(to_json_vec(&body).unwrap())
+                    .unwrap(),
+                &["!room:example.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!room:example.org");
+            assert_eq!(req.fully_read.unwrap(), "$fully_read");
+            assert_eq!(req.read_receipt.unwrap(), "$read");
+            assert_eq!(req.private_read_receipt, None);
+        }
+    }
 }
@@ -119,4 +119,42 @@ pub mod v3 {
         #[doc(hidden)]
         _Custom(PrivOwnedStr),
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_event_id, owned_room_id,
+        };
+        use ruma_events::receipt::ReceiptThread;
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::{ReceiptType, Request};
+
+        #[test]
+        fn serialize_threaded_private_receipt() {
+            let mut req = Request::new(
+                owned_room_id!("!room:example.org"),
+                ReceiptType::ReadPrivate,
+                owned_event_id!("$event"),
+            );
+            req.thread = ReceiptThread::Main;
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/v3/rooms/!room:example.org/receipt/m.read.private/$event"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({ "thread_id": "main" })
+            );
+        }
+    }
 }
@@ -165,4 +165,118 @@ pub mod v1 {
             Self { chunk, next_batch: None, prev_batch: None, recursion_depth: None }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use js_int::uint;
+            use ruma_common::{
+                api::{Direction, MatrixVersion, OutgoingRequest, SendAccessToken},
+                owned_event_id, owned_room_id,
+            };
+            use ruma_events::{relation::RelationType, TimelineEventType};
+
+            use super::Request;
+
+            let mut req = Request::new(
+                owned_room_id!("!room:example.org"),
+                owned_event_id!("$parent"),
+                RelationType::Thread,
+                TimelineEventType::RoomMessage,
+            );
+            req.from = Some("opaque_token".to_owned());
+            req.dir = Direction::Forward;
+            req.limit = Some(uint!(5));
+            req.recurse = true;
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_3],
+                )
+                .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/v1/rooms/!room:example.org/relations/$parent/m.thread/m.room.message"
+            );
+            assert_eq!(req.uri().query(), Some("from=opaque_token&dir=f&limit=5&recurse=true"));
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::{Direction, IncomingRequest as _};
+            use ruma_events::{relation::RelationType, TimelineEventType};
+
+            use super::Request;
+
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .uri(
+                        "https://matrix.org/_matrix/client/v1/rooms/!room:example.org\
+                         /relations/$parent/m.annotation/m.reaction?to=opaque_token",
+                    )
+                    .body(&[] as &[u8])
+                    .unwrap(),
+                &["!room:example.org", "$parent", "m.annotation", "m.reaction"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!room:example.org");
+            assert_eq!(req.event_id, "$parent");
+            assert_eq!(req.rel_type, RelationType::Annotation);
+            assert_eq!(req.event_type, TimelineEventType::Reaction);
+            assert_eq!(req.to.as_deref(), Some("opaque_token"));
+            assert_eq!(req.dir, Direction::Backward);
+            assert!(!req.recurse);
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use js_int::uint;
+            use ruma_common::api::IncomingResponse as _;
+            use serde_json::{json, to_vec as to_json_vec};
+
+            use super::Response;
+
+            let body = json!({
+                "chunk": [
+                    {
+                        "content": {
+                            "body": "In thread",
+                            "msgtype": "m.text",
+                            "m.relates_to": {
+                                "rel_type": "m.thread",
+                                "event_id": "$parent",
+                            },
+                        },
+                        "event_id": "$child",
+                        "origin_server_ts": 1,
+                        "room_id": "!room:example.org",
+                        "sender": "@alice:example.org",
+                        "type": "m.room.message",
+                    },
+                ],
+                "next_batch": "next",
+                "recursion_depth": 1,
+            });
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(res.chunk.len(), 1);
+            assert_eq!(
+                res.chunk[0].get_field::<String>("event_id").unwrap().as_deref(),
+                Some("$child")
+            );
+            assert_eq!(res.next_batch.as_deref(), Some("next"));
+            assert_eq!(res.prev_batch, None);
+            assert_eq!(res.recursion_depth, Some(uint!(1)));
+        }
+    }
 }
@@ -9,8 +9,9 @@ pub mod v3 {
 
     use ruma_common::{
         api::{request, response, Metadata},
-        metadata, OwnedRoomAliasId, OwnedRoomId,
+        metadata, OwnedRoomAliasId, OwnedRoomId, RoomAliasId,
     };
+    use ruma_events::room::canonical_alias::RoomCanonicalAliasEventContent;
 
     const METADATA: Metadata = metadata! {
         method: GET,
@@ -50,5 +51,78 @@ pub mod v3 {
         pub fn new(aliases: Vec<OwnedRoomAliasId>) -> Self {
             Self { aliases }
         }
+
+        /// Sorts the local aliases according to the given `m.room.canonical_alias` content.
+        ///
+        /// Aliases that appear in the content but are not local aliases of the room are ignored.
+        pub fn classify(
+            &self,
+            canonical_alias: &RoomCanonicalAliasEventContent,
+        ) -> LocalAliases<'_> {
+            let mut local_aliases = LocalAliases::default();
+
+            for alias in &self.aliases {
+                if canonical_alias.alias.as_ref() == Some(alias) {
+                    local_aliases.canonical_alias = Some(alias);
+                } else if canonical_alias.alt_aliases.contains(alias) {
+                    local_aliases.alt_aliases.push(alias);
+                } else {
+                    local_aliases.unpublished.push(alias);
+                }
+            }
+
+            local_aliases
+        }
+    }
+
+    /// The local aliases of a room, sorted according to the `m.room.canonical_alias` state of the
+    /// room.
+    ///
+    /// To construct this type, use [`Response::classify()`].
+    #[derive(Clone, Debug, Default)]
+    #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+    pub struct LocalAliases<'a> {
+        /// The local alias that is the canonical alias of the room, if any.
+        pub canonical_alias: Option<&'a RoomAliasId>,
+
+        /// The local aliases that are alternative aliases of the room.
+        pub alt_aliases: Vec<&'a RoomAliasId>,
+
+        /// The local aliases that are not published in the `m.room.canonical_alias` state.
+        pub unpublished: Vec<&'a RoomAliasId>,
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use ruma_common::{owned_room_alias_id, room_alias_id};
+        use ruma_events::room::canonical_alias::RoomCanonicalAliasEventContent;
+
+        use super::Response;
+
+        #[test]
+        fn classify_aliases() {
+            let response = Response::new(vec![
+                owned_room_alias_id!("#main:example.org"),
+                owned_room_alias_id!("#alt:example.org"),
+                owned_room_alias_id!("#hidden:example.org"),
+            ]);
+
+            let mut content = RoomCanonicalAliasEventContent::new();
+            content.alias = Some(owned_room_alias_id!("#main:example.org"));
+            content.alt_aliases = vec![
+                owned_room_alias_id!("#alt:example.org"),
+                owned_room_alias_id!("#remote:other.org"),
+            ];
+
+            let local_aliases = response.classify(&content);
+            assert_eq!(local_aliases.canonical_alias, Some(room_alias_id!("#main:example.org")));
+            assert_eq!(local_aliases.alt_aliases, [room_alias_id!("#alt:example.org")]);
+            assert_eq!(local_aliases.unpublished, [room_alias_id!("#hidden:example.org")]);
+
+            let local_aliases = response.classify(&RoomCanonicalAliasEventContent::new());
+            assert_eq!(local_aliases.canonical_alias, None);
+            assert!(local_aliases.alt_aliases.is_empty());
+            assert_eq!(local_aliases.unpublished.len(), 3);
+        }
     }
 }
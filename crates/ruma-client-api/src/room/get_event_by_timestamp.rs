@@ -8,10 +8,12 @@ pub mod v1 {
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#get_matrixclientv1roomsroomidtimestamp_to_event
 
     use ruma_common::{
-        api::{request, response, Direction, Metadata},
+        api::{error::FromHttpResponseError, request, response, Direction, Metadata},
         metadata, MilliSecondsSinceUnixEpoch, OwnedEventId, OwnedRoomId,
     };
 
+    use crate::error::ErrorKind;
+
     const METADATA: Metadata = metadata! {
         method: GET,
         rate_limited: true,
@@ -77,4 +79,134 @@ pub mod v1 {
             Self { event_id, origin_server_ts }
         }
     }
+
+    /// The error of a `get_event_by_timestamp` request, that distinguishes the case where no event
+    /// was found in the requested direction.
+    ///
+    /// It can be constructed from the error of [`Response::try_from_http_response()`].
+    ///
+    /// [`Response::try_from_http_response()`]: ruma_common::api::IncomingResponse::try_from_http_response
+    #[derive(Debug, thiserror::Error)]
+    #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+    pub enum GetEventByTimestampError {
+        /// The server found no event in the requested direction.
+        ///
+        /// Servers respond with a `404 M_NOT_FOUND` error in that case.
+        #[error("no event found in the requested direction: {0}")]
+        EventNotFound(crate::Error),
+
+        /// Any other server or transport error.
+        #[error(transparent)]
+        Other(FromHttpResponseError<crate::Error>),
+    }
+
+    impl From<FromHttpResponseError<crate::Error>> for GetEventByTimestampError {
+        fn from(error: FromHttpResponseError<crate::Error>) -> Self {
+            match error {
+                FromHttpResponseError::Server(error)
+                    if error.status_code == http::StatusCode::NOT_FOUND
+                        && error.error_kind() == Some(&ErrorKind::NotFound) =>
+                {
+                    Self::EventNotFound(error)
+                }
+                error => Self::Other(error),
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use assert_matches2::assert_matches;
+        use ruma_common::{
+            api::{
+                error::FromHttpResponseError, IncomingResponse as _, MatrixVersion,
+                OutgoingRequest as _, SendAccessToken,
+            },
+            owned_room_id, MilliSecondsSinceUnixEpoch,
+        };
+
+        use super::{GetEventByTimestampError, Request, Response};
+
+        #[test]
+        fn serialize_request() {
+            let req = Request::until(
+                owned_room_id!("!room:localhost"),
+                MilliSecondsSinceUnixEpoch(js_int::UInt::new(1_432_684_800_000).unwrap()),
+            );
+
+            let http_req = req
+                .clone()
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_6],
+                )
+                .unwrap();
+            assert_eq!(
+                http_req.uri().path(),
+                "/_matrix/client/v1/rooms/!room:localhost/timestamp_to_event"
+            );
+            assert_eq!(http_req.uri().query(), Some("ts=1432684800000&dir=b"));
+
+            // Servers that don't advertise Matrix 1.6 get the unstable path.
+            let http_req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_5],
+                )
+                .unwrap();
+            assert_eq!(
+                http_req.uri().path(),
+                "/_matrix/client/unstable/org.matrix.msc3030/rooms/!room:localhost/timestamp_to_event"
+            );
+        }
+
+        #[test]
+        fn deserialize_not_found_error() {
+            let err = Response::try_from_http_response(
+                http::Response::builder()
+                    .status(http::StatusCode::NOT_FOUND)
+                    .body(
+                        br#"{"errcode":"M_NOT_FOUND","error":"Unable to find event in direction f"}"#
+                            .as_slice(),
+                    )
+                    .unwrap(),
+            )
+            .unwrap_err();
+            assert_matches!(
+                GetEventByTimestampError::from(err),
+                GetEventByTimestampError::EventNotFound(_)
+            );
+
+            let err = Response::try_from_http_response(
+                http::Response::builder()
+                    .status(http::StatusCode::FORBIDDEN)
+                    .body(br#"{"errcode":"M_FORBIDDEN","error":"Not allowed"}"#.as_slice())
+                    .unwrap(),
+            )
+            .unwrap_err();
+            assert_matches!(
+                GetEventByTimestampError::from(err),
+                GetEventByTimestampError::Other(FromHttpResponseError::Server(_))
+            );
+
+            let err = Response::try_from_http_response(http::Response::new(b"{}".as_slice()))
+                .unwrap_err();
+            assert_matches!(
+                GetEventByTimestampError::from(err),
+                GetEventByTimestampError::Other(FromHttpResponseError::Deserialization(_))
+            );
+        }
+
+        #[test]
+        fn deserialize_response() {
+            let res = Response::try_from_http_response(http::Response::new(
+                br#"{"event_id":"$event:localhost","origin_server_ts":1432684800000}"#.as_slice(),
+            ))
+            .unwrap();
+            assert_eq!(res.event_id, "$event:localhost");
+            assert_eq!(res.origin_server_ts.get(), js_int::UInt::new(1_432_684_800_000).unwrap());
+        }
+    }
 }
@@ -58,4 +58,34 @@ pub mod v3 {
             Self { event }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            owned_event_id, owned_room_id,
+        };
+
+        use super::Request;
+
+        #[test]
+        fn serialize_request_with_slash_in_event_id() {
+            let req = Request::new(
+                owned_room_id!("!room:example.org"),
+                owned_event_id!("$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk"),
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://matrix.org",
+                SendAccessToken::IfRequired("tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/v3/rooms/!room:example.org/event/\
+                 $acR1l0raoZnm60CBwAVgqbZqoO%2FmYU81xysh1u7XcJk"
+            );
+        }
+    }
 }
@@ -7,11 +7,12 @@ pub mod v3 {
     //!
     //! [spec]: https://spec.matrix.org/latest/client-server-api/#post_matrixclientv3roomsroomidreporteventid
 
-    use js_int::Int;
+    use js_int::{int, Int};
     use ruma_common::{
         api::{request, response, Metadata},
         metadata, OwnedEventId, OwnedRoomId,
     };
+    use serde::{Deserialize, Serialize};
 
     const METADATA: Metadata = metadata! {
         method: POST,
@@ -35,8 +36,10 @@ pub mod v3 {
         pub event_id: OwnedEventId,
 
         /// Integer between -100 and 0 rating offensivness.
+        ///
+        /// -100 is "most offensive" and 0 is "inoffensive".
         #[serde(skip_serializing_if = "Option::is_none")]
-        pub score: Option<Int>,
+        pub score: Option<ReportScore>,
 
         /// Reason to report content.
         ///
@@ -52,13 +55,16 @@ pub mod v3 {
 
     impl Request {
         /// Creates a new `Request` with the given room ID, event ID, score and reason.
+        ///
+        /// Returns an error if the score is not between -100 and 0.
         pub fn new(
             room_id: OwnedRoomId,
             event_id: OwnedEventId,
             score: Option<Int>,
             reason: Option<String>,
-        ) -> Self {
-            Self { room_id, event_id, score, reason }
+        ) -> Result<Self, InvalidScoreError> {
+            let score = score.map(ReportScore::try_from).transpose()?;
+            Ok(Self { room_id, event_id, score, reason })
         }
     }
 
@@ -68,4 +74,131 @@ pub mod v3 {
             Self {}
         }
     }
+
+    /// The score of a report, an integer between -100 and 0.
+    ///
+    /// -100 is "most offensive" and 0 is "inoffensive".
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+    #[serde(try_from = "Int", into = "Int")]
+    pub struct ReportScore(Int);
+
+    impl ReportScore {
+        /// Get the value of this score.
+        pub fn value(&self) -> Int {
+            self.0
+        }
+    }
+
+    impl TryFrom<Int> for ReportScore {
+        type Error = InvalidScoreError;
+
+        fn try_from(score: Int) -> Result<Self, Self::Error> {
+            if (int!(-100)..=int!(0)).contains(&score) {
+                Ok(Self(score))
+            } else {
+                Err(InvalidScoreError)
+            }
+        }
+    }
+
+    impl From<ReportScore> for Int {
+        fn from(score: ReportScore) -> Self {
+            score.0
+        }
+    }
+
+    /// An error that happens when the score of a report is not between -100 and 0.
+    #[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+    #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+    #[error("report score must be between -100 and 0")]
+    pub struct InvalidScoreError;
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        use js_int::int;
+        #[cfg(feature = "server")]
+        use ruma_common::api::IncomingRequest as _;
+        #[cfg(feature = "client")]
+        use ruma_common::api::{MatrixVersion, OutgoingRequest, SendAccessToken};
+        use ruma_common::{owned_event_id, owned_room_id};
+        use serde_json::json;
+        #[cfg(feature = "client")]
+        use serde_json::{from_slice as from_json_slice, Value as JsonValue};
+
+        use super::{InvalidScoreError, Request};
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            let req = Request::new(
+                owned_room_id!("!room:example.org"),
+                owned_event_id!("$event"),
+                Some(int!(-100)),
+                None,
+            )
+            .unwrap()
+            .try_into_http_request::<Vec<u8>>(
+                "https://matrix.org",
+                SendAccessToken::IfRequired("tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/v3/rooms/!room:example.org/report/$event"
+            );
+            assert_eq!(from_json_slice::<JsonValue>(req.body()).unwrap(), json!({ "score": -100 }));
+        }
+
+        #[test]
+        fn invalid_score() {
+            for score in [int!(-101), int!(1)] {
+                let error = Request::new(
+                    owned_room_id!("!room:example.org"),
+                    owned_event_id!("$event"),
+                    Some(score),
+                    Some("Spam".to_owned()),
+                )
+                .unwrap_err();
+                assert_eq!(error, InvalidScoreError);
+                assert_eq!(error.to_string(), "report score must be between -100 and 0");
+            }
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method(http::Method::POST)
+                    .uri("https://matrix.org/_matrix/client/v3/rooms/!room:example.org/report/$event")
+                    .body(br#"{ "score": -50, "reason": "Spam" }"# as &[u8])
+                    .unwrap(),
+                &["!room:example.org", "$event"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!room:example.org");
+            assert_eq!(req.event_id, "$event");
+            assert_eq!(req.score.unwrap().value(), int!(-50));
+            assert_eq!(req.reason.as_deref(), Some("Spam"));
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request_with_invalid_score() {
+            for score in [-101, 1] {
+                Request::try_from_http_request(
+                    http::Request::builder()
+                        .method(http::Method::POST)
+                        .uri("https://matrix.org/_matrix/client/v3/rooms/!room:example.org/report/$event")
+                        .body(serde_json::to_vec(&json!({ "score": score })).unwrap())
+                        .unwrap(),
+                    &["!room:example.org", "$event"],
+                )
+                .unwrap_err();
+            }
+        }
+    }
 }
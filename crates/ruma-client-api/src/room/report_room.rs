@@ -0,0 +1,108 @@
+//! `POST /_matrix/client/*/rooms/{roomId}/report`
+//!
+//! Report a room as inappropriate.
+
+pub mod v3 {
+    //! `/v3/` ([MSC4151])
+    //!
+    //! [MSC4151]: https://github.com/matrix-org/matrix-spec-proposals/pull/4151
+
+    use ruma_common::{
+        api::{request, response, Metadata},
+        metadata, OwnedRoomId,
+    };
+
+    const METADATA: Metadata = metadata! {
+        method: POST,
+        rate_limited: true,
+        authentication: AccessToken,
+        history: {
+            unstable => "/_matrix/client/unstable/org.matrix.msc4151/rooms/:room_id/report",
+        }
+    };
+
+    /// Request type for the `report_room` endpoint.
+    #[request(error = crate::Error)]
+    pub struct Request {
+        /// The room to report.
+        #[ruma_api(path)]
+        pub room_id: OwnedRoomId,
+
+        /// The reason to report the room.
+        ///
+        /// May be blank.
+        pub reason: String,
+    }
+
+    /// Response type for the `report_room` endpoint.
+    #[response(error = crate::Error)]
+    #[derive(Default)]
+    pub struct Response {}
+
+    impl Request {
+        /// Creates a new `Request` with the given room ID and reason.
+        pub fn new(room_id: OwnedRoomId, reason: String) -> Self {
+            Self { room_id, reason }
+        }
+    }
+
+    impl Response {
+        /// Creates an empty `Response`.
+        pub fn new() -> Self {
+            Self {}
+        }
+    }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "server")]
+        use ruma_common::api::IncomingRequest as _;
+        #[cfg(feature = "client")]
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+            owned_room_id,
+        };
+        #[cfg(feature = "client")]
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::Request;
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            let req = Request::new(owned_room_id!("!room:example.org"), "Spam".to_owned())
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/client/unstable/org.matrix.msc4151/rooms/!room:example.org/report"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({ "reason": "Spam" })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method(http::Method::POST)
+                    .uri("https://matrix.org/_matrix/client/unstable/org.matrix.msc4151/rooms/!room:example.org/report")
+                    .body(br#"{ "reason": "" }"# as &[u8])
+                    .unwrap(),
+                &["!room:example.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!room:example.org");
+            assert_eq!(req.reason, "");
+        }
+    }
+}
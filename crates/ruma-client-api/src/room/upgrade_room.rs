@@ -53,4 +53,61 @@ pub mod v3 {
             Self { replacement_room }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_room_id, RoomVersionId,
+            };
+
+            let req = super::Request::new(owned_room_id!("!old:example.org"), RoomVersionId::V11)
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/rooms/!old:example.org/upgrade");
+            assert_eq!(req.body(), br#"{"new_version":"11"}"#);
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse as _;
+
+            let res = super::Response::try_from_http_response(http::Response::new(
+                br#"{"replacement_room":"!new:example.org"}"#.as_slice(),
+            ))
+            .unwrap();
+            assert_eq!(res.replacement_room, "!new:example.org");
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_unsupported_room_version_error() {
+            use assert_matches2::assert_matches;
+            use ruma_common::api::{error::FromHttpResponseError, IncomingResponse as _};
+
+            use crate::error::{ErrorKind, FromHttpResponseErrorExt as _};
+
+            let err = super::Response::try_from_http_response(
+                http::Response::builder()
+                    .status(http::StatusCode::BAD_REQUEST)
+                    .body(
+                        br#"{"errcode":"M_UNSUPPORTED_ROOM_VERSION","error":"Unknown room version"}"#
+                            .as_slice(),
+                    )
+                    .unwrap(),
+            )
+            .unwrap_err();
+            assert_matches!(&err, FromHttpResponseError::Server(_));
+            assert_eq!(err.error_kind(), Some(&ErrorKind::UnsupportedRoomVersion));
+        }
+    }
 }
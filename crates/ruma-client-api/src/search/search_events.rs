@@ -95,7 +95,7 @@ pub mod v3 {
         pub keys: Option<Vec<SearchKeys>>,
 
         /// A `Filter` to apply to the search.
-        #[serde(skip_serializing_if = "RoomEventFilter::is_empty")]
+        #[serde(default, skip_serializing_if = "RoomEventFilter::is_empty")]
         pub filter: RoomEventFilter,
 
         /// The order in which to search for results.
@@ -428,7 +428,7 @@ pub mod v3 {
     #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
     pub struct SearchResult {
         /// Context for result, if requested.
-        #[serde(skip_serializing_if = "EventContextResult::is_empty")]
+        #[serde(default, skip_serializing_if = "EventContextResult::is_empty")]
         pub context: EventContextResult,
 
         /// A number that describes how closely this result matches the search.
@@ -489,6 +489,7 @@ pub mod v3 {
     /// Represents either a room or user ID for returning grouped search results.
     #[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
     #[allow(clippy::exhaustive_enums)]
+    #[serde(untagged)]
     pub enum OwnedRoomIdOrUserId {
         /// Represents a room ID.
         RoomId(OwnedRoomId),
@@ -496,4 +497,169 @@ pub mod v3 {
         /// Represents a user ID.
         UserId(OwnedUserId),
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest, SendAccessToken};
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            use super::{Categories, Criteria, Grouping, GroupingKey, OrderBy, Request};
+
+            let req =
+                Request::new(Categories { room_events: Some(Criteria::new("lunch".to_owned())) })
+                    .try_into_http_request::<Vec<u8>>(
+                        "https://matrix.org",
+                        SendAccessToken::IfRequired("tok"),
+                        &[MatrixVersion::V1_1],
+                    )
+                    .unwrap();
+
+            assert_eq!(req.uri().query(), None);
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({
+                    "search_categories": {
+                        "room_events": {
+                            "search_term": "lunch",
+                        },
+                    },
+                })
+            );
+
+            let mut criteria = Criteria::new("lunch".to_owned());
+            criteria.order_by = Some(OrderBy::Rank);
+            criteria.event_context.include_profile = true;
+            criteria.groupings.group_by = vec![Grouping { key: Some(GroupingKey::RoomId) }];
+            let mut req = Request::new(Categories { room_events: Some(criteria) });
+            req.next_batch = Some("next".to_owned());
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(req.uri().query(), Some("next_batch=next"));
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({
+                    "search_categories": {
+                        "room_events": {
+                            "search_term": "lunch",
+                            "order_by": "rank",
+                            "event_context": {
+                                "include_profile": true,
+                            },
+                            "groupings": {
+                                "group_by": [{ "key": "room_id" }],
+                            },
+                        },
+                    },
+                })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest as _;
+            use serde_json::{json, to_vec as to_json_vec};
+
+            use super::Request;
+
+            let body = json!({
+                "search_categories": {
+                    "room_events": {
+                        "search_term": "lunch",
+                    },
+                },
+            });
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .method(http::Method::POST)
+                    .uri("https://matrix.org/_matrix/client/v3/search")
+                    .body(to_json_vec(&body).unwrap())
+                    .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+
+            let criteria = req.search_categories.room_events.unwrap();
+            assert_eq!(criteria.search_term, "lunch");
+            assert!(criteria.filter.is_empty());
+            assert!(criteria.groupings.is_empty());
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use assert_matches2::assert_matches;
+            use js_int::uint;
+            use ruma_common::{api::IncomingResponse as _, room_id};
+            use serde_json::{json, to_vec as to_json_vec};
+
+            use super::{GroupingKey, OwnedRoomIdOrUserId, Response};
+
+            let body = json!({
+                "search_categories": {
+                    "room_events": {
+                        "count": 1,
+                        "groups": {
+                            "room_id": {
+                                "!qPewotXpIctQySfjSy:localhost": {
+                                    "next_batch": "BdgFsdfHSf-dsFD",
+                                    "order": 1,
+                                    "results": ["$144429830826TWwbB:localhost"],
+                                },
+                            },
+                        },
+                        "highlights": ["lunch"],
+                        "next_batch": "5FdgFsd234dfgsdfFD",
+                        "results": [
+                            {
+                                "rank": 0.5,
+                                "result": {
+                                    "content": {
+                                        "body": "This is an example text message",
+                                        "msgtype": "m.text",
+                                    },
+                                    "event_id": "$144429830826TWwbB:localhost",
+                                    "origin_server_ts": 1_432_735_824_653_u64,
+                                    "room_id": "!qPewotXpIctQySfjSy:localhost",
+                                    "sender": "@example:example.org",
+                                    "type": "m.room.message",
+                                },
+                            },
+                        ],
+                    },
+                },
+            });
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            let room_events = res.search_categories.room_events;
+            assert_eq!(room_events.count, Some(uint!(1)));
+            assert_eq!(room_events.highlights, ["lunch"]);
+            assert_eq!(room_events.next_batch.as_deref(), Some("5FdgFsd234dfgsdfFD"));
+
+            let groups = &room_events.groups[&GroupingKey::RoomId];
+            let (key, group) = groups.iter().next().unwrap();
+            assert_matches!(key, OwnedRoomIdOrUserId::RoomId(room_id));
+            assert_eq!(room_id, room_id!("!qPewotXpIctQySfjSy:localhost"));
+            assert_eq!(group.order, Some(uint!(1)));
+            assert_eq!(group.results.len(), 1);
+
+            assert_eq!(room_events.results.len(), 1);
+            let result = &room_events.results[0];
+            assert!(result.context.is_empty());
+            assert_eq!(result.rank, Some(0.5));
+            assert!(result.result.is_some());
+        }
+    }
 }
@@ -97,12 +97,15 @@ pub mod v3 {
     #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
     pub struct ConnectionInfo {
         /// Most recently seen IP address of the session.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub ip: Option<String>,
 
         /// Time when that the session was last active.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub last_seen: Option<MilliSecondsSinceUnixEpoch>,
 
         /// User agent string last seen in the session.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub user_agent: Option<String>,
     }
 
@@ -112,4 +115,69 @@ pub mod v3 {
             Self::default()
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use js_int::{uint, UInt};
+        use ruma_common::MilliSecondsSinceUnixEpoch;
+        use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+        use super::{ConnectionInfo, DeviceInfo, SessionInfo};
+
+        #[test]
+        fn deserialize_device_info_with_unknown_fields() {
+            let device_info = from_json_value::<DeviceInfo>(json!({
+                "sessions": [
+                    {
+                        "connections": [
+                            {
+                                "ip": "127.0.0.1",
+                                "last_seen": 1_411_996_332_123_u64,
+                                "user_agent": "curl/7.31.0-DEV",
+                                "org.example.diagnostic": true,
+                            },
+                            {},
+                        ],
+                        "org.example.session_id": "abc",
+                    },
+                ],
+                "org.example.device_name": "Laptop",
+            }))
+            .unwrap();
+
+            assert_eq!(device_info.sessions.len(), 1);
+            let connections = &device_info.sessions[0].connections;
+            assert_eq!(connections.len(), 2);
+            assert_eq!(connections[0].ip.as_deref(), Some("127.0.0.1"));
+            assert_eq!(
+                connections[0].last_seen,
+                Some(MilliSecondsSinceUnixEpoch(UInt::new(1_411_996_332_123).unwrap()))
+            );
+            assert_eq!(connections[0].user_agent.as_deref(), Some("curl/7.31.0-DEV"));
+            assert_eq!(connections[1].ip, None);
+            assert_eq!(connections[1].last_seen, None);
+            assert_eq!(connections[1].user_agent, None);
+        }
+
+        #[test]
+        fn serialize_device_info() {
+            let mut connection = ConnectionInfo::new();
+            connection.ip = Some("127.0.0.1".to_owned());
+            connection.last_seen = Some(MilliSecondsSinceUnixEpoch(uint!(1000)));
+            let mut session = SessionInfo::new();
+            session.connections = vec![connection, ConnectionInfo::new()];
+            let mut device_info = DeviceInfo::new();
+            device_info.sessions = vec![session, SessionInfo::new()];
+
+            assert_eq!(
+                to_json_value(&device_info).unwrap(),
+                json!({
+                    "sessions": [
+                        { "connections": [{ "ip": "127.0.0.1", "last_seen": 1000 }, {}] },
+                        {},
+                    ],
+                })
+            );
+        }
+    }
 }
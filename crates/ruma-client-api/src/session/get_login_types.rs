@@ -192,9 +192,11 @@ pub mod v3 {
         pub name: String,
 
         /// The icon for the provider.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub icon: Option<OwnedMxcUri>,
 
         /// The brand identifier for the provider.
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub brand: Option<IdentityProviderBrand>,
     }
 
@@ -403,6 +405,17 @@ pub mod v3 {
             assert_eq!(provider.brand, None);
         }
 
+        #[test]
+        fn serialize_identity_provider_without_icon_and_brand() {
+            assert_eq!(
+                to_json_value(IdentityProvider::new("saml".into(), "SAML".into())).unwrap(),
+                json!({
+                    "id": "saml",
+                    "name": "SAML",
+                })
+            );
+        }
+
         #[test]
         fn serialize_sso_login_type() {
             let wrapper = to_json_value(Wrapper {
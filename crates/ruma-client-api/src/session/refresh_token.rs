@@ -85,4 +85,64 @@ pub mod v3 {
             Self { access_token, refresh_token: None, expires_in_ms: None }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use std::time::Duration;
+
+            use ruma_common::api::IncomingResponse as _;
+            use serde_json::{json, to_vec as to_json_vec};
+
+            use super::Response;
+
+            let body = json!({
+                "access_token": "new_access",
+                "refresh_token": "new_refresh",
+                "expires_in_ms": 60_000,
+            });
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(res.access_token, "new_access");
+            assert_eq!(res.refresh_token.as_deref(), Some("new_refresh"));
+            assert_eq!(res.expires_in_ms, Some(Duration::from_secs(60)));
+
+            let body = json!({ "access_token": "new_access" });
+            let res = Response::try_from_http_response(
+                http::Response::builder().body(to_json_vec(&body).unwrap()).unwrap(),
+            )
+            .unwrap();
+
+            assert_eq!(res.refresh_token, None);
+            assert_eq!(res.expires_in_ms, None);
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_response() {
+            use std::time::Duration;
+
+            use ruma_common::api::OutgoingResponse as _;
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            use super::Response;
+
+            let mut res = Response::new("new_access".to_owned());
+            res.expires_in_ms = Some(Duration::from_secs(60));
+            let res = res.try_into_http_response::<Vec<u8>>().unwrap();
+
+            assert_eq!(
+                from_json_slice::<JsonValue>(res.body()).unwrap(),
+                json!({
+                    "access_token": "new_access",
+                    "expires_in_ms": 60_000,
+                })
+            );
+        }
+    }
 }
@@ -57,14 +57,15 @@ pub mod v3 {
         }
     }
 
-    #[cfg(all(test, feature = "client"))]
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
     mod tests {
-        use ruma_common::api::{MatrixVersion, OutgoingRequest, SendAccessToken};
-
-        use super::Request;
-
+        #[cfg(feature = "client")]
         #[test]
         fn serialize_sso_login_request_uri() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest, SendAccessToken};
+
+            use super::Request;
+
             let req: http::Request<Vec<u8>> =
                 Request { redirect_url: "https://example.com/sso".to_owned() }
                     .try_into_http_request(
@@ -79,5 +80,21 @@ pub mod v3 {
             "https://homeserver.tld/_matrix/client/v3/login/sso/redirect?redirectUrl=https%3A%2F%2Fexample.com%2Fsso"
         );
         }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_sso_login_response() {
+            use http::header::LOCATION;
+            use ruma_common::api::OutgoingResponse;
+
+            use super::Response;
+
+            let res = Response::new("https://idp.example.com/auth".to_owned())
+                .try_into_http_response::<Vec<u8>>()
+                .unwrap();
+
+            assert_eq!(res.status(), http::StatusCode::FOUND);
+            assert_eq!(res.headers().get(LOCATION).unwrap(), "https://idp.example.com/auth");
+        }
     }
 }
@@ -86,4 +86,156 @@ pub mod v1 {
             Default::default()
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use js_int::uint;
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest, SendAccessToken},
+                owned_room_id,
+            };
+
+            use super::Request;
+
+            let req = Request::new(owned_room_id!("!space:example.org"))
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_2],
+                )
+                .unwrap();
+            assert_eq!(req.uri().path(), "/_matrix/client/v1/rooms/!space:example.org/hierarchy");
+            assert_eq!(req.uri().query(), None);
+
+            let mut req = Request::new(owned_room_id!("!space:example.org"));
+            req.from = Some("opaque+token/1".to_owned());
+            req.limit = Some(uint!(10));
+            req.max_depth = Some(uint!(2));
+            req.suggested_only = true;
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://matrix.org",
+                    SendAccessToken::IfRequired("tok"),
+                    &[MatrixVersion::V1_2],
+                )
+                .unwrap();
+            assert_eq!(
+                req.uri().query(),
+                Some("from=opaque%2Btoken%2F1&limit=10&max_depth=2&suggested_only=true")
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use js_int::uint;
+            use ruma_common::api::IncomingRequest as _;
+
+            use super::Request;
+
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .uri(
+                        "https://matrix.org/_matrix/client/v1/rooms/!space:example.org/hierarchy\
+                         ?from=opaque%2Btoken%2F1&max_depth=2&suggested_only=true",
+                    )
+                    .body(&[] as &[u8])
+                    .unwrap(),
+                &["!space:example.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!space:example.org");
+            assert_eq!(req.from.as_deref(), Some("opaque+token/1"));
+            assert_eq!(req.limit, None);
+            assert_eq!(req.max_depth, Some(uint!(2)));
+            assert!(req.suggested_only);
+
+            let req = Request::try_from_http_request(
+                http::Request::builder()
+                    .uri(
+                        "https://matrix.org/_matrix/client/v1/rooms/!space:example.org/hierarchy\
+                         ?suggested_only=false",
+                    )
+                    .body(&[] as &[u8])
+                    .unwrap(),
+                &["!space:example.org"],
+            )
+            .unwrap();
+            assert!(!req.suggested_only);
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use js_int::uint;
+            use ruma_common::{api::IncomingResponse as _, space::SpaceRoomJoinRule};
+            use serde_json::{json, to_vec as to_json_vec};
+
+            use super::Response;
+
+            let body = json!({
+                "next_batch": "opaque+token/2",
+                "rooms": [
+                    {
+                        "children_state": [
+                            {
+                                "content": { "via": ["example.org"] },
+                                "origin_server_ts": 1,
+                                "sender": "@alice:example.org",
+                                "state_key": "!child:example.org",
+                                "type": "m.space.child",
+                            },
+                        ],
+                        "guest_can_join": false,
+                        "join_rule": "public",
+                        "name": "The space",
+                        "num_joined_members": 42,
+                        "room_id": "!space:example.org",
+                        "room_type": "m.space",
+                        "world_readable": true,
+                    },
+                ],
+            });
+            let response = http::Response::new(to_json_vec(&body).unwrap());
+            let response = Response::try_from_http_response(response).unwrap();
+
+            assert_eq!(response.next_batch.as_deref(), Some("opaque+token/2"));
+            assert_eq!(response.rooms.len(), 1);
+
+            let room = &response.rooms[0];
+            assert_eq!(room.room_id, "!space:example.org");
+            assert_eq!(room.name.as_deref(), Some("The space"));
+            assert_eq!(room.num_joined_members, uint!(42));
+            assert_eq!(room.join_rule, SpaceRoomJoinRule::Public);
+            assert_eq!(room.room_type.as_ref().unwrap().as_str(), "m.space");
+            assert!(room.world_readable);
+            assert!(!room.guest_can_join);
+            assert_eq!(room.children_state.len(), 1);
+
+            let child = room.children_state[0].deserialize().unwrap();
+            assert_eq!(child.state_key, "!child:example.org");
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_response() {
+            use ruma_common::api::OutgoingResponse as _;
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            use super::Response;
+
+            let mut response = Response::new();
+            response.next_batch = Some("opaque+token/2".to_owned());
+            let response = response.try_into_http_response::<Vec<u8>>().unwrap();
+
+            assert_eq!(
+                from_json_slice::<JsonValue>(response.body()).unwrap(),
+                json!({ "next_batch": "opaque+token/2", "rooms": [] })
+            );
+        }
+    }
 }
@@ -61,6 +61,37 @@ pub mod v3 {
         ///
         /// Since `Request` stores the request body in serialized form, this function can fail if
         /// `T`s [`Serialize`][serde::Serialize] implementation can fail.
+        ///
+        /// # Example
+        ///
+        /// Custom state events defined outside of Ruma can be sent like the ones in the spec:
+        ///
+        /// ```
+        /// use ruma_client_api::state::send_state_event;
+        /// use ruma_common::owned_room_id;
+        /// use ruma_events::{macros::EventContent, StaticEventContent};
+        /// use serde::{Deserialize, Serialize};
+        ///
+        /// /// The status of a bridge to a remote network.
+        /// #[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+        /// #[ruma_event(type = "com.example.bridge.status", kind = State, state_key_type = String)]
+        /// pub struct BridgeStatusEventContent {
+        ///     /// Whether the remote network is reachable.
+        ///     pub online: bool,
+        /// }
+        ///
+        /// assert_eq!(BridgeStatusEventContent::TYPE, "com.example.bridge.status");
+        ///
+        /// let request = send_state_event::v3::Request::new(
+        ///     owned_room_id!("!room:example.org"),
+        ///     "remote.example.org",
+        ///     &BridgeStatusEventContent { online: true },
+        /// )?;
+        /// assert_eq!(request.event_type.to_string(), "com.example.bridge.status");
+        /// assert_eq!(request.state_key, "remote.example.org");
+        /// assert_eq!(request.body.json().get(), r#"{"online":true}"#);
+        /// # Ok::<(), serde_json::Error>(())
+        /// ```
         pub fn new<T, K>(
             room_id: OwnedRoomId,
             state_key: &K,
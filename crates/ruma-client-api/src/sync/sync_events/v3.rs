@@ -109,6 +109,7 @@ pub struct Response {
     ///
     /// The presence of this field indicates that the server supports
     /// fallback keys.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub device_unused_fallback_key_types: Option<Vec<DeviceKeyAlgorithm>>,
 }
 
@@ -200,7 +201,10 @@ impl Rooms {
 
     /// Returns true if there is no update in any room.
     pub fn is_empty(&self) -> bool {
-        self.leave.is_empty() && self.join.is_empty() && self.invite.is_empty()
+        self.leave.is_empty()
+            && self.join.is_empty()
+            && self.invite.is_empty()
+            && self.knock.is_empty()
     }
 }
 
@@ -322,17 +326,55 @@ impl JoinedRoom {
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 pub struct KnockedRoom {
     /// The knock state.
+    #[serde(default, skip_serializing_if = "KnockState::is_empty")]
     pub knock_state: KnockState,
 }
 
+impl KnockedRoom {
+    /// Creates an empty `KnockedRoom`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns true if there are no updates to this room.
+    pub fn is_empty(&self) -> bool {
+        self.knock_state.is_empty()
+    }
+}
+
+impl From<KnockState> for KnockedRoom {
+    fn from(knock_state: KnockState) -> Self {
+        KnockedRoom { knock_state, ..Default::default() }
+    }
+}
+
 /// A mapping from a key `events` to a list of `StrippedStateEvent`.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 pub struct KnockState {
     /// The list of events.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub events: Vec<Raw<AnyStrippedStateEvent>>,
 }
 
+impl KnockState {
+    /// Creates an empty `KnockState`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns true if there are no state updates.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl From<Vec<Raw<AnyStrippedStateEvent>>> for KnockState {
+    fn from(events: Vec<Raw<AnyStrippedStateEvent>>) -> Self {
+        KnockState { events, ..Default::default() }
+    }
+}
+
 /// Events in the room.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -597,10 +639,13 @@ impl ToDevice {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches2::assert_matches;
     use assign::assign;
+    use ruma_common::room_id;
+    use ruma_events::{room::member::MembershipState, AnyStrippedStateEvent};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::Timeline;
+    use super::{Rooms, Timeline};
 
     #[test]
     fn timeline_serde() {
@@ -618,6 +663,42 @@ mod tests {
             from_json_value::<Timeline>(json!({ "events": [] })).unwrap();
         assert!(!timeline_default_deserialized.limited);
     }
+
+    #[test]
+    fn knocked_rooms_deserialization() {
+        let json = json!({
+            "knock": {
+                "!knock:localhost": {
+                    "knock_state": {
+                        "events": [
+                            {
+                                "content": { "join_rule": "knock" },
+                                "sender": "@alice:localhost",
+                                "state_key": "",
+                                "type": "m.room.join_rules",
+                            },
+                            {
+                                "content": { "membership": "knock" },
+                                "sender": "@bob:localhost",
+                                "state_key": "@bob:localhost",
+                                "type": "m.room.member",
+                            },
+                        ],
+                    },
+                },
+            },
+        });
+
+        let rooms = from_json_value::<Rooms>(json).unwrap();
+        assert!(!rooms.is_empty());
+
+        let knocked_room = rooms.knock.get(room_id!("!knock:localhost")).unwrap();
+        assert_eq!(knocked_room.knock_state.events.len(), 2);
+
+        let member_event = knocked_room.knock_state.events[1].deserialize().unwrap();
+        assert_matches!(member_event, AnyStrippedStateEvent::RoomMember(member_event));
+        assert_eq!(member_event.content.membership, MembershipState::Knock);
+    }
 }
 
 #[cfg(all(test, feature = "client"))]
@@ -627,6 +708,7 @@ mod client_tests {
     use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
 
     use super::{Filter, PresenceState, Request};
+    use crate::filter::FilterDefinition;
 
     #[test]
     fn serialize_all_params() {
@@ -654,6 +736,163 @@ mod client_tests {
         assert!(query.contains("set_presence=offline"));
         assert!(query.contains("timeout=30000"));
     }
+
+    #[test]
+    fn serialize_filter_definition() {
+        let req: http::Request<Vec<u8>> = Request {
+            filter: Some(Filter::FilterDefinition(FilterDefinition::with_lazy_loading())),
+            since: None,
+            full_state: false,
+            set_presence: PresenceState::Online,
+            timeout: None,
+        }
+        .try_into_http_request(
+            "https://homeserver.tld",
+            SendAccessToken::IfRequired("auth_tok"),
+            &[MatrixVersion::V1_1],
+        )
+        .unwrap();
+
+        assert_eq!(
+            req.uri().query(),
+            Some(
+                "filter=%7B%22room%22%3A%7B%22state%22%3A%7B%22lazy_load_members%22%3Atrue%7D%7D%7D"
+            )
+        );
+    }
+
+    #[test]
+    fn deserialize_full_response() {
+        use assert_matches2::assert_matches;
+        use js_int::uint;
+        use ruma_common::{
+            api::IncomingResponse as _, event_id, room_id, user_id, DeviceKeyAlgorithm,
+        };
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+
+        let body = json!({
+            "next_batch": "s72595_4483_1934",
+            "account_data": {
+                "events": [
+                    { "type": "org.example.custom.config", "content": { "custom_config_key": "custom_config_value" } },
+                ],
+            },
+            "presence": {
+                "events": [
+                    {
+                        "type": "m.presence",
+                        "sender": "@example:localhost",
+                        "content": {
+                            "avatar_url": "mxc://localhost/wefuiwegh8742w",
+                            "currently_active": false,
+                            "last_active_ago": 2_478_593,
+                            "presence": "online",
+                            "status_msg": "Making cupcakes",
+                        },
+                    },
+                ],
+            },
+            "device_lists": {
+                "changed": ["@alice:example.org"],
+                "left": ["@bob:example.org"],
+            },
+            "device_one_time_keys_count": { "signed_curve25519": 20 },
+            "device_unused_fallback_key_types": ["signed_curve25519"],
+            "rooms": {
+                "join": {
+                    "!726s6s6q:example.com": {
+                        "account_data": {
+                            "events": [
+                                { "type": "m.tag", "content": { "tags": { "u.work": { "order": 0.9 } } } },
+                            ],
+                        },
+                        "ephemeral": {
+                            "events": [
+                                { "type": "m.typing", "content": { "user_ids": ["@alice:example.com"] } },
+                            ],
+                        },
+                        "state": { "events": [] },
+                        "summary": {
+                            "m.heroes": ["@alice:example.com", "@bob:example.com"],
+                            "m.invited_member_count": 0,
+                            "m.joined_member_count": 2,
+                        },
+                        "timeline": {
+                            "events": [
+                                {
+                                    "type": "m.room.message",
+                                    "event_id": "$143273582443PhrSn:example.org",
+                                    "sender": "@example:example.org",
+                                    "origin_server_ts": 1_432_735_824_653_u64,
+                                    "content": { "body": "This is an example text message", "msgtype": "m.text" },
+                                    "unsigned": { "age": 1234 },
+                                },
+                            ],
+                            "limited": true,
+                            "prev_batch": "t34-23535_0_0",
+                        },
+                        "unread_notifications": { "highlight_count": 1, "notification_count": 5 },
+                        "unread_thread_notifications": {
+                            "$threadroot": { "highlight_count": 2, "notification_count": 6 },
+                        },
+                    },
+                },
+                "invite": {
+                    "!696r7674:example.com": {
+                        "invite_state": {
+                            "events": [
+                                { "type": "m.room.name", "sender": "@alice:example.com", "state_key": "", "content": { "name": "My Room Name" } },
+                                { "type": "m.room.member", "sender": "@alice:example.com", "state_key": "@bob:example.com", "content": { "membership": "invite" } },
+                            ],
+                        },
+                    },
+                },
+                "leave": {},
+            },
+        });
+
+        let res =
+            Response::try_from_http_response(http::Response::new(to_json_vec(&body).unwrap()))
+                .unwrap();
+
+        assert_eq!(res.next_batch, "s72595_4483_1934");
+        assert_eq!(res.account_data.events.len(), 1);
+        assert_eq!(res.presence.events.len(), 1);
+        assert_eq!(res.device_lists.changed, [user_id!("@alice:example.org")]);
+        assert_eq!(res.device_lists.left, [user_id!("@bob:example.org")]);
+        assert_eq!(
+            res.device_one_time_keys_count.get(&DeviceKeyAlgorithm::SignedCurve25519),
+            Some(&uint!(20))
+        );
+        assert_matches!(res.device_unused_fallback_key_types, Some(fallback_key_types));
+        assert_eq!(fallback_key_types, [DeviceKeyAlgorithm::SignedCurve25519]);
+
+        let joined_room = &res.rooms.join[room_id!("!726s6s6q:example.com")];
+        assert!(!joined_room.is_empty());
+        assert_eq!(joined_room.account_data.events.len(), 1);
+        assert_eq!(joined_room.ephemeral.events.len(), 1);
+        assert_eq!(
+            joined_room.summary.heroes,
+            [user_id!("@alice:example.com"), user_id!("@bob:example.com")]
+        );
+        assert_eq!(joined_room.summary.joined_member_count, Some(uint!(2)));
+        assert_eq!(joined_room.summary.invited_member_count, Some(uint!(0)));
+        assert_eq!(joined_room.timeline.events.len(), 1);
+        assert!(joined_room.timeline.limited);
+        assert_eq!(joined_room.timeline.prev_batch.as_deref(), Some("t34-23535_0_0"));
+        assert_eq!(joined_room.unread_notifications.highlight_count, Some(uint!(1)));
+        assert_eq!(joined_room.unread_notifications.notification_count, Some(uint!(5)));
+        let thread_notifications =
+            &joined_room.unread_thread_notifications[event_id!("$threadroot")];
+        assert_eq!(thread_notifications.highlight_count, Some(uint!(2)));
+        assert_eq!(thread_notifications.notification_count, Some(uint!(6)));
+
+        let invited_room = &res.rooms.invite[room_id!("!696r7674:example.com")];
+        assert_eq!(invited_room.invite_state.events.len(), 2);
+        assert!(res.rooms.leave.is_empty());
+    }
 }
 
 #[cfg(all(test, feature = "server"))]
@@ -661,6 +900,7 @@ mod server_tests {
     use std::time::Duration;
 
     use assert_matches2::assert_matches;
+    use js_int::uint;
     use ruma_common::{api::IncomingRequest as _, presence::PresenceState};
 
     use super::{Filter, Request};
@@ -695,6 +935,29 @@ mod server_tests {
         assert_eq!(req.timeout, Some(Duration::from_millis(5000)));
     }
 
+    #[test]
+    fn deserialize_filter_definition() {
+        let uri = http::Uri::builder()
+            .scheme("https")
+            .authority("matrix.org")
+            .path_and_query(
+                "/_matrix/client/r0/sync\
+                ?filter=%7B%22room%22%3A%7B%22timeline%22%3A%7B%22limit%22%3A10%7D%7D%7D",
+            )
+            .build()
+            .unwrap();
+
+        let req = Request::try_from_http_request(
+            http::Request::builder().uri(uri).body(&[] as &[u8]).unwrap(),
+            &[] as &[String],
+        )
+        .unwrap();
+
+        assert_matches!(req.filter, Some(Filter::FilterDefinition(definition)));
+        assert_eq!(definition.room.timeline.limit, Some(uint!(10)));
+        assert!(definition.room.state.is_empty());
+    }
+
     #[test]
     fn deserialize_no_query_params() {
         let uri = http::Uri::builder()
@@ -743,4 +1006,20 @@ mod server_tests {
         assert_eq!(req.set_presence, PresenceState::Online);
         assert_eq!(req.timeout, Some(Duration::from_millis(0)));
     }
+
+    #[test]
+    fn serialize_minimal_response() {
+        use ruma_common::api::OutgoingResponse as _;
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::Response;
+
+        let res = Response::new("s72595_4483_1934".to_owned())
+            .try_into_http_response::<Vec<u8>>()
+            .unwrap();
+        assert_eq!(
+            from_json_slice::<JsonValue>(res.body()).unwrap(),
+            json!({ "next_batch": "s72595_4483_1934" })
+        );
+    }
 }
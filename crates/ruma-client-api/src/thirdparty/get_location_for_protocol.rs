@@ -59,4 +59,50 @@ pub mod v3 {
             Self { locations }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request_with_fields() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest as _, SendAccessToken};
+
+            let mut req = super::Request::new("irc".to_owned());
+            req.fields.insert("network".to_owned(), "irc.example.org".to_owned());
+            req.fields.insert("channel".to_owned(), "#matrix & co".to_owned());
+
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/thirdparty/location/irc");
+            assert_eq!(req.uri().query(), Some("channel=%23matrix+%26+co&network=irc.example.org"));
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request_with_fields() {
+            use ruma_common::api::IncomingRequest as _;
+
+            let req = super::Request::try_from_http_request(
+                http::Request::get(
+                    "https://homeserver.tld/_matrix/client/v3/thirdparty/location/irc\
+                     ?network=irc.example.org&channel=%23matrix",
+                )
+                .body(&[] as &[u8])
+                .unwrap(),
+                &["irc"],
+            )
+            .unwrap();
+
+            assert_eq!(req.protocol, "irc");
+            assert_eq!(req.fields.len(), 2);
+            assert_eq!(req.fields["network"], "irc.example.org");
+            assert_eq!(req.fields["channel"], "#matrix");
+        }
+    }
 }
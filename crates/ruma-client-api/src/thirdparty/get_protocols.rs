@@ -51,4 +51,48 @@ pub mod v3 {
             Self { protocols }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::api::IncomingResponse as _;
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "irc": {
+                    "field_types": {
+                        "channel": { "placeholder": "#foobar", "regexp": "#[^\\s]+" },
+                        "network": { "placeholder": "irc.example.org", "regexp": "([a-z0-9]+\\.)*[a-z0-9]+" },
+                    },
+                    "icon": "mxc://example.org/aBcDeFgH",
+                    "instances": [
+                        {
+                            "desc": "Freenode",
+                            "fields": { "network": "freenode" },
+                            "icon": "mxc://example.org/JkLmNoPq",
+                            "network_id": "freenode",
+                        },
+                    ],
+                    "location_fields": ["network", "channel"],
+                    "user_fields": ["network", "nickname"],
+                },
+            });
+
+            let res =
+                Response::try_from_http_response(http::Response::new(to_json_vec(&body).unwrap()))
+                    .unwrap();
+
+            let irc = &res.protocols["irc"];
+            assert_eq!(irc.location_fields, ["network", "channel"]);
+            assert_eq!(irc.user_fields, ["network", "nickname"]);
+            assert_eq!(irc.field_types["channel"].placeholder, "#foobar");
+            assert_eq!(irc.instances.len(), 1);
+            assert_eq!(irc.instances[0].desc, "Freenode");
+            assert_eq!(irc.instances[0].fields["network"], "freenode");
+            assert_eq!(irc.instances[0].network_id, "freenode");
+        }
+    }
 }
@@ -53,4 +53,42 @@ pub mod v3 {
             Self { users }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{IncomingResponse as _, MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            owned_user_id,
+        };
+
+        use super::{Request, Response};
+
+        #[test]
+        fn serialize_request() {
+            let req = Request::new(owned_user_id!("@_gitter_jim:matrix.org"))
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/client/v3/thirdparty/user");
+            assert_eq!(req.uri().query(), Some("userid=%40_gitter_jim%3Amatrix.org"));
+        }
+
+        #[test]
+        fn deserialize_response() {
+            let res = Response::try_from_http_response(http::Response::new(
+                br#"[{"userid":"@_gitter_jim:matrix.org","protocol":"gitter","fields":{"user":"jim"}}]"#
+                    .as_slice(),
+            ))
+            .unwrap();
+
+            assert_eq!(res.users.len(), 1);
+            assert_eq!(res.users[0].userid, "@_gitter_jim:matrix.org");
+            assert_eq!(res.users[0].protocol, "gitter");
+            assert_eq!(res.users[0].fields["user"], "jim");
+        }
+    }
 }
@@ -14,9 +14,10 @@ pub mod v3 {
         metadata,
         serde::Raw,
         to_device::DeviceIdOrAllDevices,
-        OwnedTransactionId, OwnedUserId,
+        OwnedDeviceId, OwnedTransactionId, OwnedUserId,
     };
-    use ruma_events::{AnyToDeviceEventContent, ToDeviceEventType};
+    use ruma_events::{AnyToDeviceEventContent, ToDeviceEventContent, ToDeviceEventType};
+    use serde_json::value::to_raw_value as to_raw_json_value;
 
     const METADATA: Metadata = metadata! {
         method: PUT,
@@ -68,6 +69,45 @@ pub mod v3 {
         ) -> Self {
             Self { event_type, txn_id, messages }
         }
+
+        /// Creates a new `Request` with the given transaction ID, to send the given content to all
+        /// the given recipients.
+        ///
+        /// The event type is derived from the content.
+        pub fn new_typed<T, I>(
+            txn_id: OwnedTransactionId,
+            content: &T,
+            recipients: I,
+        ) -> serde_json::Result<Self>
+        where
+            T: ToDeviceEventContent,
+            I: IntoIterator<Item = (OwnedUserId, DeviceIdOrAllDevices)>,
+        {
+            let raw_content = Raw::from_json(to_raw_json_value(content)?);
+
+            let mut messages = Messages::new();
+            for (user_id, device) in recipients {
+                messages.entry(user_id).or_default().insert(device, raw_content.clone());
+            }
+
+            Ok(Self { event_type: content.event_type(), txn_id, messages })
+        }
+
+        /// Creates a new `Request` with the given transaction ID, to send the given content to a
+        /// single device.
+        ///
+        /// The event type is derived from the content.
+        pub fn new_for_device<T>(
+            txn_id: OwnedTransactionId,
+            user_id: OwnedUserId,
+            device_id: OwnedDeviceId,
+            content: &T,
+        ) -> serde_json::Result<Self>
+        where
+            T: ToDeviceEventContent,
+        {
+            Self::new_typed(txn_id, content, [(user_id, device_id.into())])
+        }
     }
 
     impl Response {
@@ -82,4 +122,68 @@ pub mod v3 {
     /// Represented as a map of `{ user-ids => { device-ids => message-content } }`.
     pub type Messages =
         BTreeMap<OwnedUserId, BTreeMap<DeviceIdOrAllDevices, Raw<AnyToDeviceEventContent>>>;
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use ruma_common::{
+            api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+            owned_device_id, owned_user_id,
+            to_device::DeviceIdOrAllDevices,
+        };
+        use ruma_events::dummy::ToDeviceDummyEventContent;
+        use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+        use super::Request;
+
+        #[test]
+        fn serialize_typed_request() {
+            let req = Request::new_typed(
+                "1234".into(),
+                &ToDeviceDummyEventContent::new(),
+                [
+                    (owned_user_id!("@alice:example.org"), owned_device_id!("ALICEDEV").into()),
+                    (owned_user_id!("@alice:example.org"), owned_device_id!("OTHERDEV").into()),
+                    (owned_user_id!("@bob:example.org"), DeviceIdOrAllDevices::AllDevices),
+                ],
+            )
+            .unwrap();
+            let http_req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://homeserver.tld",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(http_req.uri().path(), "/_matrix/client/v3/sendToDevice/m.dummy/1234");
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({
+                    "messages": {
+                        "@alice:example.org": { "ALICEDEV": {}, "OTHERDEV": {} },
+                        "@bob:example.org": { "*": {} },
+                    },
+                })
+            );
+        }
+
+        #[test]
+        fn new_for_device() {
+            let req = Request::new_for_device(
+                "txn".into(),
+                owned_user_id!("@alice:example.org"),
+                owned_device_id!("ALICEDEV"),
+                &ToDeviceDummyEventContent::new(),
+            )
+            .unwrap();
+
+            assert_eq!(req.event_type.to_string(), "m.dummy");
+            assert_eq!(req.messages.len(), 1);
+            let devices = &req.messages[&owned_user_id!("@alice:example.org")];
+            assert_eq!(devices.len(), 1);
+            assert!(
+                devices.contains_key(&DeviceIdOrAllDevices::DeviceId(owned_device_id!("ALICEDEV")))
+            );
+        }
+    }
 }
@@ -107,4 +107,34 @@ pub mod v3 {
             }
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use std::time::Duration;
+
+        use assert_matches2::assert_matches;
+        use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+        use super::Typing;
+
+        #[test]
+        fn typing_serde() {
+            assert_eq!(
+                to_json_value(Typing::Yes(Duration::from_secs(30))).unwrap(),
+                json!({ "typing": true, "timeout": 30_000 })
+            );
+            assert_eq!(to_json_value(Typing::No).unwrap(), json!({ "typing": false }));
+
+            assert_matches!(
+                from_json_value(json!({ "typing": true, "timeout": 5_000 })),
+                Ok(Typing::Yes(timeout))
+            );
+            assert_eq!(timeout, Duration::from_secs(5));
+            assert_matches!(
+                from_json_value(json!({ "typing": false, "timeout": 5_000 })),
+                Ok(Typing::No)
+            );
+            from_json_value::<Typing>(json!({ "typing": true })).unwrap_err();
+        }
+    }
 }
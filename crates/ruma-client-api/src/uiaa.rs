@@ -94,7 +94,9 @@ impl AuthData {
             "m.login.email.identity" => Self::EmailIdentity(deserialize_variant(session, data)?),
             "m.login.msisdn" => Self::Msisdn(deserialize_variant(session, data)?),
             "m.login.dummy" => Self::Dummy(deserialize_variant(session, data)?),
-            "m.registration_token" => Self::RegistrationToken(deserialize_variant(session, data)?),
+            "m.login.registration_token" => {
+                Self::RegistrationToken(deserialize_variant(session, data)?)
+            }
             "m.login.terms" => Self::Terms(deserialize_variant(session, data)?),
             _ => {
                 Self::_Custom(CustomAuthData { auth_type: auth_type.into(), session, extra: data })
@@ -146,10 +148,14 @@ impl AuthData {
     /// used for custom auth types only.
     pub fn data(&self) -> Cow<'_, JsonObject> {
         fn serialize<T: Serialize>(obj: T) -> JsonObject {
-            match serde_json::to_value(obj).expect("auth data serialization to succeed") {
-                JsonValue::Object(obj) => obj,
-                _ => panic!("all auth data variants must serialize to objects"),
-            }
+            let mut obj =
+                match serde_json::to_value(obj).expect("auth data serialization to succeed") {
+                    JsonValue::Object(obj) => obj,
+                    _ => panic!("all auth data variants must serialize to objects"),
+                };
+            obj.remove("type");
+            obj.remove("session");
+            obj
         }
 
         match self {
@@ -55,6 +55,23 @@ fn deserialize_auth_data_registration_token() {
     assert_eq!(data.session.as_deref(), Some("session"));
 }
 
+#[test]
+fn auth_data_new_registration_token() {
+    let data = json!({ "token": "mytoken" });
+    let auth_data = AuthData::new(
+        "m.login.registration_token",
+        Some("session".to_owned()),
+        from_json_value(data.clone()).unwrap(),
+    )
+    .unwrap();
+
+    assert_matches!(&auth_data, AuthData::RegistrationToken(token));
+    assert_eq!(token.token, "mytoken");
+    assert_eq!(auth_data.auth_type(), Some(AuthType::RegistrationToken));
+    assert_eq!(auth_data.session(), Some("session"));
+    assert_eq!(JsonValue::Object(auth_data.data().into_owned()), data);
+}
+
 #[test]
 fn serialize_auth_data_fallback() {
     let auth_data =
@@ -9,14 +9,13 @@ use http::{
     header::{self, HeaderName, HeaderValue},
     Method,
 };
-use percent_encoding::utf8_percent_encode;
 use tracing::warn;
 
 use super::{
     error::{IntoHttpError, UnknownVersionError},
     AuthScheme, SendAccessToken,
 };
-use crate::{percent_encode::PATH_PERCENT_ENCODE_SET, serde::slice_to_buf, RoomVersionId};
+use crate::{serde::slice_to_buf, PathSegmentEncoded, RoomVersionId};
 
 /// Metadata about an API endpoint.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -111,7 +110,7 @@ impl Metadata {
                     .next()
                     .expect("number of placeholders must match number of arguments")
                     .to_string();
-                let arg = utf8_percent_encode(&arg, PATH_PERCENT_ENCODE_SET);
+                let arg = PathSegmentEncoded(&arg);
 
                 write!(res, "/{arg}").expect("writing to a String using fmt::Write can't fail");
             } else {
@@ -172,3 +172,65 @@ pub enum KeyUsage {
     #[doc(hidden)]
     _Custom(PrivOwnedStr),
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use assert_matches2::assert_matches;
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{OneTimeKey, SignedKey};
+    use crate::{owned_device_key_id, owned_user_id, serde::Base64};
+
+    #[test]
+    fn unsigned_one_time_key_serde() {
+        let json = json!("zKbLg+NrIjpnagy+pIY6uPL4ZwEG2v+8F9lmgsnlZzs");
+
+        let key = from_json_value::<OneTimeKey>(json.clone()).unwrap();
+        assert_matches!(&key, OneTimeKey::Key(key_str));
+        assert_eq!(key_str, "zKbLg+NrIjpnagy+pIY6uPL4ZwEG2v+8F9lmgsnlZzs");
+        assert_eq!(to_json_value(&key).unwrap(), json);
+    }
+
+    #[test]
+    fn signed_one_time_key_serde() {
+        let json = json!({
+            "key": "zKbLg+NrIjpnagy+pIY6uPL4ZwEG2v+8F9lmgsnlZzs",
+            "signatures": {
+                "@alice:example.org": {
+                    "ed25519:JLAFKJWSCS": "FLWxXqGbwrb8SM3Y795eB6OA8bwBcoMZFXBqnTn58AYWZSqiD45tlBVcDa2L7RwdKXebW/VzDlnfVJ+9jok1Bw",
+                },
+            },
+        });
+
+        let key = from_json_value::<OneTimeKey>(json.clone()).unwrap();
+        assert_matches!(&key, OneTimeKey::SignedKey(signed_key));
+        assert_eq!(signed_key.key.encode(), "zKbLg+NrIjpnagy+pIY6uPL4ZwEG2v+8F9lmgsnlZzs");
+        assert!(!signed_key.fallback);
+        assert_eq!(to_json_value(&key).unwrap(), json);
+    }
+
+    #[test]
+    fn fallback_key_serialization() {
+        let signatures = BTreeMap::from([(
+            owned_user_id!("@alice:example.org"),
+            BTreeMap::from([(owned_device_key_id!("ed25519:JLAFKJWSCS"), "sig".to_owned())]),
+        )]);
+        let key = OneTimeKey::SignedKey(SignedKey::new_fallback(
+            Base64::parse("zKbLg+NrIjpnagy+pIY6uPL4ZwEG2v+8F9lmgsnlZzs").unwrap(),
+            signatures,
+        ));
+
+        assert_eq!(
+            to_json_value(&key).unwrap(),
+            json!({
+                "key": "zKbLg+NrIjpnagy+pIY6uPL4ZwEG2v+8F9lmgsnlZzs",
+                "signatures": {
+                    "@alice:example.org": { "ed25519:JLAFKJWSCS": "sig" },
+                },
+                "fallback": true,
+            })
+        );
+    }
+}
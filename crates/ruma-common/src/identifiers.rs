@@ -7,7 +7,7 @@
 #[doc(inline)]
 pub use ruma_identifiers_validation::error::{
     Error as IdParseError, MatrixIdError, MatrixToError, MatrixUriError, MxcUriError,
-    VoipVersionIdError,
+    ServerNameError, VoipVersionIdError,
 };
 use serde::de::{self, Deserializer, Unexpected};
 
@@ -31,7 +31,7 @@ pub use self::{
     room_id::{OwnedRoomId, RoomId},
     room_or_alias_id::{OwnedRoomOrAliasId, RoomOrAliasId},
     room_version_id::RoomVersionId,
-    server_name::{OwnedServerName, ServerName},
+    server_name::{Host, OwnedServerName, ServerName},
     session_id::{OwnedSessionId, SessionId},
     signatures::{DeviceSignatures, EntitySignatures, ServerSignatures, Signatures},
     transaction_id::{OwnedTransactionId, TransactionId},
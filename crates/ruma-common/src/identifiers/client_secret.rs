@@ -31,9 +31,35 @@ impl ClientSecret {
 #[cfg(test)]
 mod tests {
     use super::ClientSecret;
+    use crate::IdParseError;
 
     #[test]
     fn valid_secret() {
         <&ClientSecret>::try_from("this_=_a_valid_secret_1337").unwrap();
     }
+
+    #[test]
+    fn invalid_secret() {
+        assert_eq!(<&ClientSecret>::try_from("").unwrap_err(), IdParseError::Empty);
+        assert_eq!(
+            <&ClientSecret>::try_from("not a valid secret").unwrap_err(),
+            IdParseError::InvalidCharacter { index: 3, character: ' ' }
+        );
+        assert_eq!(
+            <&ClientSecret>::try_from("sécret").unwrap_err(),
+            IdParseError::InvalidCharacter { index: 1, character: 'é' }
+        );
+        assert_eq!(
+            <&ClientSecret>::try_from("a".repeat(256).as_str()).unwrap_err(),
+            IdParseError::MaximumLengthExceeded
+        );
+        <&ClientSecret>::try_from("a".repeat(255).as_str()).unwrap();
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_secret() {
+        let secret = ClientSecret::new();
+        ClientSecret::parse(secret.as_str()).unwrap();
+    }
 }
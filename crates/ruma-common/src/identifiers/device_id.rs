@@ -15,7 +15,7 @@ use super::generate_localpart;
 ///
 /// # #[cfg(feature = "rand")] {
 /// let random_id = DeviceId::new();
-/// assert_eq!(random_id.as_str().len(), 8);
+/// assert_eq!(random_id.as_str().len(), 10);
 /// # }
 ///
 /// let static_id = device_id!("01234567");
@@ -33,10 +33,12 @@ pub struct DeviceId(str);
 
 impl DeviceId {
     /// Generates a random `DeviceId`, suitable for assignment to a new device.
+    ///
+    /// The generated ID consists of 10 uppercase ASCII letters and digits.
     #[cfg(feature = "rand")]
     #[allow(clippy::new_ret_no_self)]
     pub fn new() -> OwnedDeviceId {
-        Self::from_borrowed(&generate_localpart(8)).to_owned()
+        Self::from_borrowed(&generate_localpart(10).to_uppercase()).to_owned()
     }
 }
 
@@ -47,7 +49,14 @@ mod tests {
     #[cfg(feature = "rand")]
     #[test]
     fn generate_device_id() {
-        assert_eq!(DeviceId::new().as_str().len(), 8);
+        for _ in 0..100 {
+            let device_id = DeviceId::new();
+            assert_eq!(device_id.as_str().len(), 10);
+            assert!(device_id
+                .as_str()
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()));
+        }
     }
 
     #[test]
@@ -68,4 +77,13 @@ mod tests {
         let device_id: OwnedDeviceId = box_str.into();
         assert_eq!(device_id.as_str(), "ijklmnop");
     }
+
+    #[test]
+    fn deserialize_borrowed_device_id() {
+        let json = r#""ijklmnop""#;
+        let device_id = serde_json::from_str::<&DeviceId>(json).unwrap();
+
+        assert_eq!(device_id.as_str(), "ijklmnop");
+        assert!(std::ptr::eq(device_id.as_str(), &json[1..json.len() - 1]));
+    }
 }
@@ -99,4 +99,21 @@ mod tests {
         let device_key_id = <&DeviceKeyId>::try_from("ed25519:JLAFKJWSCS").unwrap();
         assert_eq!(device_key_id.device_id(), "JLAFKJWSCS");
     }
+
+    #[test]
+    fn unknown_key_algorithm() {
+        let device_key_id = <&DeviceKeyId>::try_from("org.example.custom:JLAFKJWSCS").unwrap();
+        assert_eq!(device_key_id.algorithm().as_ref(), "org.example.custom");
+        assert_eq!(device_key_id.device_id(), "JLAFKJWSCS");
+
+        let serialized = serde_json::to_value(device_key_id).unwrap();
+        assert_eq!(serialized, serde_json::json!("org.example.custom:JLAFKJWSCS"));
+
+        let deserialized: OwnedDeviceKeyId = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, device_key_id);
+        assert_eq!(
+            DeviceKeyId::from_parts(deserialized.algorithm(), deserialized.device_id()),
+            device_key_id
+        );
+    }
 }
@@ -2,7 +2,8 @@
 
 use ruma_macros::IdZst;
 
-use super::ServerName;
+use super::{IdParseError, RoomVersionId, ServerName};
+use crate::room_version_rules::EventFormatVersion;
 
 /// A Matrix [event ID].
 ///
@@ -52,6 +53,29 @@ impl EventId {
         Self::from_borrowed(&format!("${}:{server_name}", super::generate_localpart(18))).to_owned()
     }
 
+    /// Try parsing a `&str` into an `OwnedEventId`, only accepting the event ID format used by the
+    /// given room version.
+    ///
+    /// Room versions 1 and 2 use the original format, room version 3 uses standard base64 and
+    /// later room versions use URL-safe base64. Custom room versions accept any format, like
+    /// [`EventId::parse()`].
+    pub fn parse_for_room_version(
+        s: impl AsRef<str>,
+        room_version: &RoomVersionId,
+    ) -> Result<OwnedEventId, IdParseError> {
+        use ruma_identifiers_validation::event_id;
+
+        let s = s.as_ref();
+        match room_version.rules().map(|rules| rules.event_format) {
+            Some(EventFormatVersion::V1) => event_id::validate_original(s)?,
+            Some(EventFormatVersion::V2) => event_id::validate_base64(s)?,
+            Some(EventFormatVersion::V3) => event_id::validate_url_safe_base64(s)?,
+            None => event_id::validate(s)?,
+        }
+
+        Ok(Self::from_borrowed(s).to_owned())
+    }
+
     /// Returns the event's unique ID.
     ///
     /// For the original event format as used by Matrix room versions 1 and 2, this is the
@@ -77,7 +101,7 @@ impl EventId {
 #[cfg(test)]
 mod tests {
     use super::{EventId, OwnedEventId};
-    use crate::IdParseError;
+    use crate::{IdParseError, RoomVersionId, ServerNameError};
 
     #[test]
     fn valid_original_event_id() {
@@ -105,16 +129,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_original_event_id_for_room_version() {
+        let id = "$39hvsi03hlne:example.com";
+
+        let event_id = EventId::parse_for_room_version(id, &RoomVersionId::V1).unwrap();
+        assert_eq!(event_id, id);
+        assert_eq!(event_id.server_name().unwrap(), "example.com");
+        assert_eq!(
+            EventId::parse_for_room_version(id, &RoomVersionId::V3).unwrap_err(),
+            IdParseError::InvalidCharacter { index: 13, character: ':' }
+        );
+        assert_eq!(
+            EventId::parse_for_room_version("$39hvsi03hlne", &RoomVersionId::V2).unwrap_err(),
+            IdParseError::MissingColon
+        );
+    }
+
+    #[test]
+    fn parse_base64_event_id_for_room_version() {
+        let id = "$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk+";
+
+        let event_id = EventId::parse_for_room_version(id, &RoomVersionId::V3).unwrap();
+        assert_eq!(event_id, id);
+        assert_eq!(event_id.server_name(), None);
+        assert_eq!(
+            EventId::parse_for_room_version(id, &RoomVersionId::V4).unwrap_err(),
+            IdParseError::InvalidCharacter { index: 27, character: '/' }
+        );
+        assert_eq!(
+            EventId::parse_for_room_version(id, &RoomVersionId::V1).unwrap_err(),
+            IdParseError::MissingColon
+        );
+    }
+
+    #[test]
+    fn parse_url_safe_base64_event_id_for_room_version() {
+        let id = "$Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Zg";
+
+        let event_id = EventId::parse_for_room_version(id, &RoomVersionId::V6).unwrap();
+        assert_eq!(event_id, id);
+        assert_eq!(event_id.localpart(), &id[1..]);
+        assert_eq!(event_id.server_name(), None);
+        assert_eq!(
+            EventId::parse_for_room_version(id, &RoomVersionId::V3).unwrap_err(),
+            IdParseError::InvalidCharacter { index: 5, character: '-' }
+        );
+    }
+
+    #[test]
+    fn parse_padded_base64_event_id_for_room_version() {
+        assert_eq!(
+            EventId::parse_for_room_version(
+                "$acR1l0raoZnm60CBwAVgqbZqoOmYU81xysh1u7XcJk=",
+                &RoomVersionId::V3
+            )
+            .unwrap_err(),
+            IdParseError::InvalidCharacter { index: 43, character: '=' }
+        );
+        assert_eq!(
+            EventId::parse_for_room_version(
+                "$Rqnc-F-dvnEYJTyHq_iKxU2bZ1CI92-kuZq3a5lr5Z=",
+                &RoomVersionId::V10
+            )
+            .unwrap_err(),
+            IdParseError::InvalidCharacter { index: 43, character: '=' }
+        );
+    }
+
+    #[test]
+    fn parse_empty_event_id_for_room_version() {
+        assert_eq!(
+            EventId::parse_for_room_version("$", &RoomVersionId::V11).unwrap_err(),
+            IdParseError::Empty
+        );
+    }
+
     #[cfg(feature = "rand")]
     #[test]
     fn generate_random_valid_event_id() {
         use crate::server_name;
 
-        let event_id = EventId::new(server_name!("example.com"));
-        let id_str = event_id.as_str();
+        for _ in 0..100 {
+            let event_id = EventId::new(server_name!("example.com"));
+            let id_str = event_id.as_str();
 
-        assert!(id_str.starts_with('$'));
-        assert_eq!(id_str.len(), 31);
+            assert!(id_str.starts_with('$'));
+            assert_eq!(id_str.len(), 31);
+            assert_eq!(
+                EventId::parse_for_room_version(id_str, &RoomVersionId::V1).unwrap(),
+                event_id
+            );
+        }
     }
 
     #[test]
@@ -232,7 +338,7 @@ mod tests {
     fn invalid_event_id_host() {
         assert_eq!(
             <&EventId>::try_from("$39hvsi03hlne:/").unwrap_err(),
-            IdParseError::InvalidServerName
+            IdParseError::InvalidServerName(ServerNameError::InvalidHost)
         );
     }
 
@@ -240,7 +346,7 @@ mod tests {
     fn invalid_event_id_port() {
         assert_eq!(
             <&EventId>::try_from("$39hvsi03hlne:example.com:notaport").unwrap_err(),
-            IdParseError::InvalidServerName
+            IdParseError::InvalidServerName(ServerNameError::InvalidPort)
         );
     }
 }
@@ -795,6 +795,32 @@ mod tests {
         assert_eq!(matrix_to.via().len(), 0);
     }
 
+    #[test]
+    fn matrixtouri_roundtrip() {
+        #[allow(deprecated)]
+        let uris = [
+            user_id!("@jplatte:notareal.hs").matrix_to_uri(),
+            room_alias_id!("#ruma:notareal.hs").matrix_to_uri(),
+            room_id!("!ruma:notareal.hs").matrix_to_uri_via(vec![
+                server_name!("notareal.hs"),
+                server_name!("anotherunreal.hs"),
+            ]),
+            room_alias_id!("#ruma:notareal.hs")
+                .matrix_to_event_uri(event_id!("$event:notareal.hs")),
+            room_id!("!ruma:notareal.hs").matrix_to_event_uri_via(
+                event_id!("$event:notareal.hs"),
+                vec![server_name!("notareal.hs")],
+            ),
+        ];
+
+        for uri in uris {
+            let parsed =
+                MatrixToUri::parse(&uri.to_string()).expect("Failed to parse MatrixToUri.");
+            assert_eq!(parsed, uri);
+            assert_eq!(parsed.to_string(), uri.to_string());
+        }
+    }
+
     #[test]
     fn parse_matrixtouri_wrong_base_url() {
         assert_eq!(MatrixToUri::parse("").unwrap_err(), MatrixToError::WrongBaseUrl.into());
@@ -1056,6 +1082,39 @@ mod tests {
         assert_eq!(matrix_uri.action(), Some(&UriAction::Join));
     }
 
+    #[test]
+    fn parse_matrixuri_custom_action() {
+        let matrix_uri = MatrixUri::parse("matrix:r/ruma:notareal.hs?action=org.example.dance")
+            .expect("Failed to create MatrixUri.");
+        assert_eq!(matrix_uri.id(), &room_alias_id!("#ruma:notareal.hs").into());
+        assert_eq!(matrix_uri.action().unwrap().as_str(), "org.example.dance");
+        assert_eq!(matrix_uri.to_string(), "matrix:r/ruma:notareal.hs?action=org.example.dance");
+    }
+
+    #[test]
+    fn matrixuri_roundtrip() {
+        #[allow(deprecated)]
+        let uris = [
+            user_id!("@jplatte:notareal.hs").matrix_uri(true),
+            room_alias_id!("#ruma:notareal.hs").matrix_uri(false),
+            room_id!("!ruma:notareal.hs").matrix_uri_via(
+                vec![server_name!("notareal.hs"), server_name!("anotherunreal.hs")],
+                true,
+            ),
+            room_alias_id!("#ruma:notareal.hs").matrix_event_uri(event_id!("$event:notareal.hs")),
+            room_id!("!ruma:notareal.hs").matrix_event_uri_via(
+                event_id!("$event:notareal.hs"),
+                vec![server_name!("notareal.hs")],
+            ),
+        ];
+
+        for uri in uris {
+            let parsed = MatrixUri::parse(&uri.to_string()).expect("Failed to parse MatrixUri.");
+            assert_eq!(parsed, uri);
+            assert_eq!(parsed.to_string(), uri.to_string());
+        }
+    }
+
     #[test]
     fn parse_matrixuri_invalid_uri() {
         assert_eq!(
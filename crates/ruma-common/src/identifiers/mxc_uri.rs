@@ -109,4 +109,15 @@ mod tests {
             Ok(("server".try_into().expect("Failed to create ServerName"), "1234id"))
         );
     }
+
+    #[test]
+    fn deserialize_invalid_mxc_uri() {
+        let mxc = serde_json::from_str::<OwnedMxcUri>(r#""https://server/1234id""#)
+            .expect("Failed to convert JSON to MxcUri");
+
+        assert_eq!(mxc.as_str(), "https://server/1234id");
+        assert!(!mxc.is_valid());
+        assert_eq!(mxc.server_name(), Err(MxcUriError::WrongSchema));
+        assert_eq!(mxc.media_id(), Err(MxcUriError::WrongSchema));
+    }
 }
@@ -67,7 +67,7 @@ impl RoomAliasId {
 #[cfg(test)]
 mod tests {
     use super::{OwnedRoomAliasId, RoomAliasId};
-    use crate::IdParseError;
+    use crate::{IdParseError, ServerNameError};
 
     #[test]
     fn valid_room_alias_id() {
@@ -158,7 +158,7 @@ mod tests {
     fn invalid_room_alias_id_host() {
         assert_eq!(
             <&RoomAliasId>::try_from("#ruma:/").unwrap_err(),
-            IdParseError::InvalidServerName
+            IdParseError::InvalidServerName(ServerNameError::InvalidHost)
         );
     }
 
@@ -166,7 +166,7 @@ mod tests {
     fn invalid_room_alias_id_port() {
         assert_eq!(
             <&RoomAliasId>::try_from("#ruma:example.com:notaport").unwrap_err(),
-            IdParseError::InvalidServerName
+            IdParseError::InvalidServerName(ServerNameError::InvalidPort)
         );
     }
 }
@@ -202,6 +202,8 @@ impl RoomId {
 
 #[cfg(test)]
 mod tests {
+    use std::{rc::Rc, sync::Arc};
+
     use super::{OwnedRoomId, RoomId};
     use crate::{server_name, IdParseError};
 
@@ -222,11 +224,45 @@ mod tests {
     #[cfg(feature = "rand")]
     #[test]
     fn generate_random_valid_room_id() {
-        let room_id = RoomId::new(server_name!("example.com"));
-        let id_str = room_id.as_str();
+        for _ in 0..100 {
+            let room_id = RoomId::new(server_name!("example.com"));
+            let id_str = room_id.as_str();
+
+            assert!(id_str.starts_with('!'));
+            assert_eq!(id_str.len(), 31);
+            assert_eq!(<&RoomId>::try_from(id_str).unwrap(), room_id);
+        }
+    }
+
+    #[test]
+    fn parse_arc_room_id_does_not_allocate() {
+        let s: Arc<str> = "!29fhd83h92h0:example.com".into();
+        let room_id = RoomId::parse_arc(s.clone()).expect("Failed to create RoomId.");
+
+        assert_eq!(room_id.as_str(), "!29fhd83h92h0:example.com");
+        assert!(std::ptr::eq(room_id.as_str(), &*s));
+        assert_eq!(Arc::strong_count(&s), 2);
+    }
+
+    #[test]
+    fn parse_rc_room_id_does_not_allocate() {
+        let s: Rc<str> = "!29fhd83h92h0:example.com".into();
+        let room_id = RoomId::parse_rc(s.clone()).expect("Failed to create RoomId.");
+
+        assert_eq!(room_id.as_str(), "!29fhd83h92h0:example.com");
+        assert!(std::ptr::eq(room_id.as_str(), &*s));
+        assert_eq!(Rc::strong_count(&s), 2);
+    }
+
+    #[test]
+    fn shared_room_id_from_ref() {
+        let room_id = <&RoomId>::try_from("!29fhd83h92h0:example.com").unwrap();
+
+        let arc_room_id = Arc::<RoomId>::from(room_id);
+        assert_eq!(*arc_room_id, *room_id);
 
-        assert!(id_str.starts_with('!'));
-        assert_eq!(id_str.len(), 31);
+        let rc_room_id = Rc::<RoomId>::from(room_id);
+        assert_eq!(*rc_room_id, *room_id);
     }
 
     #[test]
@@ -59,6 +59,33 @@ impl RoomOrAliasId {
         self.variant() == Variant::RoomAliasId
     }
 
+    /// Returns this identifier as a room ID, if it is one.
+    ///
+    /// This doesn't allocate, the returned `RoomId` borrows the same string slice.
+    pub fn as_room_id(&self) -> Option<&RoomId> {
+        self.try_into().ok()
+    }
+
+    /// Returns this identifier as a room alias ID, if it is one.
+    ///
+    /// This doesn't allocate, the returned `RoomAliasId` borrows the same string slice.
+    pub fn as_room_alias_id(&self) -> Option<&RoomAliasId> {
+        self.try_into().ok()
+    }
+
+    /// Turn this `RoomOrAliasId` into `Either<&RoomId, &RoomAliasId>`.
+    ///
+    /// This doesn't allocate, the returned ID borrows the same string slice.
+    #[cfg(feature = "either")]
+    pub fn either(&self) -> either::Either<&RoomId, &RoomAliasId> {
+        match self.variant() {
+            Variant::RoomId => either::Either::Left(RoomId::from_borrowed(self.as_str())),
+            Variant::RoomAliasId => {
+                either::Either::Right(RoomAliasId::from_borrowed(self.as_str()))
+            }
+        }
+    }
+
     fn variant(&self) -> Variant {
         match self.as_bytes().first() {
             Some(b'!') => Variant::RoomId,
@@ -149,7 +176,7 @@ impl TryFrom<OwnedRoomOrAliasId> for OwnedRoomAliasId {
 #[cfg(test)]
 mod tests {
     use super::{OwnedRoomOrAliasId, RoomOrAliasId};
-    use crate::IdParseError;
+    use crate::{IdParseError, RoomAliasId, RoomId};
 
     #[test]
     fn valid_room_id_or_alias_id_with_a_room_alias_id() {
@@ -203,6 +230,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn as_room_id_borrows() {
+        let id = <&RoomOrAliasId>::try_from("!29fhd83h92h0:example.com").unwrap();
+
+        let room_id = id.as_room_id().unwrap();
+        assert_eq!(room_id, "!29fhd83h92h0:example.com");
+        assert!(std::ptr::eq(room_id.as_str(), id.as_str()));
+        assert!(id.as_room_alias_id().is_none());
+    }
+
+    #[test]
+    fn as_room_alias_id_borrows() {
+        let id = <&RoomOrAliasId>::try_from("#ruma:example.com").unwrap();
+
+        let room_alias_id = id.as_room_alias_id().unwrap();
+        assert_eq!(room_alias_id, "#ruma:example.com");
+        assert!(std::ptr::eq(room_alias_id.as_str(), id.as_str()));
+        assert!(id.as_room_id().is_none());
+    }
+
+    #[cfg(feature = "either")]
+    #[test]
+    fn either_borrows() {
+        use assert_matches2::assert_matches;
+        use either::Either;
+
+        let id = <&RoomOrAliasId>::try_from("!29fhd83h92h0:example.com").unwrap();
+        assert_matches!(id.either(), Either::Left(room_id));
+        assert_eq!(room_id, "!29fhd83h92h0:example.com");
+        assert!(std::ptr::eq(room_id.as_str(), id.as_str()));
+
+        let id = <&RoomOrAliasId>::try_from("#ruma:example.com").unwrap();
+        assert_matches!(id.either(), Either::Right(room_alias_id));
+        assert_eq!(room_alias_id, "#ruma:example.com");
+        assert!(std::ptr::eq(room_alias_id.as_str(), id.as_str()));
+    }
+
+    #[test]
+    fn borrowed_conversions_with_a_room_id() {
+        let room_id = <&RoomId>::try_from("!29fhd83h92h0:example.com").unwrap();
+
+        let id = <&RoomOrAliasId>::from(room_id);
+        assert!(id.is_room_id());
+        assert!(std::ptr::eq(id.as_str(), room_id.as_str()));
+
+        let back = <&RoomId>::try_from(id).unwrap();
+        assert!(std::ptr::eq(back.as_str(), room_id.as_str()));
+        assert_eq!(<&RoomAliasId>::try_from(id).unwrap_err(), room_id);
+    }
+
+    #[test]
+    fn borrowed_conversions_with_a_room_alias_id() {
+        let room_alias_id = <&RoomAliasId>::try_from("#ruma:example.com").unwrap();
+
+        let id = <&RoomOrAliasId>::from(room_alias_id);
+        assert!(id.is_room_alias_id());
+        assert!(std::ptr::eq(id.as_str(), room_alias_id.as_str()));
+
+        let back = <&RoomAliasId>::try_from(id).unwrap();
+        assert!(std::ptr::eq(back.as_str(), room_alias_id.as_str()));
+        assert_eq!(<&RoomId>::try_from(id).unwrap_err(), room_alias_id);
+    }
+
     #[test]
     fn deserialize_valid_room_id_or_alias_id_with_a_room_alias_id() {
         assert_eq!(
@@ -221,4 +311,50 @@ mod tests {
                 .expect("Failed to create RoomAliasId.")
         );
     }
+
+    #[test]
+    fn deserialize_borrowed_room_id_or_alias_id() {
+        let json = r##""#ruma:example.com""##;
+        let id = serde_json::from_str::<&RoomOrAliasId>(json)
+            .expect("Failed to convert JSON to RoomOrAliasId");
+
+        assert_eq!(id, "#ruma:example.com");
+        assert!(std::ptr::eq(id.as_str(), &json[1..json.len() - 1]));
+    }
+
+    #[test]
+    fn deserialize_borrowed_room_id_or_alias_id_with_escapes() {
+        let json = r#""\u0023ruma:example.com""#;
+
+        // Strings with escape sequences can't be borrowed, the owned form needs to be used.
+        serde_json::from_str::<&RoomOrAliasId>(json).unwrap_err();
+        assert_eq!(
+            serde_json::from_str::<OwnedRoomOrAliasId>(json)
+                .expect("Failed to convert JSON to RoomOrAliasId"),
+            "#ruma:example.com"
+        );
+    }
+
+    #[test]
+    fn deserialize_borrowed_invalid_room_id_or_alias_id() {
+        serde_json::from_str::<&RoomOrAliasId>(r#""ruma:example.com""#).unwrap_err();
+    }
+
+    #[test]
+    fn deserialize_struct_with_borrowed_ids() {
+        #[derive(serde::Deserialize)]
+        struct Borrowed<'a> {
+            #[serde(borrow)]
+            room: &'a RoomOrAliasId,
+            #[serde(borrow)]
+            room_id: &'a RoomId,
+        }
+
+        let json = r##"{ "room": "#ruma:example.com", "room_id": "!29fhd83h92h0:example.com" }"##;
+        let borrowed = serde_json::from_str::<Borrowed<'_>>(json)
+            .expect("Failed to deserialize struct with borrowed IDs");
+
+        assert_eq!(borrowed.room, "#ruma:example.com");
+        assert_eq!(borrowed.room_id, "!29fhd83h92h0:example.com");
+    }
 }
@@ -6,6 +6,7 @@ use ruma_macros::DisplayAsRefStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::IdParseError;
+use crate::room_version_rules::RoomVersionRules;
 
 /// A Matrix [room version] ID.
 ///
@@ -88,6 +89,27 @@ impl RoomVersionId {
     pub fn as_bytes(&self) -> &[u8] {
         self.as_str().as_bytes()
     }
+
+    /// Get the [`RoomVersionRules`] for this `RoomVersionId`, if it matches a supported room
+    /// version.
+    ///
+    /// Returns `None` for custom room versions.
+    pub fn rules(&self) -> Option<RoomVersionRules> {
+        Some(match self {
+            Self::V1 => RoomVersionRules::V1,
+            Self::V2 => RoomVersionRules::V2,
+            Self::V3 => RoomVersionRules::V3,
+            Self::V4 => RoomVersionRules::V4,
+            Self::V5 => RoomVersionRules::V5,
+            Self::V6 => RoomVersionRules::V6,
+            Self::V7 => RoomVersionRules::V7,
+            Self::V8 => RoomVersionRules::V8,
+            Self::V9 => RoomVersionRules::V9,
+            Self::V10 => RoomVersionRules::V10,
+            Self::V11 => RoomVersionRules::V11,
+            Self::_Custom(_) => return None,
+        })
+    }
 }
 
 impl From<RoomVersionId> for String {
@@ -265,7 +287,13 @@ impl AsRef<str> for CustomRoomVersion {
 #[cfg(test)]
 mod tests {
     use super::RoomVersionId;
-    use crate::IdParseError;
+    use crate::{
+        room_version_rules::{
+            AuthorizationRules, EventFormatVersion, RedactionRules, RoomVersionRules,
+            StateResolutionVersion,
+        },
+        IdParseError,
+    };
 
     #[test]
     fn valid_version_1_room_version_id() {
@@ -391,7 +419,72 @@ mod tests {
         assert!(serde_json::from_str::<RoomVersionId>(r#""-""#).is_ok());
         assert_eq!(
             RoomVersionId::try_from("io_ruma_1").unwrap_err(),
-            IdParseError::InvalidCharacters
+            IdParseError::InvalidCharacter { index: 2, character: '_' }
         );
     }
+
+    #[test]
+    fn rules_of_stable_room_versions() {
+        let rules = RoomVersionId::V1.rules().unwrap();
+        assert_eq!(rules.event_format, EventFormatVersion::V1);
+        assert_eq!(rules.state_res, StateResolutionVersion::V1);
+        assert!(!rules.strict_canonical_json);
+        assert!(rules.authorization.special_case_room_redaction);
+
+        let rules = RoomVersionId::V3.rules().unwrap();
+        assert_eq!(rules.event_format, EventFormatVersion::V2);
+        assert_eq!(rules.state_res, StateResolutionVersion::V2);
+        assert_eq!(rules.authorization, AuthorizationRules::V3);
+        assert!(!rules.enforce_key_validity);
+
+        let rules = RoomVersionId::V5.rules().unwrap();
+        assert!(rules.enforce_key_validity);
+        assert!(rules.authorization.special_case_room_aliases);
+
+        let rules = RoomVersionId::V6.rules().unwrap();
+        assert_eq!(rules.event_format, EventFormatVersion::V3);
+        assert!(rules.strict_canonical_json);
+        assert!(!rules.knocking);
+        assert!(!rules.authorization.special_case_room_aliases);
+        assert!(rules.authorization.limit_notifications_power_levels);
+
+        let rules = RoomVersionId::V8.rules().unwrap();
+        assert!(rules.knocking);
+        assert!(rules.restricted_join_rule);
+        assert!(!rules.integer_power_levels);
+
+        let rules = RoomVersionId::V11.rules().unwrap();
+        assert_eq!(rules.redaction, RedactionRules::V11);
+        assert!(rules.authorization.use_room_create_sender);
+        assert_eq!(
+            RoomVersionRules {
+                authorization: AuthorizationRules::V6,
+                redaction: RedactionRules::V9,
+                ..rules
+            },
+            RoomVersionRules::V10
+        );
+    }
+
+    #[test]
+    fn no_rules_for_custom_room_version() {
+        let room_version = RoomVersionId::try_from("io.ruma.1").unwrap();
+        assert_eq!(room_version.rules(), None);
+        assert_eq!(RedactionRules::for_room_version(&room_version), RedactionRules::V11);
+    }
+
+    #[test]
+    fn redaction_rules_of_stable_room_versions() {
+        assert_eq!(RedactionRules::for_room_version(&RoomVersionId::V5), RedactionRules::V1);
+        assert_eq!(RedactionRules::for_room_version(&RoomVersionId::V7), RedactionRules::V6);
+        assert_eq!(RedactionRules::for_room_version(&RoomVersionId::V8), RedactionRules::V8);
+        assert_eq!(RedactionRules::for_room_version(&RoomVersionId::V10), RedactionRules::V9);
+
+        let rules = RedactionRules::V11;
+        assert!(!rules.keep_room_aliases_aliases);
+        assert!(rules.keep_room_join_rules_allow);
+        assert!(rules.keep_room_member_join_authorised_via_users_server);
+        assert!(rules.keep_room_create_content);
+        assert!(!rules.keep_origin_membership_prev_state);
+    }
 }
@@ -1,6 +1,6 @@
 //! Matrix-spec compliant server names.
 
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 use ruma_macros::IdZst;
 
@@ -49,11 +49,41 @@ impl ServerName {
     pub fn is_ip_literal(&self) -> bool {
         self.host().parse::<Ipv4Addr>().is_ok() || self.0.starts_with('[')
     }
+
+    /// Returns the host of the server name, parsed into a domain name or an IP address.
+    pub fn parsed_host(&self) -> Host<'_> {
+        let host = self.host();
+
+        if let Some(ipv6) = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+            Host::Ipv6(ipv6.parse().expect("IPv6 address was validated at parse time"))
+        } else if let Ok(ipv4) = host.parse() {
+            Host::Ipv4(ipv4)
+        } else {
+            Host::Domain(host)
+        }
+    }
+}
+
+/// The host of a [`ServerName`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum Host<'a> {
+    /// A domain name.
+    Domain(&'a str),
+
+    /// An IPv4 address.
+    Ipv4(Ipv4Addr),
+
+    /// An IPv6 address, without the surrounding brackets.
+    Ipv6(Ipv6Addr),
 }
 
 #[cfg(test)]
 mod tests {
-    use super::ServerName;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::{Host, ServerName};
+    use crate::{IdParseError, ServerNameError};
 
     #[test]
     fn ipv4_host() {
@@ -87,28 +117,54 @@ mod tests {
 
     #[test]
     fn empty_string() {
-        <&ServerName>::try_from("").unwrap_err();
+        assert_eq!(
+            <&ServerName>::try_from("").unwrap_err(),
+            IdParseError::InvalidServerName(ServerNameError::EmptyHost)
+        );
+    }
+
+    #[test]
+    fn empty_host() {
+        assert_eq!(
+            <&ServerName>::try_from(":8080").unwrap_err(),
+            IdParseError::InvalidServerName(ServerNameError::EmptyHost)
+        );
     }
 
     #[test]
     fn invalid_ipv6() {
-        <&ServerName>::try_from("[test::1]").unwrap_err();
+        assert_eq!(
+            <&ServerName>::try_from("[test::1]").unwrap_err(),
+            IdParseError::InvalidServerName(ServerNameError::InvalidHost)
+        );
     }
 
     #[test]
     fn ipv4_with_invalid_port() {
-        <&ServerName>::try_from("127.0.0.1:").unwrap_err();
+        assert_eq!(
+            <&ServerName>::try_from("127.0.0.1:").unwrap_err(),
+            IdParseError::InvalidServerName(ServerNameError::InvalidPort)
+        );
     }
 
     #[test]
     fn ipv6_with_invalid_port() {
-        <&ServerName>::try_from("[fe80::1]:100000").unwrap_err();
-        <&ServerName>::try_from("[fe80::1]!").unwrap_err();
+        assert_eq!(
+            <&ServerName>::try_from("[fe80::1]:100000").unwrap_err(),
+            IdParseError::InvalidServerName(ServerNameError::InvalidPort)
+        );
+        assert_eq!(
+            <&ServerName>::try_from("[fe80::1]!").unwrap_err(),
+            IdParseError::InvalidServerName(ServerNameError::InvalidHost)
+        );
     }
 
     #[test]
     fn dns_name_with_invalid_port() {
-        <&ServerName>::try_from("matrix.org:hello").unwrap_err();
+        assert_eq!(
+            <&ServerName>::try_from("matrix.org:hello").unwrap_err(),
+            IdParseError::InvalidServerName(ServerNameError::InvalidPort)
+        );
     }
 
     #[test]
@@ -152,4 +208,24 @@ mod tests {
         assert!(!server_name.is_ip_literal());
         assert_eq!(server_name.host(), "ruma.io");
     }
+
+    #[test]
+    fn parsed_host() {
+        let server_name = <&ServerName>::try_from("example.com:8448").unwrap();
+        assert_eq!(server_name.parsed_host(), Host::Domain("example.com"));
+        assert_eq!(server_name.port(), Some(8448));
+
+        let server_name = <&ServerName>::try_from("1.1.1.1").unwrap();
+        assert_eq!(server_name.parsed_host(), Host::Ipv4(Ipv4Addr::new(1, 1, 1, 1)));
+        assert_eq!(server_name.port(), None);
+
+        let server_name = <&ServerName>::try_from("[::1]:8448").unwrap();
+        assert_eq!(server_name.parsed_host(), Host::Ipv6(Ipv6Addr::LOCALHOST));
+        assert_eq!(server_name.host(), "[::1]");
+        assert_eq!(server_name.port(), Some(8448));
+
+        let server_name = <&ServerName>::try_from("[::1]").unwrap();
+        assert_eq!(server_name.parsed_host(), Host::Ipv6(Ipv6Addr::LOCALHOST));
+        assert_eq!(server_name.port(), None);
+    }
 }
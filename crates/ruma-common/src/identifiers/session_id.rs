@@ -2,46 +2,31 @@
 
 use ruma_macros::IdZst;
 
-use super::IdParseError;
-
 /// A session ID.
 ///
 /// Session IDs in Matrix are opaque character sequences of `[0-9a-zA-Z.=_-]`. Their length must
 /// must not exceed 255 characters.
 #[repr(transparent)]
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, IdZst)]
-#[ruma_id(validate = validate_session_id)]
+// Session IDs use the same grammar as client secrets.
+#[ruma_id(validate = ruma_identifiers_validation::client_secret::validate)]
 pub struct SessionId(str);
 
 impl SessionId {
     #[doc(hidden)]
     pub const fn _priv_const_new(s: &str) -> Result<&Self, &'static str> {
-        match validate_session_id(s) {
-            Ok(()) => Ok(Self::from_borrowed(s)),
-            Err(IdParseError::MaximumLengthExceeded) => {
-                Err("Invalid Session ID: exceeds 255 bytes")
-            }
-            Err(IdParseError::InvalidCharacters) => {
-                Err("Invalid Session ID: contains invalid characters")
-            }
-            Err(IdParseError::Empty) => Err("Invalid Session ID: empty"),
-            Err(_) => unreachable!(),
+        if s.len() > 255 {
+            Err("Invalid Session ID: exceeds 255 bytes")
+        } else if contains_invalid_byte(s.as_bytes()) {
+            Err("Invalid Session ID: contains invalid characters")
+        } else if s.is_empty() {
+            Err("Invalid Session ID: empty")
+        } else {
+            Ok(Self::from_borrowed(s))
         }
     }
 }
 
-const fn validate_session_id(s: &str) -> Result<(), IdParseError> {
-    if s.len() > 255 {
-        return Err(IdParseError::MaximumLengthExceeded);
-    } else if contains_invalid_byte(s.as_bytes()) {
-        return Err(IdParseError::InvalidCharacters);
-    } else if s.is_empty() {
-        return Err(IdParseError::Empty);
-    }
-
-    Ok(())
-}
-
 const fn contains_invalid_byte(mut bytes: &[u8]) -> bool {
     // non-const form:
     //
@@ -58,3 +43,27 @@ const fn contains_invalid_byte(mut bytes: &[u8]) -> bool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SessionId;
+    use crate::IdParseError;
+
+    #[test]
+    fn valid_session_id() {
+        <&SessionId>::try_from("abc.DEF=gh_i-2").unwrap();
+    }
+
+    #[test]
+    fn invalid_session_id() {
+        assert_eq!(<&SessionId>::try_from("").unwrap_err(), IdParseError::Empty);
+        assert_eq!(
+            <&SessionId>::try_from("a/b").unwrap_err(),
+            IdParseError::InvalidCharacter { index: 1, character: '/' }
+        );
+        assert_eq!(
+            <&SessionId>::try_from("a".repeat(256).as_str()).unwrap_err(),
+            IdParseError::MaximumLengthExceeded
+        );
+    }
+}
@@ -24,3 +24,31 @@ impl TransactionId {
         Self::from_borrowed(&id.simple().to_string()).to_owned()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{OwnedTransactionId, TransactionId};
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_transaction_id() {
+        let first = TransactionId::new();
+        let second = TransactionId::new();
+
+        assert_eq!(first.as_str().len(), 32);
+        assert!(first.as_str().bytes().all(|b| b.is_ascii_hexdigit()));
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn create_transaction_id_from_str() {
+        let ref_id: &TransactionId = "m1234.5678".into();
+        assert_eq!(ref_id.as_str(), "m1234.5678");
+    }
+
+    #[test]
+    fn create_owned_transaction_id_from_string() {
+        let owned_id: OwnedTransactionId = String::from("m1234.5678").into();
+        assert_eq!(owned_id, "m1234.5678");
+    }
+}
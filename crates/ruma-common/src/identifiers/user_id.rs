@@ -107,6 +107,26 @@ impl UserId {
         !localpart_is_fully_conforming(self.localpart()).unwrap()
     }
 
+    /// Validate this user ID against the strict grammar.
+    ///
+    /// This should be used to validate newly created user IDs, as historical user IDs are
+    /// deprecated.
+    pub fn validate_strict(&self) -> Result<(), IdParseError> {
+        validate_localpart_strict(self.localpart()).map_err(localpart_error_to_user_id_error)
+    }
+
+    /// Validate this user ID against the historical grammar.
+    ///
+    /// According to the spec, servers should check events received over federation that contain
+    /// user IDs with this method, and those that fail should not be forwarded to their users.
+    ///
+    /// Contrary to [`UserId::is_historical()`], this method also includes user IDs that conform to
+    /// the latest grammar. Parsing a `UserId` already checks the historical grammar, unless the
+    /// `compat-user-id` feature is enabled.
+    pub fn validate_historical(&self) -> Result<(), IdParseError> {
+        validate_localpart_historical(self.localpart()).map_err(localpart_error_to_user_id_error)
+    }
+
     /// Create a `matrix.to` URI for this user ID.
     ///
     /// # Example
@@ -150,12 +170,25 @@ impl UserId {
 }
 
 pub use ruma_identifiers_validation::user_id::localpart_is_fully_conforming;
+use ruma_identifiers_validation::user_id::{
+    validate_localpart_historical, validate_localpart_strict,
+};
 use ruma_macros::IdZst;
 
+/// Makes the index of invalid characters in a localpart relative to the full user ID.
+fn localpart_error_to_user_id_error(error: IdParseError) -> IdParseError {
+    match error {
+        IdParseError::InvalidCharacter { index, character } => {
+            IdParseError::InvalidCharacter { index: index + 1, character }
+        }
+        error => error,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{OwnedUserId, UserId};
-    use crate::{server_name, IdParseError};
+    use crate::{server_name, IdParseError, ServerNameError};
 
     #[test]
     fn valid_user_id_from_str() {
@@ -243,25 +276,77 @@ mod tests {
         assert!(user_id.is_historical());
     }
 
+    #[test]
+    fn parse_with_server_name_full_user_id() {
+        let user_id = UserId::parse_with_server_name("@carl:example.com", server_name!("ruma.io"))
+            .expect("Failed to create UserId.");
+        assert_eq!(user_id.as_str(), "@carl:example.com");
+
+        assert_eq!(
+            UserId::parse_with_server_name("@carl", server_name!("example.com")).unwrap_err(),
+            IdParseError::MissingColon
+        );
+    }
+
+    #[test]
+    fn parse_with_server_name_localpart_with_colon() {
+        assert_eq!(
+            UserId::parse_with_server_name("carl:example.com", server_name!("example.com"))
+                .unwrap_err(),
+            IdParseError::InvalidCharacter { index: 4, character: ':' }
+        );
+    }
+
     #[test]
     fn uppercase_user_id() {
         let user_id = <&UserId>::try_from("@CARL:example.com").expect("Failed to create UserId.");
         assert_eq!(user_id.as_str(), "@CARL:example.com");
         assert!(user_id.is_historical());
+        assert_eq!(
+            user_id.validate_strict().unwrap_err(),
+            IdParseError::InvalidCharacter { index: 1, character: 'C' }
+        );
+        user_id.validate_historical().unwrap();
+    }
+
+    #[test]
+    fn validate_fully_conforming_user_id() {
+        let user_id = <&UserId>::try_from("@carl.=_/+-9:example.com").unwrap();
+        user_id.validate_strict().unwrap();
+        user_id.validate_historical().unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "compat-user-id")]
+    fn validate_invalid_historical_user_id() {
+        let user_id = <&UserId>::try_from("@te st:example.com").unwrap();
+        assert_eq!(
+            user_id.validate_strict().unwrap_err(),
+            IdParseError::InvalidCharacter { index: 3, character: ' ' }
+        );
+        assert_eq!(
+            user_id.validate_historical().unwrap_err(),
+            IdParseError::InvalidCharacter { index: 3, character: ' ' }
+        );
     }
 
     #[cfg(feature = "rand")]
     #[test]
     fn generate_random_valid_user_id() {
         let server_name = server_name!("example.com");
-        let user_id = UserId::new(server_name);
-        assert_eq!(user_id.localpart().len(), 12);
-        assert_eq!(user_id.server_name(), "example.com");
 
-        let id_str = user_id.as_str();
+        for _ in 0..100 {
+            let user_id = UserId::new(server_name);
+            assert_eq!(user_id.localpart().len(), 12);
+            assert_eq!(user_id.server_name(), "example.com");
+
+            let id_str = user_id.as_str();
 
-        assert!(id_str.starts_with('@'));
-        assert_eq!(id_str.len(), 25);
+            assert!(id_str.starts_with('@'));
+            assert_eq!(id_str.len(), 25);
+            assert_eq!(<&UserId>::try_from(id_str).unwrap(), user_id);
+            user_id.validate_strict().unwrap();
+        }
     }
 
     #[test]
@@ -307,7 +392,7 @@ mod tests {
     fn invalid_characters_in_user_id_localpart() {
         assert_eq!(
             <&UserId>::try_from("@te\nst:example.com").unwrap_err(),
-            IdParseError::InvalidCharacters
+            IdParseError::InvalidCharacter { index: 3, character: '\n' }
         );
     }
 
@@ -326,14 +411,17 @@ mod tests {
 
     #[test]
     fn invalid_user_id_host() {
-        assert_eq!(<&UserId>::try_from("@carl:/").unwrap_err(), IdParseError::InvalidServerName);
+        assert_eq!(
+            <&UserId>::try_from("@carl:/").unwrap_err(),
+            IdParseError::InvalidServerName(ServerNameError::InvalidHost)
+        );
     }
 
     #[test]
     fn invalid_user_id_port() {
         assert_eq!(
             <&UserId>::try_from("@carl:example.com:notaport").unwrap_err(),
-            IdParseError::InvalidServerName
+            IdParseError::InvalidServerName(ServerNameError::InvalidPort)
         );
     }
 }
@@ -54,6 +54,16 @@ impl VoipVersionId {
     pub fn as_bytes(&self) -> &[u8] {
         self.as_str().as_bytes()
     }
+
+    /// Whether this is VoIP version 0.
+    pub fn is_version_0(&self) -> bool {
+        *self == Self::V0
+    }
+
+    /// Whether this is VoIP version 1.
+    pub fn is_version_1(&self) -> bool {
+        *self == Self::V1
+    }
 }
 
 impl From<VoipVersionId> for String {
@@ -181,6 +191,21 @@ mod tests {
         assert_eq!(version.as_ref(), "io.ruma.2");
     }
 
+    #[test]
+    fn version_checks() {
+        assert!(VoipVersionId::V0.is_version_0());
+        assert!(!VoipVersionId::V0.is_version_1());
+        assert!(VoipVersionId::V1.is_version_1());
+        assert!(!VoipVersionId::V1.is_version_0());
+
+        let custom = VoipVersionId::from("io.ruma.2");
+        assert!(!custom.is_version_0());
+        assert!(!custom.is_version_1());
+
+        // The string "0" is not the integer version 0.
+        assert!(!VoipVersionId::from("0").is_version_0());
+    }
+
     #[test]
     fn serialize_version_0() {
         assert_eq!(to_json_value(&VoipVersionId::V0).unwrap(), json!(0));
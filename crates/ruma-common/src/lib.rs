@@ -32,6 +32,7 @@ pub mod power_levels;
 pub mod presence;
 pub mod push;
 pub mod room;
+pub mod room_version_rules;
 pub mod serde;
 pub mod space;
 pub mod thirdparty;
@@ -44,6 +45,7 @@ use std::fmt;
 pub use self::canonical_json::{CanonicalJsonError, CanonicalJsonObject, CanonicalJsonValue};
 pub use self::{
     identifiers::*,
+    percent_encode::PathSegmentEncoded,
     time::{MilliSecondsSinceUnixEpoch, SecondsSinceUnixEpoch},
 };
 
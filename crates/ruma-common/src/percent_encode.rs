@@ -1,4 +1,6 @@
-use percent_encoding::{AsciiSet, CONTROLS};
+use std::fmt;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
 /// The [path percent-encode set] as defined in the WHATWG URL standard + `/` since
 /// we always encode single segments of the path.
@@ -15,3 +17,50 @@ pub(crate) const PATH_PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'{')
     .add(b'}')
     .add(b'/');
+
+/// A string that is percent-encoded when displayed, to be used as a single segment of a URL path.
+///
+/// This is the encoding used by the request types to build the URLs of endpoints, so the
+/// identifiers of a room alias (`#`) or a v3 event ID (`/`) are not mistaken for a fragment or
+/// another segment.
+///
+/// Use the `encode_for_path()` method of the identifier types to get this for a given identifier.
+///
+/// # Example
+///
+/// ```
+/// use ruma_common::event_id;
+///
+/// let event_id = event_id!("$acR1l0raoZnm60CBwAVgqbZqoO/mYU81xysh1u7XcJk");
+/// assert_eq!(
+///     format!("/rooms/!room:example.org/event/{}", event_id.encode_for_path()),
+///     "/rooms/!room:example.org/event/$acR1l0raoZnm60CBwAVgqbZqoO%2FmYU81xysh1u7XcJk",
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct PathSegmentEncoded<'a>(pub &'a str);
+
+impl fmt::Display for PathSegmentEncoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        utf8_percent_encode(self.0, PATH_PERCENT_ENCODE_SET).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathSegmentEncoded;
+
+    #[test]
+    fn encode_reserved_chars() {
+        assert_eq!(PathSegmentEncoded("#alias:example.org").to_string(), "%23alias:example.org");
+        assert_eq!(PathSegmentEncoded("a/b?c d").to_string(), "a%2Fb%3Fc%20d");
+    }
+
+    #[test]
+    fn keep_sigils_and_colons() {
+        assert_eq!(PathSegmentEncoded("!room:example.org").to_string(), "!room:example.org");
+        assert_eq!(PathSegmentEncoded("@user:example.org").to_string(), "@user:example.org");
+        assert_eq!(PathSegmentEncoded("$event+id=").to_string(), "$event+id=");
+    }
+}
@@ -14,11 +14,13 @@
 //! - sender rules
 //! - underride rules
 
-use std::hash::{Hash, Hasher};
+use std::{
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+};
 
 use indexmap::{Equivalent, IndexSet};
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "unstable-unspecified")]
 use serde_json::Value as JsonValue;
 use thiserror::Error;
 use tracing::instrument;
@@ -383,6 +385,12 @@ pub struct SimplePushRule<T> {
     ///
     /// This is generally the Matrix ID of the entity that it applies to.
     pub rule_id: T,
+
+    /// Fields of the rule that are not known by ruma.
+    ///
+    /// They are kept so they are not lost when the rule is serialized again.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, JsonValue>,
 }
 
 /// Initial set of fields of `SimplePushRule`.
@@ -410,7 +418,7 @@ pub struct SimplePushRuleInit<T> {
 impl<T> From<SimplePushRuleInit<T>> for SimplePushRule<T> {
     fn from(init: SimplePushRuleInit<T>) -> Self {
         let SimplePushRuleInit { actions, default, enabled, rule_id } = init;
-        Self { actions, default, enabled, rule_id }
+        Self { actions, default, enabled, rule_id, extra: BTreeMap::new() }
     }
 }
 
@@ -473,6 +481,12 @@ pub struct ConditionalPushRule {
     /// A rule with no conditions always matches.
     #[serde(default)]
     pub conditions: Vec<PushCondition>,
+
+    /// Fields of the rule that are not known by ruma.
+    ///
+    /// They are kept so they are not lost when the rule is serialized again.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, JsonValue>,
 }
 
 impl ConditionalPushRule {
@@ -552,7 +566,7 @@ pub struct ConditionalPushRuleInit {
 impl From<ConditionalPushRuleInit> for ConditionalPushRule {
     fn from(init: ConditionalPushRuleInit) -> Self {
         let ConditionalPushRuleInit { actions, default, enabled, rule_id, conditions } = init;
-        Self { actions, default, enabled, rule_id, conditions }
+        Self { actions, default, enabled, rule_id, conditions, extra: BTreeMap::new() }
     }
 }
 
@@ -602,6 +616,12 @@ pub struct PatternedPushRule {
 
     /// The glob-style pattern to match against.
     pub pattern: String,
+
+    /// Fields of the rule that are not known by ruma.
+    ///
+    /// They are kept so they are not lost when the rule is serialized again.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, JsonValue>,
 }
 
 impl PatternedPushRule {
@@ -659,7 +679,7 @@ pub struct PatternedPushRuleInit {
 impl From<PatternedPushRuleInit> for PatternedPushRule {
     fn from(init: PatternedPushRuleInit) -> Self {
         let PatternedPushRuleInit { actions, default, enabled, rule_id, pattern } = init;
-        Self { actions, default, enabled, rule_id, pattern }
+        Self { actions, default, enabled, rule_id, pattern, extra: BTreeMap::new() }
     }
 }
 
@@ -721,6 +741,14 @@ impl HttpPusherData {
             default_payload: JsonValue::default(),
         }
     }
+
+    /// Whether the `url` is valid for a Push Gateway.
+    ///
+    /// According to the spec, it must be an HTTPS URL with a path of `/_matrix/push/v1/notify`.
+    pub fn has_valid_url(&self) -> bool {
+        url::Url::parse(&self.url)
+            .is_ok_and(|url| url.scheme() == "https" && url.path() == "/_matrix/push/v1/notify")
+    }
 }
 
 /// A special format that the homeserver should use when sending notifications to a Push Gateway.
@@ -832,7 +860,7 @@ impl<T> NewSimplePushRule<T> {
 impl<T> From<NewSimplePushRule<T>> for SimplePushRule<T> {
     fn from(new_rule: NewSimplePushRule<T>) -> Self {
         let NewSimplePushRule { rule_id, actions } = new_rule;
-        Self { actions, default: false, enabled: true, rule_id }
+        Self { actions, default: false, enabled: true, rule_id, extra: BTreeMap::new() }
     }
 }
 
@@ -861,7 +889,7 @@ impl NewPatternedPushRule {
 impl From<NewPatternedPushRule> for PatternedPushRule {
     fn from(new_rule: NewPatternedPushRule) -> Self {
         let NewPatternedPushRule { rule_id, pattern, actions } = new_rule;
-        Self { actions, default: false, enabled: true, rule_id, pattern }
+        Self { actions, default: false, enabled: true, rule_id, pattern, extra: BTreeMap::new() }
     }
 }
 
@@ -894,7 +922,7 @@ impl NewConditionalPushRule {
 impl From<NewConditionalPushRule> for ConditionalPushRule {
     fn from(new_rule: NewConditionalPushRule) -> Self {
         let NewConditionalPushRule { rule_id, conditions, actions } = new_rule;
-        Self { actions, default: false, enabled: true, rule_id, conditions }
+        Self { actions, default: false, enabled: true, rule_id, conditions, extra: BTreeMap::new() }
     }
 }
 
@@ -996,7 +1024,8 @@ mod tests {
         condition::{
             PushCondition, PushConditionPowerLevelsCtx, PushConditionRoomCtx, RoomMemberCountIs,
         },
-        AnyPushRule, ConditionalPushRule, PatternedPushRule, Ruleset, SimplePushRule,
+        AnyPushRule, ConditionalPushRule, HttpPusherData, PatternedPushRule, Ruleset,
+        SimplePushRule,
     };
     use crate::{
         owned_room_id, owned_user_id,
@@ -1006,6 +1035,21 @@ mod tests {
         user_id,
     };
 
+    #[test]
+    fn http_pusher_data_valid_url() {
+        assert!(HttpPusherData::new("https://push.example.org/_matrix/push/v1/notify".to_owned())
+            .has_valid_url());
+
+        for url in [
+            "http://push.example.org/_matrix/push/v1/notify",
+            "https://push.example.org/",
+            "https://push.example.org/_matrix/push/v1/notify/extra",
+            "/_matrix/push/v1/notify",
+        ] {
+            assert!(!HttpPusherData::new(url.to_owned()).has_valid_url(), "{url}");
+        }
+    }
+
     fn example_ruleset() -> Ruleset {
         let mut set = Ruleset::new();
 
@@ -1018,6 +1062,7 @@ mod tests {
             rule_id: ".m.rule.call".into(),
             enabled: true,
             default: true,
+            extra: BTreeMap::new(),
         });
 
         set
@@ -1044,6 +1089,7 @@ mod tests {
             rule_id: "!roomid:matrix.org".into(),
             enabled: true,
             default: false,
+            extra: BTreeMap::new(),
         });
         assert!(added);
 
@@ -1053,6 +1099,7 @@ mod tests {
             rule_id: ".m.rule.suppress_notices".into(),
             enabled: false,
             default: true,
+            extra: BTreeMap::new(),
         });
         assert!(added);
 
@@ -1098,6 +1145,7 @@ mod tests {
                 PushCondition::RoomMemberCount { is: RoomMemberCountIs::gt(uint!(2)) },
                 PushCondition::SenderNotificationPermission { key: "room".into() },
             ],
+            extra: BTreeMap::new(),
         };
 
         let rule_value: JsonValue = to_json_value(rule).unwrap();
@@ -1142,6 +1190,7 @@ mod tests {
             default: false,
             enabled: false,
             rule_id: owned_room_id!("!roomid:server.name"),
+            extra: BTreeMap::new(),
         };
 
         let rule_value: JsonValue = to_json_value(rule).unwrap();
@@ -1173,6 +1222,7 @@ mod tests {
             enabled: true,
             pattern: "user_id".into(),
             rule_id: ".m.rule.contains_user_name".into(),
+            extra: BTreeMap::new(),
         };
 
         let rule_value: JsonValue = to_json_value(rule).unwrap();
@@ -1215,6 +1265,7 @@ mod tests {
             rule_id: ".m.rule.room_one_to_one".into(),
             enabled: true,
             default: true,
+            extra: BTreeMap::new(),
         });
         set.content.insert(PatternedPushRule {
             actions: vec![
@@ -1226,6 +1277,7 @@ mod tests {
             pattern: "user_id".into(),
             enabled: true,
             default: true,
+            extra: BTreeMap::new(),
         });
 
         let set_value: JsonValue = to_json_value(set).unwrap();
@@ -1434,6 +1486,53 @@ mod tests {
         assert_matches!(iter.next(), None);
     }
 
+    #[test]
+    fn ruleset_unknown_rule_fields_roundtrip() {
+        let json = json!({
+            "override": [
+                {
+                    "actions": [],
+                    "conditions": [],
+                    "rule_id": ".m.rule.master",
+                    "default": true,
+                    "enabled": false,
+                    "org.example.priority": 1,
+                },
+            ],
+            "content": [
+                {
+                    "actions": [],
+                    "pattern": "ruma",
+                    "rule_id": "ruma",
+                    "default": false,
+                    "enabled": true,
+                    "org.example.source": { "client": "ruma" },
+                }
+            ],
+            "room": [
+                {
+                    "actions": [],
+                    "rule_id": "!roomid:server.name",
+                    "default": false,
+                    "enabled": false,
+                    "org.example.muted_until": 1_432_735_824_653_u64,
+                }
+            ],
+        });
+
+        let set = from_json_value::<Ruleset>(json.clone()).unwrap();
+
+        let master = set.override_.get(".m.rule.master").unwrap();
+        assert_eq!(master.extra.len(), 1);
+        assert_eq!(master.extra["org.example.priority"], 1);
+        let content = set.content.get("ruma").unwrap();
+        assert_eq!(content.extra["org.example.source"], json!({ "client": "ruma" }));
+        let room = set.room.iter().next().unwrap();
+        assert_eq!(room.extra["org.example.muted_until"], 1_432_735_824_653_u64);
+
+        assert_eq!(to_json_value(&set).unwrap(), json);
+    }
+
     #[test]
     fn default_ruleset_applies() {
         let set = Ruleset::server_default(user_id!("@jolly_jumper:server.name"));
@@ -1570,6 +1669,7 @@ mod tests {
             conditions: vec![PushCondition::RoomMemberCount {
                 is: RoomMemberCountIs::from(uint!(2)),
             }],
+            extra: BTreeMap::new(),
         };
         set.underride.insert(disabled);
 
@@ -1582,6 +1682,7 @@ mod tests {
             enabled: true,
             rule_id: "no.conditions".into(),
             conditions: vec![],
+            extra: BTreeMap::new(),
         };
         set.underride.insert(no_conditions);
 
@@ -1596,6 +1697,7 @@ mod tests {
             default: false,
             enabled: true,
             rule_id: owned_user_id!("@rantanplan:server.name"),
+            extra: BTreeMap::new(),
         };
         set.sender.insert(sender);
 
@@ -1607,6 +1709,7 @@ mod tests {
             default: false,
             enabled: true,
             rule_id: owned_room_id!("!dm:server.name"),
+            extra: BTreeMap::new(),
         };
         set.room.insert(room);
 
@@ -1622,6 +1725,7 @@ mod tests {
             enabled: true,
             rule_id: "content".into(),
             pattern: "joke".into(),
+            extra: BTreeMap::new(),
         };
         set.content.insert(content);
 
@@ -1645,6 +1749,7 @@ mod tests {
                     pattern: "!dm:server.name".into(),
                 },
             ],
+            extra: BTreeMap::new(),
         };
         set.override_.insert(three_conditions);
 
@@ -2,6 +2,8 @@
 //!
 //! [predefined push rules]: https://spec.matrix.org/latest/client-server-api/#predefined-rules
 
+use std::collections::BTreeMap;
+
 use ruma_macros::StringEnum;
 
 use super::{
@@ -143,6 +145,7 @@ impl ConditionalPushRule {
             enabled: false,
             rule_id: PredefinedOverrideRuleId::Master.to_string(),
             conditions: vec![],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -157,6 +160,7 @@ impl ConditionalPushRule {
                 key: "content.msgtype".into(),
                 pattern: "m.notice".into(),
             }],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -176,6 +180,7 @@ impl ConditionalPushRule {
                 EventMatch { key: "content.membership".into(), pattern: "invite".into() },
                 EventMatch { key: "state_key".into(), pattern: user_id.to_string() },
             ],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -187,6 +192,7 @@ impl ConditionalPushRule {
             enabled: true,
             rule_id: PredefinedOverrideRuleId::MemberEvent.to_string(),
             conditions: vec![EventMatch { key: "type".into(), pattern: "m.room.member".into() }],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -206,6 +212,7 @@ impl ConditionalPushRule {
                 key: r"content.m\.mentions.user_ids".to_owned(),
                 value: user_id.as_str().into(),
             }],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -227,6 +234,7 @@ impl ConditionalPushRule {
             enabled: true,
             rule_id: PredefinedOverrideRuleId::ContainsDisplayName.to_string(),
             conditions: vec![ContainsDisplayName],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -243,6 +251,7 @@ impl ConditionalPushRule {
                 EventMatch { key: "type".into(), pattern: "m.room.tombstone".into() },
                 EventMatch { key: "state_key".into(), pattern: "".into() },
             ],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -258,6 +267,7 @@ impl ConditionalPushRule {
                 EventPropertyIs { key: r"content.m\.mentions.room".to_owned(), value: true.into() },
                 SenderNotificationPermission { key: "room".to_owned() },
             ],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -278,6 +288,7 @@ impl ConditionalPushRule {
                 EventMatch { key: "content.body".into(), pattern: "@room".into() },
                 SenderNotificationPermission { key: "room".into() },
             ],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -291,6 +302,7 @@ impl ConditionalPushRule {
             enabled: true,
             rule_id: PredefinedOverrideRuleId::Reaction.to_string(),
             conditions: vec![EventMatch { key: "type".into(), pattern: "m.reaction".into() }],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -307,6 +319,7 @@ impl ConditionalPushRule {
                 EventMatch { key: "type".into(), pattern: "m.room.server_acl".into() },
                 EventMatch { key: "state_key".into(), pattern: "".into() },
             ],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -323,6 +336,7 @@ impl ConditionalPushRule {
                 key: r"content.m\.relates_to.rel_type".to_owned(),
                 value: "m.replace".into(),
             }],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -343,6 +357,7 @@ impl ConditionalPushRule {
                 value: "org.matrix.msc3381.poll.response".into(),
             }],
             actions: vec![],
+            extra: BTreeMap::new(),
         }
     }
 }
@@ -367,6 +382,7 @@ impl PatternedPushRule {
                 SetTweak(Tweak::Sound("default".into())),
                 SetTweak(Tweak::Highlight(true)),
             ],
+            extra: BTreeMap::new(),
         }
     }
 }
@@ -385,6 +401,7 @@ impl ConditionalPushRule {
                 SetTweak(Tweak::Sound("ring".into())),
                 SetTweak(Tweak::Highlight(false)),
             ],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -407,6 +424,7 @@ impl ConditionalPushRule {
                 SetTweak(Tweak::Sound("default".into())),
                 SetTweak(Tweak::Highlight(false)),
             ],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -425,6 +443,7 @@ impl ConditionalPushRule {
                 SetTweak(Tweak::Sound("default".into())),
                 SetTweak(Tweak::Highlight(false)),
             ],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -436,6 +455,7 @@ impl ConditionalPushRule {
             enabled: true,
             conditions: vec![EventMatch { key: "type".into(), pattern: "m.room.message".into() }],
             actions: vec![Notify, SetTweak(Tweak::Highlight(false))],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -451,6 +471,7 @@ impl ConditionalPushRule {
             enabled: true,
             conditions: vec![EventMatch { key: "type".into(), pattern: "m.room.encrypted".into() }],
             actions: vec![Notify, SetTweak(Tweak::Highlight(false))],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -474,6 +495,7 @@ impl ConditionalPushRule {
                 },
             ],
             actions: vec![Notify, SetTweak(Tweak::Sound("default".into()))],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -494,6 +516,7 @@ impl ConditionalPushRule {
                 value: "org.matrix.msc3381.poll.start".into(),
             }],
             actions: vec![Notify],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -517,6 +540,7 @@ impl ConditionalPushRule {
                 },
             ],
             actions: vec![Notify, SetTweak(Tweak::Sound("default".into()))],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -537,6 +561,7 @@ impl ConditionalPushRule {
                 value: "org.matrix.msc3381.poll.end".into(),
             }],
             actions: vec![Notify],
+            extra: BTreeMap::new(),
         }
     }
 }
@@ -0,0 +1,330 @@
+//! Types for the rules applied to the different [room versions].
+//!
+//! [room versions]: https://spec.matrix.org/latest/rooms/
+
+use crate::RoomVersionId;
+
+/// The rules applied to a [room version].
+///
+/// This type can be constructed from one of its constants (like [`RoomVersionRules::V1`]), or by
+/// calling [`RoomVersionId::rules()`](crate::RoomVersionId::rules).
+///
+/// [room version]: https://spec.matrix.org/latest/rooms/
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct RoomVersionRules {
+    /// The format of the event IDs.
+    pub event_format: EventFormatVersion,
+
+    /// The state resolution algorithm that is used.
+    pub state_res: StateResolutionVersion,
+
+    /// Whether to enforce the `valid_until_ts` of the server signing keys when verifying
+    /// signatures.
+    ///
+    /// Introduced in room version 5.
+    pub enforce_key_validity: bool,
+
+    /// Whether to strictly enforce [canonical JSON].
+    ///
+    /// Integers outside of the range `[-(2**53)+1, (2**53)-1]`, floats, `NaN`, `Infinity` and
+    /// `-Infinity` are not allowed.
+    ///
+    /// Introduced in room version 6.
+    ///
+    /// [canonical JSON]: https://spec.matrix.org/latest/appendices/#canonical-json
+    pub strict_canonical_json: bool,
+
+    /// Whether power levels are required to be integers, rather than strings containing integers.
+    ///
+    /// Introduced in room version 10.
+    pub integer_power_levels: bool,
+
+    /// Whether the `knock` membership and join rule are supported.
+    ///
+    /// Introduced in room version 7.
+    pub knocking: bool,
+
+    /// Whether the `restricted` join rule is supported.
+    ///
+    /// This also means that a signature is required from the server of the user in the
+    /// `join_authorised_via_users_server` field of the content of `m.room.member` events.
+    ///
+    /// Introduced in room version 8.
+    pub restricted_join_rule: bool,
+
+    /// Whether the `knock_restricted` join rule is supported.
+    ///
+    /// Introduced in room version 10.
+    pub knock_restricted_join_rule: bool,
+
+    /// The tweaks in the authorization rules.
+    pub authorization: AuthorizationRules,
+
+    /// The tweaks in the redaction algorithm.
+    pub redaction: RedactionRules,
+}
+
+impl RoomVersionRules {
+    /// Rules for [room version 1].
+    ///
+    /// [room version 1]: https://spec.matrix.org/latest/rooms/v1/
+    pub const V1: Self = Self {
+        event_format: EventFormatVersion::V1,
+        state_res: StateResolutionVersion::V1,
+        enforce_key_validity: false,
+        strict_canonical_json: false,
+        integer_power_levels: false,
+        knocking: false,
+        restricted_join_rule: false,
+        knock_restricted_join_rule: false,
+        authorization: AuthorizationRules::V1,
+        redaction: RedactionRules::V1,
+    };
+
+    /// Rules for [room version 2].
+    ///
+    /// [room version 2]: https://spec.matrix.org/latest/rooms/v2/
+    pub const V2: Self = Self { state_res: StateResolutionVersion::V2, ..Self::V1 };
+
+    /// Rules for [room version 3].
+    ///
+    /// [room version 3]: https://spec.matrix.org/latest/rooms/v3/
+    pub const V3: Self = Self {
+        event_format: EventFormatVersion::V2,
+        authorization: AuthorizationRules::V3,
+        ..Self::V2
+    };
+
+    /// Rules for [room version 4].
+    ///
+    /// [room version 4]: https://spec.matrix.org/latest/rooms/v4/
+    pub const V4: Self = Self { event_format: EventFormatVersion::V3, ..Self::V3 };
+
+    /// Rules for [room version 5].
+    ///
+    /// [room version 5]: https://spec.matrix.org/latest/rooms/v5/
+    pub const V5: Self = Self { enforce_key_validity: true, ..Self::V4 };
+
+    /// Rules for [room version 6].
+    ///
+    /// [room version 6]: https://spec.matrix.org/latest/rooms/v6/
+    pub const V6: Self = Self {
+        strict_canonical_json: true,
+        authorization: AuthorizationRules::V6,
+        redaction: RedactionRules::V6,
+        ..Self::V5
+    };
+
+    /// Rules for [room version 7].
+    ///
+    /// [room version 7]: https://spec.matrix.org/latest/rooms/v7/
+    pub const V7: Self = Self { knocking: true, ..Self::V6 };
+
+    /// Rules for [room version 8].
+    ///
+    /// [room version 8]: https://spec.matrix.org/latest/rooms/v8/
+    pub const V8: Self =
+        Self { restricted_join_rule: true, redaction: RedactionRules::V8, ..Self::V7 };
+
+    /// Rules for [room version 9].
+    ///
+    /// [room version 9]: https://spec.matrix.org/latest/rooms/v9/
+    pub const V9: Self = Self { redaction: RedactionRules::V9, ..Self::V8 };
+
+    /// Rules for [room version 10].
+    ///
+    /// [room version 10]: https://spec.matrix.org/latest/rooms/v10/
+    pub const V10: Self =
+        Self { integer_power_levels: true, knock_restricted_join_rule: true, ..Self::V9 };
+
+    /// Rules for [room version 11].
+    ///
+    /// [room version 11]: https://spec.matrix.org/latest/rooms/v11/
+    pub const V11: Self = Self {
+        authorization: AuthorizationRules::V11,
+        redaction: RedactionRules::V11,
+        ..Self::V10
+    };
+}
+
+/// The tweaks in the [authorization rules] for a room version.
+///
+/// This type can be constructed from one of its constants (like [`AuthorizationRules::V1`]), or by
+/// accessing the `authorization` field of [`RoomVersionRules`].
+///
+/// [authorization rules]: https://spec.matrix.org/latest/server-server-api/#authorization-rules
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct AuthorizationRules {
+    /// Whether `m.room.aliases` events are only allowed if the domain of the sender matches the
+    /// state key, regardless of power levels.
+    ///
+    /// Disabled in room version 6.
+    pub special_case_room_aliases: bool,
+
+    /// Whether `m.room.redaction` events are only allowed if the sender has the `redact` power
+    /// level or if the redacted event was sent by the same server.
+    ///
+    /// Disabled in room version 3.
+    pub special_case_room_redaction: bool,
+
+    /// Whether to check the `notifications` field of `m.room.power_levels` events against the
+    /// power level of the sender.
+    ///
+    /// Introduced in room version 6.
+    pub limit_notifications_power_levels: bool,
+
+    /// Whether the room creator is the `sender` of the `m.room.create` event, rather than the
+    /// `creator` field of its `content`.
+    ///
+    /// Introduced in room version 11.
+    pub use_room_create_sender: bool,
+}
+
+impl AuthorizationRules {
+    /// Authorization rules for room versions 1 and 2.
+    pub const V1: Self = Self {
+        special_case_room_aliases: true,
+        special_case_room_redaction: true,
+        limit_notifications_power_levels: false,
+        use_room_create_sender: false,
+    };
+
+    /// Authorization rules for room versions 3 through 5.
+    pub const V3: Self = Self { special_case_room_redaction: false, ..Self::V1 };
+
+    /// Authorization rules for room versions 6 through 10.
+    pub const V6: Self = Self {
+        special_case_room_aliases: false,
+        limit_notifications_power_levels: true,
+        ..Self::V3
+    };
+
+    /// Authorization rules for room version 11.
+    pub const V11: Self = Self { use_room_create_sender: true, ..Self::V6 };
+}
+
+/// The tweaks in the [redaction] algorithm for a room version.
+///
+/// This type can be constructed from one of its constants (like [`RedactionRules::V1`]), or by
+/// accessing the `redaction` field of [`RoomVersionRules`].
+///
+/// [redaction]: https://spec.matrix.org/latest/client-server-api/#redactions
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct RedactionRules {
+    /// Whether to keep the `aliases` field in the `content` of `m.room.aliases` events.
+    ///
+    /// Disabled in room version 6.
+    pub keep_room_aliases_aliases: bool,
+
+    /// Whether to keep the `allow` field in the `content` of `m.room.join_rules` events.
+    ///
+    /// Introduced in room version 8.
+    pub keep_room_join_rules_allow: bool,
+
+    /// Whether to keep the `join_authorised_via_users_server` field in the `content` of
+    /// `m.room.member` events.
+    ///
+    /// Introduced in room version 9.
+    pub keep_room_member_join_authorised_via_users_server: bool,
+
+    /// Whether to keep the `signed` field of `third_party_invite` in the `content` of
+    /// `m.room.member` events.
+    ///
+    /// Introduced in room version 11.
+    pub keep_room_member_third_party_invite_signed: bool,
+
+    /// Whether to keep the `invite` field in the `content` of `m.room.power_levels` events.
+    ///
+    /// Introduced in room version 11.
+    pub keep_room_power_levels_invite: bool,
+
+    /// Whether to keep the `redacts` field in the `content` of `m.room.redaction` events.
+    ///
+    /// Introduced in room version 11.
+    pub keep_room_redaction_redacts: bool,
+
+    /// Whether to keep all the fields in the `content` of `m.room.create` events, rather than only
+    /// the `creator` field.
+    ///
+    /// Introduced in room version 11.
+    pub keep_room_create_content: bool,
+
+    /// Whether to keep the top-level `origin`, `membership` and `prev_state` fields of events.
+    ///
+    /// Disabled in room version 11.
+    pub keep_origin_membership_prev_state: bool,
+}
+
+impl RedactionRules {
+    /// Redaction rules for room versions 1 through 5.
+    pub const V1: Self = Self {
+        keep_room_aliases_aliases: true,
+        keep_room_join_rules_allow: false,
+        keep_room_member_join_authorised_via_users_server: false,
+        keep_room_member_third_party_invite_signed: false,
+        keep_room_power_levels_invite: false,
+        keep_room_redaction_redacts: false,
+        keep_room_create_content: false,
+        keep_origin_membership_prev_state: true,
+    };
+
+    /// Redaction rules for room versions 6 and 7.
+    pub const V6: Self = Self { keep_room_aliases_aliases: false, ..Self::V1 };
+
+    /// Redaction rules for room version 8.
+    pub const V8: Self = Self { keep_room_join_rules_allow: true, ..Self::V6 };
+
+    /// Redaction rules for room versions 9 and 10.
+    pub const V9: Self =
+        Self { keep_room_member_join_authorised_via_users_server: true, ..Self::V8 };
+
+    /// Redaction rules for room version 11.
+    pub const V11: Self = Self {
+        keep_room_member_third_party_invite_signed: true,
+        keep_room_power_levels_invite: true,
+        keep_room_redaction_redacts: true,
+        keep_room_create_content: true,
+        keep_origin_membership_prev_state: false,
+        ..Self::V9
+    };
+
+    /// The redaction rules for the given room version.
+    ///
+    /// The rules of custom room versions are unknown, so they use the rules of the latest stable
+    /// room version.
+    pub fn for_room_version(version: &RoomVersionId) -> Self {
+        version.rules().map(|rules| rules.redaction).unwrap_or(Self::V11)
+    }
+}
+
+/// The format of [event IDs] for a room version.
+///
+/// [event IDs]: https://spec.matrix.org/latest/appendices/#event-ids
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum EventFormatVersion {
+    /// `$id:server` format, used by room versions 1 and 2.
+    V1,
+
+    /// `$hash` format using standard base64, introduced in room version 3.
+    V2,
+
+    /// `$hash` format using URL-safe base64, introduced in room version 4.
+    V3,
+}
+
+/// The [state resolution] algorithm used by a room version.
+///
+/// [state resolution]: https://spec.matrix.org/latest/server-server-api/#room-state-resolution
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum StateResolutionVersion {
+    /// State resolution for room version 1.
+    V1,
+
+    /// State resolution for room version 2 and later.
+    V2,
+}
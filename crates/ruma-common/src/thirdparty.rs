@@ -9,10 +9,15 @@ use std::{
 
 use serde::{Deserialize, Serialize};
 
+#[doc(hidden)]
+pub use self::third_party_id::CustomThirdPartyId;
+pub use self::third_party_id::{EmailAddress, Msisdn, ThirdPartyId, ThirdPartyIdError};
 use crate::{
     serde::StringEnum, MilliSecondsSinceUnixEpoch, OwnedRoomAliasId, OwnedUserId, PrivOwnedStr,
 };
 
+mod third_party_id;
+
 /// Metadata about a third party protocol.
 ///
 /// To create an instance of this type, first create a `ProtocolInit` and convert it via
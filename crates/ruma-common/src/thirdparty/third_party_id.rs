@@ -0,0 +1,379 @@
+//! Validated third party identifiers.
+
+use std::str::FromStr;
+
+use ruma_macros::DisplayAsRefStr;
+use serde::{
+    de::{self, Deserializer},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+
+use super::Medium;
+
+/// The maximum number of digits in an MSISDN, according to the [E.164] numbering plan.
+///
+/// [E.164]: https://www.itu.int/rec/T-REC-E.164-201011-I/en
+const MAX_MSISDN_DIGITS: usize = 15;
+
+/// A third party identifier, made of a [`Medium`] and an address.
+///
+/// The address is validated according to the medium when this type is constructed or
+/// deserialized. Addresses with a custom medium are not validated.
+///
+/// This type is (de)serialized as an object with separate `medium` and `address` fields.
+///
+/// ```
+/// # use ruma_common::thirdparty::{Medium, ThirdPartyId};
+/// let threepid = ThirdPartyId::new(Medium::Email, "Alice@Example.org".to_owned()).unwrap();
+/// assert_eq!(threepid.medium(), Medium::Email);
+/// assert_eq!(threepid.address(), "alice@example.org");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum ThirdPartyId {
+    /// An email address.
+    Email(EmailAddress),
+
+    /// A phone number in the MSISDN format.
+    Msisdn(Msisdn),
+
+    #[doc(hidden)]
+    _Custom(CustomThirdPartyId),
+}
+
+impl ThirdPartyId {
+    /// Creates a new `ThirdPartyId` with the given medium and address.
+    ///
+    /// Returns an error if the address is not valid for the medium.
+    pub fn new(medium: Medium, address: String) -> Result<Self, ThirdPartyIdError> {
+        Ok(match medium {
+            Medium::Email => Self::Email(address.try_into()?),
+            Medium::Msisdn => Self::Msisdn(address.try_into()?),
+            _ => Self::_Custom(CustomThirdPartyId { medium, address }),
+        })
+    }
+
+    /// The medium of this third party identifier.
+    pub fn medium(&self) -> Medium {
+        match self {
+            Self::Email(_) => Medium::Email,
+            Self::Msisdn(_) => Medium::Msisdn,
+            Self::_Custom(CustomThirdPartyId { medium, .. }) => medium.clone(),
+        }
+    }
+
+    /// The address of this third party identifier.
+    pub fn address(&self) -> &str {
+        match self {
+            Self::Email(address) => address.as_str(),
+            Self::Msisdn(number) => number.as_str(),
+            Self::_Custom(CustomThirdPartyId { address, .. }) => address,
+        }
+    }
+}
+
+impl From<EmailAddress> for ThirdPartyId {
+    fn from(address: EmailAddress) -> Self {
+        Self::Email(address)
+    }
+}
+
+impl From<Msisdn> for ThirdPartyId {
+    fn from(number: Msisdn) -> Self {
+        Self::Msisdn(number)
+    }
+}
+
+impl Serialize for ThirdPartyId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = serializer.serialize_struct("ThirdPartyId", 2)?;
+        st.serialize_field("medium", &self.medium())?;
+        st.serialize_field("address", self.address())?;
+        st.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ThirdPartyId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct ThirdPartyIdDeHelper {
+            medium: Medium,
+            address: String,
+        }
+
+        let ThirdPartyIdDeHelper { medium, address } =
+            ThirdPartyIdDeHelper::deserialize(deserializer)?;
+        Self::new(medium, address).map_err(de::Error::custom)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct CustomThirdPartyId {
+    medium: Medium,
+    address: String,
+}
+
+/// An email address used as a third party identifier.
+///
+/// The address is lowercased on construction, as required to look it up on an identity server.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, DisplayAsRefStr)]
+pub struct EmailAddress(Box<str>);
+
+impl EmailAddress {
+    /// Creates a string slice from this `EmailAddress`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for EmailAddress {
+    type Error = ThirdPartyIdError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let is_valid = s
+            .rsplit_once('@')
+            .is_some_and(|(localpart, domain)| !localpart.is_empty() && !domain.is_empty())
+            && !s.chars().any(|c| c.is_whitespace() || c.is_control());
+
+        if !is_valid {
+            return Err(ThirdPartyIdError::MalformedEmailAddress);
+        }
+
+        Ok(Self(s.to_lowercase().into()))
+    }
+}
+
+impl TryFrom<String> for EmailAddress {
+    type Error = ThirdPartyIdError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.as_str().try_into()
+    }
+}
+
+impl FromStr for EmailAddress {
+    type Err = ThirdPartyIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl AsRef<str> for EmailAddress {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<EmailAddress> for String {
+    fn from(address: EmailAddress) -> Self {
+        address.0.into()
+    }
+}
+
+impl Serialize for EmailAddress {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EmailAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = crate::serde::deserialize_cow_str(deserializer)?;
+        s.as_ref().try_into().map_err(de::Error::custom)
+    }
+}
+
+/// A phone number in the MSISDN format used as a third party identifier.
+///
+/// According to the [E.164] numbering plan, an MSISDN is made of at most 15 digits. It must not
+/// start with a `+`.
+///
+/// [E.164]: https://www.itu.int/rec/T-REC-E.164-201011-I/en
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, DisplayAsRefStr)]
+pub struct Msisdn(Box<str>);
+
+impl Msisdn {
+    /// Creates a string slice from this `Msisdn`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Msisdn {
+    type Error = ThirdPartyIdError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ThirdPartyIdError::MsisdnInvalidCharacters);
+        }
+
+        if s.is_empty() || s.len() > MAX_MSISDN_DIGITS {
+            return Err(ThirdPartyIdError::MsisdnInvalidLength);
+        }
+
+        Ok(Self(s.into()))
+    }
+}
+
+impl TryFrom<String> for Msisdn {
+    type Error = ThirdPartyIdError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.as_str().try_into()
+    }
+}
+
+impl FromStr for Msisdn {
+    type Err = ThirdPartyIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.try_into()
+    }
+}
+
+impl AsRef<str> for Msisdn {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<Msisdn> for String {
+    fn from(number: Msisdn) -> Self {
+        number.0.into()
+    }
+}
+
+impl Serialize for Msisdn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Msisdn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = crate::serde::deserialize_cow_str(deserializer)?;
+        s.as_ref().try_into().map_err(de::Error::custom)
+    }
+}
+
+/// An error encountered when trying to construct a [`ThirdPartyId`] or one of its addresses.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub enum ThirdPartyIdError {
+    /// The email address is not of the form `localpart@domain`.
+    #[error("email address is not of the form `localpart@domain`")]
+    MalformedEmailAddress,
+
+    /// The MSISDN contains characters that are not ASCII digits.
+    #[error("MSISDN must only contain ASCII digits")]
+    MsisdnInvalidCharacters,
+
+    /// The MSISDN is empty or longer than 15 digits.
+    #[error("MSISDN must contain between 1 and 15 digits")]
+    MsisdnInvalidLength,
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches2::assert_matches;
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{EmailAddress, Msisdn, ThirdPartyId, ThirdPartyIdError};
+    use crate::thirdparty::Medium;
+
+    #[test]
+    fn valid_email_address() {
+        let address = EmailAddress::try_from("Alice.Smith@Example.ORG").unwrap();
+        assert_eq!(address.as_str(), "alice.smith@example.org");
+    }
+
+    #[test]
+    fn invalid_email_address() {
+        for address in ["", "alice", "@example.org", "alice@", "al ice@a.b"] {
+            assert_eq!(
+                EmailAddress::try_from(address).unwrap_err(),
+                ThirdPartyIdError::MalformedEmailAddress,
+                "{address}"
+            );
+        }
+    }
+
+    #[test]
+    fn valid_msisdn() {
+        let number = Msisdn::try_from("447700900123").unwrap();
+        assert_eq!(number.as_str(), "447700900123");
+    }
+
+    #[test]
+    fn invalid_msisdn() {
+        assert_eq!(
+            Msisdn::try_from("+447700900123").unwrap_err(),
+            ThirdPartyIdError::MsisdnInvalidCharacters
+        );
+        assert_eq!(
+            Msisdn::try_from("44 7700 900123").unwrap_err(),
+            ThirdPartyIdError::MsisdnInvalidCharacters
+        );
+        assert_eq!(Msisdn::try_from("").unwrap_err(), ThirdPartyIdError::MsisdnInvalidLength);
+        assert_eq!(
+            Msisdn::try_from("1234567890123456").unwrap_err(),
+            ThirdPartyIdError::MsisdnInvalidLength
+        );
+    }
+
+    #[test]
+    fn third_party_id_serde() {
+        let threepid = ThirdPartyId::new(Medium::Email, "Alice@Example.org".to_owned()).unwrap();
+        assert_eq!(
+            to_json_value(&threepid).unwrap(),
+            json!({ "medium": "email", "address": "alice@example.org" })
+        );
+
+        let threepid = from_json_value::<ThirdPartyId>(json!({
+            "medium": "msisdn",
+            "address": "447700900123",
+        }))
+        .unwrap();
+        assert_matches!(threepid, ThirdPartyId::Msisdn(number));
+        assert_eq!(number.as_str(), "447700900123");
+    }
+
+    #[test]
+    fn custom_third_party_id_serde() {
+        let json = json!({ "medium": "io.ruma.pigeon", "address": "Rooftop #3" });
+        let threepid = from_json_value::<ThirdPartyId>(json.clone()).unwrap();
+        assert_eq!(threepid.medium().as_str(), "io.ruma.pigeon");
+        assert_eq!(threepid.address(), "Rooftop #3");
+        assert_eq!(to_json_value(&threepid).unwrap(), json);
+    }
+
+    #[test]
+    fn invalid_third_party_id_deserialization() {
+        from_json_value::<ThirdPartyId>(json!({ "medium": "email", "address": "alice" }))
+            .unwrap_err();
+        from_json_value::<ThirdPartyId>(json!({ "medium": "msisdn", "address": "+4477009" }))
+            .unwrap_err();
+    }
+}
@@ -1 +1,2 @@
+mod collections;
 mod id_macros;
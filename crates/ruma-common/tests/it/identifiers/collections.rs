@@ -0,0 +1,53 @@
+#![allow(clippy::disallowed_types)]
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use ruma_common::{
+    owned_event_id, owned_room_id, owned_user_id, room_id, user_id, EventId, OwnedEventId,
+    OwnedRoomId, OwnedUserId, RoomId, UserId,
+};
+
+#[test]
+fn btree_map_lookup_with_borrowed_id() {
+    let mut map: BTreeMap<OwnedRoomId, u8> = BTreeMap::new();
+    map.insert(owned_room_id!("!a:example.org"), 1);
+    map.insert(owned_room_id!("!b:example.org"), 2);
+
+    let room_id: &RoomId = room_id!("!b:example.org");
+    assert_eq!(map.get(room_id), Some(&2));
+    assert_eq!(map.get("!a:example.org"), Some(&1));
+    assert_eq!(map.get("!c:example.org"), None);
+}
+
+#[test]
+fn hash_set_lookup_with_borrowed_id() {
+    let mut set: HashSet<OwnedUserId> = HashSet::new();
+    set.insert(owned_user_id!("@alice:example.org"));
+
+    let user_id: &UserId = user_id!("@alice:example.org");
+    assert!(set.contains(user_id));
+    assert!(set.contains("@alice:example.org"));
+    assert!(!set.contains(user_id!("@bob:example.org")));
+}
+
+#[test]
+fn hash_map_lookup_with_str() {
+    let mut map: HashMap<OwnedEventId, u8> = HashMap::new();
+    map.insert(owned_event_id!("$h29iv0s8:example.com"), 1);
+
+    let event_id = <&EventId>::try_from("$h29iv0s8:example.com").unwrap();
+    assert_eq!(map.get(event_id), Some(&1));
+    assert_eq!(map.get("$h29iv0s8:example.com"), Some(&1));
+}
+
+#[test]
+fn ordering_is_consistent_between_forms() {
+    let ids = ["@carl:example.org", "@alice:example.org", "@bob:example.org", "@alice:example.com"];
+
+    let owned = ids.iter().map(|s| UserId::parse(s).unwrap()).collect::<BTreeSet<_>>();
+    let borrowed = ids.iter().map(|s| <&UserId>::try_from(*s).unwrap()).collect::<BTreeSet<_>>();
+    let strings = ids.iter().copied().collect::<BTreeSet<_>>();
+
+    assert!(owned.iter().map(|id| id.as_str()).eq(strings.iter().copied()));
+    assert!(borrowed.iter().map(|id| id.as_str()).eq(strings.iter().copied()));
+}
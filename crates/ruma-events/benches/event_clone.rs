@@ -0,0 +1,50 @@
+// To compare the clone cost of the different storage types of owned identifiers, run this
+// benchmark once without `ruma_identifiers_storage` and once with it, for example:
+// `cargo bench --features criterion --bench event_clone -- --save-baseline box`
+// `RUSTFLAGS='--cfg=ruma_identifiers_storage="Arc"' cargo bench --features criterion \
+//      --bench event_clone -- --baseline box`
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ruma_events::{AnySyncTimelineEvent, AnyTimelineEvent};
+use serde_json::json;
+
+fn message_event(idx: usize) -> serde_json::Value {
+    json!({
+        "content": {
+            "body": "Hello world!",
+            "msgtype": "m.text"
+        },
+        "event_id": format!("$event{idx}:localhost"),
+        "origin_server_ts": 45,
+        "sender": "@example:localhost",
+        "room_id": "!room:localhost",
+        "type": "m.room.message",
+        "unsigned": {
+            "age": 45
+        }
+    })
+}
+
+fn timeline_events() -> Vec<AnyTimelineEvent> {
+    (0..1000).map(|idx| serde_json::from_value(message_event(idx)).unwrap()).collect()
+}
+
+fn clone_timeline_events(c: &mut Criterion) {
+    let events = timeline_events();
+
+    c.bench_function("clone 1000 `AnyTimelineEvent`s", |b| {
+        b.iter(|| events.clone());
+    });
+}
+
+fn clone_sync_timeline_events(c: &mut Criterion) {
+    let events = timeline_events().into_iter().map(AnySyncTimelineEvent::from).collect::<Vec<_>>();
+
+    c.bench_function("clone 1000 `AnySyncTimelineEvent`s", |b| {
+        b.iter(|| events.clone());
+    });
+}
+
+criterion_group!(benches, clone_timeline_events, clone_sync_timeline_events);
+
+criterion_main!(benches);
@@ -9,8 +9,12 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use ruma_common::serde::Raw;
 use ruma_events::{
-    room::power_levels::RoomPowerLevelsEventContent, AnyStateEvent, AnyTimelineEvent,
-    OriginalStateEvent,
+    room::{
+        member::RoomMemberEventContent, message::RoomMessageEventContent,
+        power_levels::RoomPowerLevelsEventContent,
+    },
+    AnyStateEvent, AnySyncTimelineEvent, AnyTimelineEvent, OriginalStateEvent,
+    OriginalSyncStateEvent,
 };
 use serde_json::json;
 
@@ -80,11 +84,126 @@ fn deserialize_specific_event(c: &mut Criterion) {
     });
 }
 
+fn deserialize_room_message_content(c: &mut Criterion) {
+    let json = serde_json::to_string(&json!({
+        "body": "> <@alice:localhost> Hello\n\nHi!",
+        "format": "org.matrix.custom.html",
+        "formatted_body": "<mx-reply>Hello</mx-reply><strong>Hi!</strong>",
+        "msgtype": "m.text",
+        "m.mentions": { "user_ids": ["@alice:localhost"] },
+        "m.relates_to": {
+            "m.in_reply_to": { "event_id": "$previous:localhost" },
+        },
+    }))
+    .unwrap();
+
+    c.bench_function("deserialize to `RoomMessageEventContent`", |b| {
+        b.iter(|| {
+            let _ = serde_json::from_str::<RoomMessageEventContent>(&json).unwrap();
+        });
+    });
+}
+
+fn deserialize_member_event(c: &mut Criterion) {
+    let json = serde_json::to_string(&json!({
+        "content": {
+            "avatar_url": "mxc://localhost/abcdef",
+            "displayname": "Example",
+            "membership": "join",
+        },
+        "event_id": "$15139375512JaHAW:localhost",
+        "origin_server_ts": 45,
+        "sender": "@example:localhost",
+        "state_key": "@example:localhost",
+        "type": "m.room.member",
+        "unsigned": {
+            "age": 45,
+            "prev_content": {
+                "displayname": "Old name",
+                "membership": "join",
+            },
+        },
+    }))
+    .unwrap();
+
+    c.bench_function("deserialize to `OriginalSyncStateEvent<RoomMemberEventContent>`", |b| {
+        b.iter(|| {
+            let _ = serde_json::from_str::<OriginalSyncStateEvent<RoomMemberEventContent>>(&json)
+                .unwrap();
+        });
+    });
+}
+
+/// A timeline similar to the one of a large sync response, with a mix of common events.
+fn sync_timeline(len: usize) -> String {
+    let events: Vec<_> = (0..len)
+        .map(|i| match i % 4 {
+            0 | 1 => json!({
+                "content": {
+                    "body": format!("Message number {i}"),
+                    "format": "org.matrix.custom.html",
+                    "formatted_body": format!("<strong>Message</strong> number {i}"),
+                    "msgtype": "m.text",
+                    "m.mentions": {},
+                },
+                "event_id": format!("${i}:localhost"),
+                "origin_server_ts": 1,
+                "sender": "@example:localhost",
+                "type": "m.room.message",
+                "unsigned": { "age": 45 },
+            }),
+            2 => json!({
+                "content": {
+                    "m.relates_to": {
+                        "event_id": format!("${}:localhost", i - 1),
+                        "key": "👍",
+                        "rel_type": "m.annotation",
+                    },
+                },
+                "event_id": format!("${i}:localhost"),
+                "origin_server_ts": 1,
+                "sender": "@example:localhost",
+                "type": "m.reaction",
+            }),
+            _ => json!({
+                "content": {
+                    "displayname": "Example",
+                    "membership": "join",
+                },
+                "event_id": format!("${i}:localhost"),
+                "origin_server_ts": 1,
+                "sender": "@example:localhost",
+                "state_key": "@example:localhost",
+                "type": "m.room.member",
+                "unsigned": { "age": 45 },
+            }),
+        })
+        .collect();
+
+    serde_json::to_string(&events).unwrap()
+}
+
+fn deserialize_sync_timeline(c: &mut Criterion) {
+    let json = sync_timeline(10_000);
+
+    c.bench_function("deserialize a timeline of 10k `AnySyncTimelineEvent`s", |b| {
+        b.iter(|| {
+            let events = serde_json::from_str::<Vec<Raw<AnySyncTimelineEvent>>>(&json).unwrap();
+            for event in events {
+                let _ = event.deserialize().unwrap();
+            }
+        });
+    });
+}
+
 criterion_group!(
     benches,
     deserialize_any_room_event,
     deserialize_any_state_event,
-    deserialize_specific_event
+    deserialize_specific_event,
+    deserialize_room_message_content,
+    deserialize_member_event,
+    deserialize_sync_timeline
 );
 
 criterion_main!(benches);
@@ -36,8 +36,8 @@ pub struct CallSdpStreamMetadataChangedEventContent {
 }
 
 impl CallSdpStreamMetadataChangedEventContent {
-    /// Creates a new `SdpStreamMetadataChangedEventContent` with the given call ID, party ID, VoIP
-    /// version and stream metadata.
+    /// Creates a new `CallSdpStreamMetadataChangedEventContent` with the given call ID, party ID,
+    /// VoIP version and stream metadata.
     pub fn new(
         call_id: OwnedVoipId,
         party_id: OwnedVoipId,
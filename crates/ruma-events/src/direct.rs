@@ -7,7 +7,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use ruma_common::{OwnedRoomId, OwnedUserId};
+use ruma_common::{OwnedRoomId, OwnedUserId, RoomId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -22,6 +22,42 @@ use serde::{Deserialize, Serialize};
 #[ruma_event(type = "m.direct", kind = GlobalAccountData)]
 pub struct DirectEventContent(pub BTreeMap<OwnedUserId, Vec<OwnedRoomId>>);
 
+impl DirectEventContent {
+    /// Mark the given room as a direct chat with the given user.
+    ///
+    /// Returns `false` if the room was already marked as a direct chat with this user, in which
+    /// case it is not added again.
+    pub fn add_dm(&mut self, user_id: OwnedUserId, room_id: OwnedRoomId) -> bool {
+        let rooms = self.0.entry(user_id).or_default();
+
+        if rooms.contains(&room_id) {
+            return false;
+        }
+
+        rooms.push(room_id);
+        true
+    }
+
+    /// Remove the given room from the direct chats of every user.
+    ///
+    /// Users that have no direct chats left are removed from the map.
+    ///
+    /// Returns `true` if the room was marked as a direct chat with at least one user.
+    pub fn remove_room(&mut self, room_id: &RoomId) -> bool {
+        let mut removed = false;
+
+        self.0.retain(|_, rooms| {
+            let len = rooms.len();
+            rooms.retain(|id| id != room_id);
+            removed |= rooms.len() != len;
+
+            !rooms.is_empty()
+        });
+
+        removed
+    }
+}
+
 impl Deref for DirectEventContent {
     type Target = BTreeMap<OwnedUserId, Vec<OwnedRoomId>>;
 
@@ -58,7 +94,7 @@ impl FromIterator<(OwnedUserId, Vec<OwnedRoomId>)> for DirectEventContent {
 mod tests {
     use std::collections::BTreeMap;
 
-    use ruma_common::{owned_room_id, owned_user_id};
+    use ruma_common::{owned_room_id, owned_user_id, room_id};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{DirectEvent, DirectEventContent};
@@ -96,4 +132,32 @@ mod tests {
         assert!(direct_rooms.contains(&rooms[0]));
         assert!(direct_rooms.contains(&rooms[1]));
     }
+
+    #[test]
+    fn add_dm() {
+        let mut content = DirectEventContent::default();
+        let alice = owned_user_id!("@alice:ruma.io");
+
+        assert!(content.add_dm(alice.clone(), owned_room_id!("!1:ruma.io")));
+        assert!(content.add_dm(alice.clone(), owned_room_id!("!2:ruma.io")));
+        assert!(!content.add_dm(alice.clone(), owned_room_id!("!1:ruma.io")));
+
+        assert_eq!(content[&alice], [owned_room_id!("!1:ruma.io"), owned_room_id!("!2:ruma.io")]);
+    }
+
+    #[test]
+    fn remove_room() {
+        let mut content = DirectEventContent::default();
+        let alice = owned_user_id!("@alice:ruma.io");
+        let bob = owned_user_id!("@bob:ruma.io");
+        content.add_dm(alice.clone(), owned_room_id!("!1:ruma.io"));
+        content.add_dm(alice.clone(), owned_room_id!("!2:ruma.io"));
+        content.add_dm(bob.clone(), owned_room_id!("!1:ruma.io"));
+
+        assert!(content.remove_room(room_id!("!1:ruma.io")));
+        assert!(!content.remove_room(room_id!("!3:ruma.io")));
+
+        assert_eq!(content[&alice], [owned_room_id!("!2:ruma.io")]);
+        assert!(!content.contains_key(&bob));
+    }
 }
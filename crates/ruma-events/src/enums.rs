@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use ruma_common::{
     serde::from_raw_json_value, EventId, MilliSecondsSinceUnixEpoch, OwnedRoomId, RoomId,
     TransactionId, UserId,
@@ -11,9 +13,13 @@ use super::room::encrypted;
 event_enum! {
     /// Any global account data event.
     enum GlobalAccountData {
+        "m.cross_signing.master" => super::secret_storage::secret,
+        "m.cross_signing.self_signing" => super::secret_storage::secret,
+        "m.cross_signing.user_signing" => super::secret_storage::secret,
         "m.direct" => super::direct,
         "m.identity_server" => super::identity_server,
         "m.ignored_user_list" => super::ignored_user_list,
+        "m.megolm_backup.v1" => super::secret_storage::secret,
         "m.push_rules" => super::push_rules,
         "m.secret_storage.default_key" => super::secret_storage::default_key,
         "m.secret_storage.key.*" => super::secret_storage::key,
@@ -212,6 +218,21 @@ impl AnyTimelineEvent {
             Self::State(e) => e.event_type().into(),
         }
     }
+
+    /// Converts `self` to an `AnySyncTimelineEvent` by removing the room ID, and returns the room
+    /// ID separately.
+    pub fn into_sync_event_and_room_id(self) -> (AnySyncTimelineEvent, OwnedRoomId) {
+        match self {
+            Self::MessageLike(ev) => {
+                let (ev, room_id) = ev.into_sync_event_and_room_id();
+                (AnySyncTimelineEvent::MessageLike(ev), room_id)
+            }
+            Self::State(ev) => {
+                let (ev, room_id) = ev.into_sync_event_and_room_id();
+                (AnySyncTimelineEvent::State(ev), room_id)
+            }
+        }
+    }
 }
 
 /// Any sync room event.
@@ -268,9 +289,13 @@ impl From<AnyTimelineEvent> for AnySyncTimelineEvent {
     }
 }
 
+/// Helper struct to determine both the kind and the type of a timeline event while only scanning
+/// the JSON once.
 #[derive(Deserialize)]
 #[allow(clippy::exhaustive_structs)]
-struct EventDeHelper {
+struct TimelineEventDeHelper<'a> {
+    #[serde(borrow, rename = "type")]
+    ev_type: Cow<'a, str>,
     state_key: Option<de::IgnoredAny>,
 }
 
@@ -280,12 +305,16 @@ impl<'de> Deserialize<'de> for AnyTimelineEvent {
         D: de::Deserializer<'de>,
     {
         let json = Box::<RawJsonValue>::deserialize(deserializer)?;
-        let EventDeHelper { state_key } = from_raw_json_value(&json)?;
+        let TimelineEventDeHelper { ev_type, state_key } = from_raw_json_value(&json)?;
 
         if state_key.is_some() {
-            Ok(AnyTimelineEvent::State(from_raw_json_value(&json)?))
+            AnyStateEvent::from_raw_json_with_type(&ev_type, &json)
+                .map(AnyTimelineEvent::State)
+                .map_err(de::Error::custom)
         } else {
-            Ok(AnyTimelineEvent::MessageLike(from_raw_json_value(&json)?))
+            AnyMessageLikeEvent::from_raw_json_with_type(&ev_type, &json)
+                .map(AnyTimelineEvent::MessageLike)
+                .map_err(de::Error::custom)
         }
     }
 }
@@ -296,12 +325,16 @@ impl<'de> Deserialize<'de> for AnySyncTimelineEvent {
         D: de::Deserializer<'de>,
     {
         let json = Box::<RawJsonValue>::deserialize(deserializer)?;
-        let EventDeHelper { state_key } = from_raw_json_value(&json)?;
+        let TimelineEventDeHelper { ev_type, state_key } = from_raw_json_value(&json)?;
 
         if state_key.is_some() {
-            Ok(AnySyncTimelineEvent::State(from_raw_json_value(&json)?))
+            AnySyncStateEvent::from_raw_json_with_type(&ev_type, &json)
+                .map(AnySyncTimelineEvent::State)
+                .map_err(de::Error::custom)
         } else {
-            Ok(AnySyncTimelineEvent::MessageLike(from_raw_json_value(&json)?))
+            AnySyncMessageLikeEvent::from_raw_json_with_type(&ev_type, &json)
+                .map(AnySyncTimelineEvent::MessageLike)
+                .map_err(de::Error::custom)
         }
     }
 }
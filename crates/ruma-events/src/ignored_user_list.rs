@@ -2,9 +2,9 @@
 //!
 //! [`m.ignored_user_list`]: https://spec.matrix.org/latest/client-server-api/#mignored_user_list
 
-use std::collections::BTreeMap;
+use std::collections::{btree_map, BTreeMap};
 
-use ruma_common::OwnedUserId;
+use ruma_common::{OwnedUserId, UserId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +32,28 @@ impl IgnoredUserListEventContent {
     pub fn users(ignored_users: impl IntoIterator<Item = OwnedUserId>) -> Self {
         Self::new(ignored_users.into_iter().map(|id| (id, IgnoredUser {})).collect())
     }
+
+    /// Add the given user to the list of ignored users.
+    ///
+    /// If the user is already ignored, the details about the ignored user are kept.
+    ///
+    /// Returns `true` if the user was not ignored before.
+    pub fn ignore(&mut self, user_id: OwnedUserId) -> bool {
+        match self.ignored_users.entry(user_id) {
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(IgnoredUser::new());
+                true
+            }
+            btree_map::Entry::Occupied(_) => false,
+        }
+    }
+
+    /// Remove the given user from the list of ignored users.
+    ///
+    /// Returns `true` if the user was ignored before.
+    pub fn unignore(&mut self, user_id: &UserId) -> bool {
+        self.ignored_users.remove(user_id).is_some()
+    }
 }
 
 /// Details about an ignored user.
@@ -91,4 +113,29 @@ mod tests {
             vec![user_id!("@carl:example.com")]
         );
     }
+
+    #[test]
+    fn ignore_and_unignore() {
+        let mut content = IgnoredUserListEventContent::default();
+
+        assert!(content.ignore(owned_user_id!("@carl:example.com")));
+        assert!(!content.ignore(owned_user_id!("@carl:example.com")));
+        assert!(content.ignore(owned_user_id!("@dave:example.com")));
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({
+                "ignored_users": {
+                    "@carl:example.com": {},
+                    "@dave:example.com": {},
+                },
+            })
+        );
+
+        assert!(content.unignore(user_id!("@carl:example.com")));
+        assert!(!content.unignore(user_id!("@carl:example.com")));
+        assert_eq!(
+            content.ignored_users.keys().collect::<Vec<_>>(),
+            vec![user_id!("@dave:example.com")]
+        );
+    }
 }
@@ -4,7 +4,7 @@
 
 use std::collections::BTreeMap;
 
-use ruma_common::{serde::Base64, OwnedTransactionId};
+use ruma_common::{serde::Base64, EventId, IdParseError, OwnedTransactionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
@@ -63,6 +63,24 @@ impl KeyVerificationAcceptEventContent {
     }
 }
 
+impl From<KeyVerificationAcceptEventContent> for ToDeviceKeyVerificationAcceptEventContent {
+    fn from(content: KeyVerificationAcceptEventContent) -> Self {
+        let KeyVerificationAcceptEventContent { method, relates_to } = content;
+        Self { method, transaction_id: relates_to.event_id.as_str().into() }
+    }
+}
+
+/// In-room verifications use the event ID of the `m.key.verification.request` message as the
+/// transaction ID, so the conversion fails if the accepted transaction ID is not an event ID.
+impl TryFrom<ToDeviceKeyVerificationAcceptEventContent> for KeyVerificationAcceptEventContent {
+    type Error = IdParseError;
+
+    fn try_from(content: ToDeviceKeyVerificationAcceptEventContent) -> Result<Self, Self::Error> {
+        let ToDeviceKeyVerificationAcceptEventContent { method, transaction_id } = content;
+        Ok(Self { method, relates_to: Reference::new(EventId::parse(transaction_id)?) })
+    }
+}
+
 /// An enum representing the different method specific `m.key.verification.accept` content.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -373,4 +391,38 @@ mod tests {
         assert_matches!(deser_content.method, AcceptMethod::SasV1(_));
         assert_eq!(deser_content.relates_to.event_id, event_id);
     }
+
+    #[test]
+    fn in_room_to_device_roundtrip() {
+        let json = json!({
+            "method": "m.sas.v1",
+            "commitment": "aGVsbG8",
+            "key_agreement_protocol": "curve25519",
+            "hash": "sha256",
+            "message_authentication_code": "hkdf-hmac-sha256.v2",
+            "short_authentication_string": ["decimal"],
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$1598361704261elfgc:localhost",
+            },
+        });
+        let content = from_json_value::<KeyVerificationAcceptEventContent>(json.clone()).unwrap();
+
+        let to_device_content = ToDeviceKeyVerificationAcceptEventContent::from(content);
+        assert_eq!(
+            to_json_value(&to_device_content).unwrap(),
+            json!({
+                "method": "m.sas.v1",
+                "commitment": "aGVsbG8",
+                "key_agreement_protocol": "curve25519",
+                "hash": "sha256",
+                "message_authentication_code": "hkdf-hmac-sha256.v2",
+                "short_authentication_string": ["decimal"],
+                "transaction_id": "$1598361704261elfgc:localhost",
+            })
+        );
+
+        let content = KeyVerificationAcceptEventContent::try_from(to_device_content).unwrap();
+        assert_eq!(to_json_value(&content).unwrap(), json);
+    }
 }
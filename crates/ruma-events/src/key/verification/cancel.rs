@@ -2,7 +2,7 @@
 //!
 //! [`m.key.verification.cancel`]: https://spec.matrix.org/latest/client-server-api/#mkeyverificationcancel
 
-use ruma_common::{serde::StringEnum, OwnedTransactionId};
+use ruma_common::{serde::StringEnum, EventId, IdParseError, OwnedTransactionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -62,6 +62,24 @@ impl KeyVerificationCancelEventContent {
     }
 }
 
+impl From<KeyVerificationCancelEventContent> for ToDeviceKeyVerificationCancelEventContent {
+    fn from(content: KeyVerificationCancelEventContent) -> Self {
+        let KeyVerificationCancelEventContent { reason, code, relates_to } = content;
+        Self { reason, code, transaction_id: relates_to.event_id.as_str().into() }
+    }
+}
+
+/// The cancel code and reason are kept as-is. Fails if the cancelled transaction ID is not the
+/// event ID of an in-room verification request.
+impl TryFrom<ToDeviceKeyVerificationCancelEventContent> for KeyVerificationCancelEventContent {
+    type Error = IdParseError;
+
+    fn try_from(content: ToDeviceKeyVerificationCancelEventContent) -> Result<Self, Self::Error> {
+        let ToDeviceKeyVerificationCancelEventContent { reason, code, transaction_id } = content;
+        Ok(Self { reason, code, relates_to: Reference::new(EventId::parse(transaction_id)?) })
+    }
+}
+
 /// An error code for why the process/request was cancelled by the user.
 ///
 /// Custom error codes should use the Java package naming convention.
@@ -131,7 +149,9 @@ pub enum CancelCode {
 mod tests {
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::CancelCode;
+    use super::{
+        CancelCode, KeyVerificationCancelEventContent, ToDeviceKeyVerificationCancelEventContent,
+    };
 
     #[test]
     fn cancel_codes_serialize_to_display_form() {
@@ -155,4 +175,30 @@ mod tests {
             "io.ruma.test".into()
         );
     }
+
+    #[test]
+    fn in_room_to_device_roundtrip() {
+        let json = json!({
+            "reason": "Some reason",
+            "code": "m.user",
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$1598361704261elfgc:localhost",
+            },
+        });
+        let content = from_json_value::<KeyVerificationCancelEventContent>(json.clone()).unwrap();
+
+        let to_device_content = ToDeviceKeyVerificationCancelEventContent::from(content);
+        assert_eq!(
+            to_json_value(&to_device_content).unwrap(),
+            json!({
+                "reason": "Some reason",
+                "code": "m.user",
+                "transaction_id": "$1598361704261elfgc:localhost",
+            })
+        );
+
+        let content = KeyVerificationCancelEventContent::try_from(to_device_content).unwrap();
+        assert_eq!(to_json_value(&content).unwrap(), json);
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! [`m.key.verification.done`]: https://spec.matrix.org/latest/client-server-api/#mkeyverificationdone
 
-use ruma_common::OwnedTransactionId;
+use ruma_common::{EventId, IdParseError, OwnedTransactionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -47,12 +47,30 @@ impl KeyVerificationDoneEventContent {
     }
 }
 
+impl From<KeyVerificationDoneEventContent> for ToDeviceKeyVerificationDoneEventContent {
+    fn from(content: KeyVerificationDoneEventContent) -> Self {
+        let KeyVerificationDoneEventContent { relates_to } = content;
+        Self { transaction_id: relates_to.event_id.as_str().into() }
+    }
+}
+
+/// To-device verifications usually use random transaction IDs, which can't be converted: this
+/// fails if the transaction ID is not a valid event ID.
+impl TryFrom<ToDeviceKeyVerificationDoneEventContent> for KeyVerificationDoneEventContent {
+    type Error = IdParseError;
+
+    fn try_from(content: ToDeviceKeyVerificationDoneEventContent) -> Result<Self, Self::Error> {
+        let ToDeviceKeyVerificationDoneEventContent { transaction_id } = content;
+        Ok(Self { relates_to: Reference::new(EventId::parse(transaction_id)?) })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ruma_common::owned_event_id;
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::KeyVerificationDoneEventContent;
+    use super::{KeyVerificationDoneEventContent, ToDeviceKeyVerificationDoneEventContent};
     use crate::relation::Reference;
 
     #[test]
@@ -83,4 +101,16 @@ mod tests {
         let content = from_json_value::<KeyVerificationDoneEventContent>(json_data).unwrap();
         assert_eq!(content.relates_to.event_id, "$1598361704261elfgc:localhost");
     }
+
+    #[test]
+    fn in_room_to_device_roundtrip() {
+        let event_id = owned_event_id!("$1598361704261elfgc:localhost");
+        let content = KeyVerificationDoneEventContent::new(Reference::new(event_id.clone()));
+
+        let to_device_content = ToDeviceKeyVerificationDoneEventContent::from(content);
+        assert_eq!(to_device_content.transaction_id, event_id.as_str());
+
+        let content = KeyVerificationDoneEventContent::try_from(to_device_content).unwrap();
+        assert_eq!(content.relates_to.event_id, event_id);
+    }
 }
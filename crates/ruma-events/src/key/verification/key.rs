@@ -2,7 +2,7 @@
 //!
 //! [`m.key.verification.key`]: https://spec.matrix.org/latest/client-server-api/#mkeyverificationkey
 
-use ruma_common::{serde::Base64, OwnedTransactionId};
+use ruma_common::{serde::Base64, EventId, IdParseError, OwnedTransactionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -53,3 +53,52 @@ impl KeyVerificationKeyEventContent {
         Self { key, relates_to }
     }
 }
+
+impl From<KeyVerificationKeyEventContent> for ToDeviceKeyVerificationKeyEventContent {
+    fn from(content: KeyVerificationKeyEventContent) -> Self {
+        let KeyVerificationKeyEventContent { key, relates_to } = content;
+        Self { key, transaction_id: relates_to.event_id.as_str().into() }
+    }
+}
+
+/// The ephemeral public key is kept as-is. Fails if the transaction ID can't be parsed as the
+/// event ID of the in-room request that the key exchange belongs to.
+impl TryFrom<ToDeviceKeyVerificationKeyEventContent> for KeyVerificationKeyEventContent {
+    type Error = IdParseError;
+
+    fn try_from(content: ToDeviceKeyVerificationKeyEventContent) -> Result<Self, Self::Error> {
+        let ToDeviceKeyVerificationKeyEventContent { key, transaction_id } = content;
+        Ok(Self { key, relates_to: Reference::new(EventId::parse(transaction_id)?) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{KeyVerificationKeyEventContent, ToDeviceKeyVerificationKeyEventContent};
+
+    #[test]
+    fn in_room_to_device_roundtrip() {
+        let json = json!({
+            "key": "aGVsbG8",
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$1598361704261elfgc:localhost",
+            },
+        });
+        let content = from_json_value::<KeyVerificationKeyEventContent>(json.clone()).unwrap();
+
+        let to_device_content = ToDeviceKeyVerificationKeyEventContent::from(content);
+        assert_eq!(
+            to_json_value(&to_device_content).unwrap(),
+            json!({
+                "key": "aGVsbG8",
+                "transaction_id": "$1598361704261elfgc:localhost",
+            })
+        );
+
+        let content = KeyVerificationKeyEventContent::try_from(to_device_content).unwrap();
+        assert_eq!(to_json_value(&content).unwrap(), json);
+    }
+}
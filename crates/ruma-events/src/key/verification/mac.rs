@@ -4,7 +4,7 @@
 
 use std::collections::BTreeMap;
 
-use ruma_common::{serde::Base64, OwnedTransactionId};
+use ruma_common::{serde::Base64, EventId, IdParseError, OwnedTransactionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -72,3 +72,54 @@ impl KeyVerificationMacEventContent {
         Self { mac, keys, relates_to }
     }
 }
+
+impl From<KeyVerificationMacEventContent> for ToDeviceKeyVerificationMacEventContent {
+    fn from(content: KeyVerificationMacEventContent) -> Self {
+        let KeyVerificationMacEventContent { mac, keys, relates_to } = content;
+        Self { mac, keys, transaction_id: relates_to.event_id.as_str().into() }
+    }
+}
+
+/// The MACs of the keys are kept as-is. Fails if the transaction ID can't be parsed as the event
+/// ID of the in-room request whose keys are verified.
+impl TryFrom<ToDeviceKeyVerificationMacEventContent> for KeyVerificationMacEventContent {
+    type Error = IdParseError;
+
+    fn try_from(content: ToDeviceKeyVerificationMacEventContent) -> Result<Self, Self::Error> {
+        let ToDeviceKeyVerificationMacEventContent { mac, keys, transaction_id } = content;
+        Ok(Self { mac, keys, relates_to: Reference::new(EventId::parse(transaction_id)?) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::{KeyVerificationMacEventContent, ToDeviceKeyVerificationMacEventContent};
+
+    #[test]
+    fn in_room_to_device_roundtrip() {
+        let json = json!({
+            "mac": { "ed25519:ABCDEF": "aGVsbG8" },
+            "keys": "d29ybGQ",
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$1598361704261elfgc:localhost",
+            },
+        });
+        let content = from_json_value::<KeyVerificationMacEventContent>(json.clone()).unwrap();
+
+        let to_device_content = ToDeviceKeyVerificationMacEventContent::from(content);
+        assert_eq!(
+            to_json_value(&to_device_content).unwrap(),
+            json!({
+                "mac": { "ed25519:ABCDEF": "aGVsbG8" },
+                "keys": "d29ybGQ",
+                "transaction_id": "$1598361704261elfgc:localhost",
+            })
+        );
+
+        let content = KeyVerificationMacEventContent::try_from(to_device_content).unwrap();
+        assert_eq!(to_json_value(&content).unwrap(), json);
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! [`m.key.verification.ready`]: https://spec.matrix.org/latest/client-server-api/#mkeyverificationready
 
-use ruma_common::{OwnedDeviceId, OwnedTransactionId};
+use ruma_common::{EventId, IdParseError, OwnedDeviceId, OwnedTransactionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -73,6 +73,29 @@ impl KeyVerificationReadyEventContent {
     }
 }
 
+impl From<KeyVerificationReadyEventContent> for ToDeviceKeyVerificationReadyEventContent {
+    fn from(content: KeyVerificationReadyEventContent) -> Self {
+        let KeyVerificationReadyEventContent { from_device, methods, relates_to } = content;
+        Self { from_device, methods, transaction_id: relates_to.event_id.as_str().into() }
+    }
+}
+
+/// The `from_device` and supported methods are kept as-is. Fails if the transaction ID is not the
+/// event ID of the in-room request being answered.
+impl TryFrom<ToDeviceKeyVerificationReadyEventContent> for KeyVerificationReadyEventContent {
+    type Error = IdParseError;
+
+    fn try_from(content: ToDeviceKeyVerificationReadyEventContent) -> Result<Self, Self::Error> {
+        let ToDeviceKeyVerificationReadyEventContent { from_device, methods, transaction_id } =
+            content;
+        Ok(Self {
+            from_device,
+            methods,
+            relates_to: Reference::new(EventId::parse(transaction_id)?),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ruma_common::{owned_event_id, OwnedDeviceId};
@@ -146,4 +169,30 @@ mod tests {
         assert_eq!(content.methods, vec![VerificationMethod::SasV1]);
         assert_eq!(content.transaction_id, "456");
     }
+
+    #[test]
+    fn in_room_to_device_roundtrip() {
+        let json = json!({
+            "from_device": "123",
+            "methods": ["m.sas.v1"],
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$1598361704261elfgc:localhost",
+            },
+        });
+        let content = from_json_value::<KeyVerificationReadyEventContent>(json.clone()).unwrap();
+
+        let to_device_content = ToDeviceKeyVerificationReadyEventContent::from(content);
+        assert_eq!(
+            to_json_value(&to_device_content).unwrap(),
+            json!({
+                "from_device": "123",
+                "methods": ["m.sas.v1"],
+                "transaction_id": "$1598361704261elfgc:localhost",
+            })
+        );
+
+        let content = KeyVerificationReadyEventContent::try_from(to_device_content).unwrap();
+        assert_eq!(to_json_value(&content).unwrap(), json);
+    }
 }
@@ -4,7 +4,7 @@
 
 use std::{collections::BTreeMap, fmt};
 
-use ruma_common::{serde::Base64, OwnedDeviceId, OwnedTransactionId};
+use ruma_common::{serde::Base64, EventId, IdParseError, OwnedDeviceId, OwnedTransactionId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
@@ -75,6 +75,29 @@ impl KeyVerificationStartEventContent {
     }
 }
 
+impl From<KeyVerificationStartEventContent> for ToDeviceKeyVerificationStartEventContent {
+    fn from(content: KeyVerificationStartEventContent) -> Self {
+        let KeyVerificationStartEventContent { from_device, method, relates_to } = content;
+        Self { from_device, method, transaction_id: relates_to.event_id.as_str().into() }
+    }
+}
+
+/// The start method is kept as-is, including its method-specific fields. Fails if the
+/// transaction ID is not the event ID of the in-room request being started.
+impl TryFrom<ToDeviceKeyVerificationStartEventContent> for KeyVerificationStartEventContent {
+    type Error = IdParseError;
+
+    fn try_from(content: ToDeviceKeyVerificationStartEventContent) -> Result<Self, Self::Error> {
+        let ToDeviceKeyVerificationStartEventContent { from_device, method, transaction_id } =
+            content;
+        Ok(Self {
+            from_device,
+            method,
+            relates_to: Reference::new(EventId::parse(transaction_id)?),
+        })
+    }
+}
+
 /// An enum representing the different method specific `m.key.verification.start` content.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -488,4 +511,38 @@ mod tests {
         assert_matches!(content.method, StartMethod::ReciprocateV1(reciprocate));
         assert_eq!(reciprocate.secret.encode(), "c2VjcmV0Cg");
     }
+
+    #[test]
+    fn in_room_to_device_roundtrip() {
+        let json = json!({
+            "from_device": "123",
+            "method": "m.reciprocate.v1",
+            "secret": "c2VjcmV0Cg",
+            "m.relates_to": {
+                "rel_type": "m.reference",
+                "event_id": "$1598361704261elfgc:localhost",
+            },
+        });
+        let content = from_json_value::<KeyVerificationStartEventContent>(json.clone()).unwrap();
+
+        let to_device_content = ToDeviceKeyVerificationStartEventContent::from(content);
+        assert_eq!(to_device_content.transaction_id, "$1598361704261elfgc:localhost");
+        assert_matches!(&to_device_content.method, StartMethod::ReciprocateV1(_));
+
+        let content = KeyVerificationStartEventContent::try_from(to_device_content).unwrap();
+        assert_eq!(to_json_value(&content).unwrap(), json);
+    }
+
+    #[test]
+    fn to_device_to_in_room_invalid_transaction_id() {
+        let to_device_content = ToDeviceKeyVerificationStartEventContent::new(
+            "123".into(),
+            "456".into(),
+            StartMethod::ReciprocateV1(ReciprocateV1Content::new(
+                Base64::parse("c2VjcmV0Cg").unwrap(),
+            )),
+        );
+
+        KeyVerificationStartEventContent::try_from(to_device_content).unwrap_err();
+    }
 }
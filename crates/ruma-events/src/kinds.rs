@@ -311,6 +311,32 @@ pub struct StrippedStateEvent<C: PossiblyRedactedStateEventContent> {
     pub state_key: C::StateKey,
 }
 
+impl<C: PossiblyRedactedStateEventContent> Serialize for StrippedStateEvent<C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("StrippedStateEvent", 4)?;
+        state.serialize_field("type", &self.content.event_type())?;
+        state.serialize_field("content", &self.content)?;
+        state.serialize_field("sender", &self.sender)?;
+        state.serialize_field("state_key", &self.state_key)?;
+        state.end()
+    }
+}
+
+impl<C> From<OriginalSyncStateEvent<C>> for StrippedStateEvent<C::PossiblyRedacted>
+where
+    C: StaticStateEventContent,
+    C::PossiblyRedacted: PossiblyRedactedStateEventContent<StateKey = C::StateKey> + From<C>,
+{
+    /// Strip the `event_id`, `origin_server_ts` and `unsigned` fields of the given event, to
+    /// use it for example in the `invite_room_state` of an invite.
+    fn from(event: OriginalSyncStateEvent<C>) -> Self {
+        Self { content: event.content.into(), sender: event.sender, state_key: event.state_key }
+    }
+}
+
 /// A minimal state event, used for creating a new room.
 #[derive(Clone, Debug, Event)]
 pub struct InitialStateEvent<C: StaticStateEventContent> {
@@ -666,6 +692,21 @@ impl_possibly_redacted_event!(
         pub fn as_original(&self) -> Option<&OriginalMessageLikeEvent<C>> {
             as_variant!(self, Self::Original)
         }
+
+        /// Convert this full event into a sync event (one without a `room_id` field), and return
+        /// the room ID separately.
+        pub fn into_sync_event_and_room_id(self) -> (SyncMessageLikeEvent<C>, OwnedRoomId) {
+            match self {
+                Self::Original(ev) => {
+                    let (ev, room_id) = ev.into_sync_event_and_room_id();
+                    (SyncMessageLikeEvent::Original(ev), room_id)
+                }
+                Self::Redacted(ev) => {
+                    let (ev, room_id) = ev.into_sync_event_and_room_id();
+                    (SyncMessageLikeEvent::Redacted(ev), room_id)
+                }
+            }
+        }
     }
 );
 
@@ -713,6 +754,21 @@ impl_possibly_redacted_event!(
         pub fn as_original(&self) -> Option<&OriginalStateEvent<C>> {
             as_variant!(self, Self::Original)
         }
+
+        /// Convert this full event into a sync event (one without a `room_id` field), and return
+        /// the room ID separately.
+        pub fn into_sync_event_and_room_id(self) -> (SyncStateEvent<C>, OwnedRoomId) {
+            match self {
+                Self::Original(ev) => {
+                    let (ev, room_id) = ev.into_sync_event_and_room_id();
+                    (SyncStateEvent::Original(ev), room_id)
+                }
+                Self::Redacted(ev) => {
+                    let (ev, room_id) = ev.into_sync_event_and_room_id();
+                    (SyncStateEvent::Redacted(ev), room_id)
+                }
+            }
+        }
     }
 );
 
@@ -36,6 +36,10 @@
 //! }
 //! ```
 //!
+//! The event type can be any string, so this also works for custom events defined outside of
+//! Ruma, like `com.example.bridge.status`. The type is available through the
+//! [`StaticEventContent::TYPE`] constant.
+//!
 //! This can be used with events structs, such as passing it into
 //! `ruma::api::client::state::send_state_event`'s `Request`.
 //!
@@ -55,6 +55,13 @@ pub struct PossiblyRedactedPolicyRuleEventContent {
     pub reason: Option<String>,
 }
 
+impl From<PolicyRuleEventContent> for PossiblyRedactedPolicyRuleEventContent {
+    fn from(content: PolicyRuleEventContent) -> Self {
+        let PolicyRuleEventContent { entity, recommendation, reason } = content;
+        Self { entity: Some(entity), recommendation: Some(recommendation), reason: Some(reason) }
+    }
+}
+
 /// The possible actions that can be taken.
 #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/doc/string_enum.md"))]
 #[derive(Clone, PartialEq, Eq, StringEnum)]
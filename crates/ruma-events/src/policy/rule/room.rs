@@ -35,6 +35,12 @@ impl PossiblyRedactedStateEventContent for PossiblyRedactedPolicyRuleRoomEventCo
     type StateKey = String;
 }
 
+impl From<PolicyRuleRoomEventContent> for PossiblyRedactedPolicyRuleRoomEventContent {
+    fn from(content: PolicyRuleRoomEventContent) -> Self {
+        Self(content.0.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ruma_common::serde::Raw;
@@ -34,3 +34,9 @@ impl EventContent for PossiblyRedactedPolicyRuleServerEventContent {
 impl PossiblyRedactedStateEventContent for PossiblyRedactedPolicyRuleServerEventContent {
     type StateKey = String;
 }
+
+impl From<PolicyRuleServerEventContent> for PossiblyRedactedPolicyRuleServerEventContent {
+    fn from(content: PolicyRuleServerEventContent) -> Self {
+        Self(content.0.into())
+    }
+}
@@ -34,3 +34,9 @@ impl EventContent for PossiblyRedactedPolicyRuleUserEventContent {
 impl PossiblyRedactedStateEventContent for PossiblyRedactedPolicyRuleUserEventContent {
     type StateKey = String;
 }
+
+impl From<PolicyRuleUserEventContent> for PossiblyRedactedPolicyRuleUserEventContent {
+    fn from(content: PolicyRuleUserEventContent) -> Self {
+        Self(content.0.into())
+    }
+}
@@ -35,9 +35,10 @@ impl From<Ruleset> for PushRulesEventContent {
 
 #[cfg(test)]
 mod tests {
-    use serde_json::{from_value as from_json_value, json};
+    use ruma_common::{push::Ruleset, user_id};
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::PushRulesEvent;
+    use super::{PushRulesEvent, PushRulesEventContent};
 
     #[test]
     fn sanity_check() {
@@ -232,4 +233,22 @@ mod tests {
 
         from_json_value::<PushRulesEvent>(json_data).unwrap();
     }
+
+    #[test]
+    fn server_default_rules_roundtrip() {
+        let content =
+            PushRulesEventContent::new(Ruleset::server_default(user_id!("@alice:example.org")));
+        let json = to_json_value(&content).unwrap();
+
+        let kinds = json["global"].as_object().unwrap();
+        assert!(!kinds.is_empty());
+        for rules in kinds.values() {
+            for rule in rules.as_array().unwrap() {
+                assert_eq!(rule["default"], true, "{rule}");
+            }
+        }
+
+        let roundtrip = from_json_value::<PushRulesEventContent>(json.clone()).unwrap();
+        assert_eq!(to_json_value(&roundtrip).unwrap(), json);
+    }
 }
@@ -93,4 +93,21 @@ mod tests {
         assert_eq!(deser_content.relates_to.event_id, content.relates_to.event_id);
         assert_eq!(deser_content.relates_to.key, content.relates_to.key);
     }
+
+    #[test]
+    fn key_with_variation_selector_roundtrip() {
+        // Red heart followed by the emoji presentation selector U+FE0F.
+        let key = "\u{2764}\u{fe0f}";
+        let content = ReactionEventContent::new(Annotation::new(
+            owned_event_id!("$my_reaction"),
+            key.to_owned(),
+        ));
+
+        let json = to_json_value(&content).unwrap();
+        assert_eq!(json["m.relates_to"]["key"], key);
+
+        let deser_content = from_json_value::<ReactionEventContent>(json).unwrap();
+        assert_eq!(deser_content.relates_to.key, key);
+        assert_eq!(deser_content.relates_to.key.chars().count(), 2);
+    }
 }
@@ -38,6 +38,26 @@ impl ReceiptEventContent {
             Some((event_id.as_ref(), receipt))
         })
     }
+
+    /// Get the receipt for the given user ID with the given receipt type in the given thread, if
+    /// it exists.
+    ///
+    /// Since a user can have one receipt of each type per thread, this should be preferred over
+    /// [`ReceiptEventContent::user_receipt()`] when threaded receipts are used.
+    pub fn user_receipt_in_thread(
+        &self,
+        user_id: &UserId,
+        receipt_type: ReceiptType,
+        thread: &ReceiptThread,
+    ) -> Option<(&EventId, &Receipt)> {
+        self.iter().find_map(|(event_id, receipts)| {
+            let receipt = receipts
+                .get(&receipt_type)?
+                .get(user_id)
+                .filter(|receipt| receipt.thread == *thread)?;
+            Some((event_id.as_ref(), receipt))
+        })
+    }
 }
 
 impl Deref for ReceiptEventContent {
@@ -2,7 +2,7 @@
 //!
 //! [relationships between events]: https://spec.matrix.org/latest/client-server-api/#forming-relationships-between-events
 
-use std::fmt::Debug;
+use std::{collections::BTreeMap, fmt::Debug};
 
 use js_int::UInt;
 use ruma_common::{
@@ -10,8 +10,11 @@ use ruma_common::{
     OwnedEventId,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 
 use super::AnyMessageLikeEvent;
+#[cfg(feature = "unstable-msc2677")]
+use crate::MessageLikeEventType;
 use crate::PrivOwnedStr;
 
 mod rel_serde;
@@ -155,6 +158,56 @@ impl BundledThread {
     }
 }
 
+/// A bundled annotation.
+#[cfg(feature = "unstable-msc2677")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct BundledAnnotation {
+    /// The type of the annotating events, usually `m.reaction`.
+    #[serde(rename = "type")]
+    pub event_type: MessageLikeEventType,
+
+    /// The key of the annotation, e.g. the emoji of a reaction.
+    pub key: String,
+
+    /// The number of annotating events with this type and key.
+    pub count: UInt,
+}
+
+#[cfg(feature = "unstable-msc2677")]
+impl BundledAnnotation {
+    /// Creates a new `BundledAnnotation` with the given event type, key and count.
+    pub fn new(event_type: MessageLikeEventType, key: String, count: UInt) -> Self {
+        Self { event_type, key, count }
+    }
+
+    /// Creates a new `BundledAnnotation` for reactions with the given key and count.
+    pub fn reaction(key: String, count: UInt) -> Self {
+        Self::new(MessageLikeEventType::Reaction, key, count)
+    }
+}
+
+/// A chunk of annotations.
+#[cfg(feature = "unstable-msc2677")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+pub struct AnnotationChunk {
+    /// A batch of bundled annotations.
+    pub chunk: Vec<BundledAnnotation>,
+
+    /// Used to paginate through the remaining annotations, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_batch: Option<String>,
+}
+
+#[cfg(feature = "unstable-msc2677")]
+impl AnnotationChunk {
+    /// Creates a new `AnnotationChunk` with the given chunk.
+    pub fn new(chunk: Vec<BundledAnnotation>) -> Self {
+        Self { chunk, next_batch: None }
+    }
+}
+
 /// A [reference] to another event.
 ///
 /// [reference]: https://spec.matrix.org/latest/client-server-api/#reference-relations
@@ -206,32 +259,45 @@ impl ReferenceChunk {
 /// [Bundled aggregations] of related child events of a message-like event.
 ///
 /// [Bundled aggregations]: https://spec.matrix.org/latest/client-server-api/#aggregations-of-child-events
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 pub struct BundledMessageLikeRelations<E> {
     /// Replacement relation.
-    #[serde(rename = "m.replace", skip_serializing_if = "Option::is_none")]
     pub replace: Option<Box<E>>,
 
     /// Set when the above fails to deserialize.
     ///
     /// Intentionally *not* public.
-    #[serde(skip_serializing)]
     has_invalid_replacement: bool,
 
     /// Thread relation.
-    #[serde(rename = "m.thread", skip_serializing_if = "Option::is_none")]
     pub thread: Option<Box<BundledThread>>,
 
     /// Reference relations.
-    #[serde(rename = "m.reference", skip_serializing_if = "Option::is_none")]
     pub reference: Option<Box<ReferenceChunk>>,
+
+    /// Annotation relations, as defined in [MSC2677].
+    ///
+    /// [MSC2677]: https://github.com/matrix-org/matrix-spec-proposals/pull/2677
+    #[cfg(feature = "unstable-msc2677")]
+    pub annotation: Option<Box<AnnotationChunk>>,
+
+    /// Aggregations of unknown relation types, keyed by relation type.
+    pub other: BTreeMap<String, JsonValue>,
 }
 
 impl<E> BundledMessageLikeRelations<E> {
     /// Creates a new empty `BundledMessageLikeRelations`.
     pub const fn new() -> Self {
-        Self { replace: None, has_invalid_replacement: false, thread: None, reference: None }
+        Self {
+            replace: None,
+            has_invalid_replacement: false,
+            thread: None,
+            reference: None,
+            #[cfg(feature = "unstable-msc2677")]
+            annotation: None,
+            other: BTreeMap::new(),
+        }
     }
 
     /// Whether this bundle contains a replacement relation.
@@ -246,15 +312,45 @@ impl<E> BundledMessageLikeRelations<E> {
 
     /// Returns `true` if all fields are empty.
     pub fn is_empty(&self) -> bool {
-        self.replace.is_none() && self.thread.is_none() && self.reference.is_none()
+        self.replace.is_none()
+            && self.thread.is_none()
+            && self.reference.is_none()
+            && self.has_no_annotation()
+            && self.other.is_empty()
+    }
+
+    #[cfg(feature = "unstable-msc2677")]
+    fn has_no_annotation(&self) -> bool {
+        self.annotation.is_none()
+    }
+
+    #[cfg(not(feature = "unstable-msc2677"))]
+    fn has_no_annotation(&self) -> bool {
+        true
     }
 
     /// Transform `BundledMessageLikeRelations<E>` to `BundledMessageLikeRelations<T>` using the
     /// given closure to convert the `replace` field if it is `Some(_)`.
     pub(crate) fn map_replace<T>(self, f: impl FnOnce(E) -> T) -> BundledMessageLikeRelations<T> {
-        let Self { replace, has_invalid_replacement, thread, reference } = self;
+        let Self {
+            replace,
+            has_invalid_replacement,
+            thread,
+            reference,
+            #[cfg(feature = "unstable-msc2677")]
+            annotation,
+            other,
+        } = self;
         let replace = replace.map(|r| Box::new(f(*r)));
-        BundledMessageLikeRelations { replace, has_invalid_replacement, thread, reference }
+        BundledMessageLikeRelations {
+            replace,
+            has_invalid_replacement,
+            thread,
+            reference,
+            #[cfg(feature = "unstable-msc2677")]
+            annotation,
+            other,
+        }
     }
 }
 
@@ -267,27 +363,28 @@ impl<E> Default for BundledMessageLikeRelations<E> {
 /// [Bundled aggregations] of related child events of a state event.
 ///
 /// [Bundled aggregations]: https://spec.matrix.org/latest/client-server-api/#aggregations-of-child-events
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
 pub struct BundledStateRelations {
     /// Thread relation.
-    #[serde(rename = "m.thread", skip_serializing_if = "Option::is_none")]
     pub thread: Option<Box<BundledThread>>,
 
     /// Reference relations.
-    #[serde(rename = "m.reference", skip_serializing_if = "Option::is_none")]
     pub reference: Option<Box<ReferenceChunk>>,
+
+    /// Aggregations of unknown relation types, keyed by relation type.
+    pub other: BTreeMap<String, JsonValue>,
 }
 
 impl BundledStateRelations {
     /// Creates a new empty `BundledStateRelations`.
     pub const fn new() -> Self {
-        Self { thread: None, reference: None }
+        Self { thread: None, reference: None, other: BTreeMap::new() }
     }
 
     /// Returns `true` if all fields are empty.
     pub fn is_empty(&self) -> bool {
-        self.thread.is_none() && self.reference.is_none()
+        self.thread.is_none() && self.reference.is_none() && self.other.is_empty()
     }
 }
 
@@ -1,17 +1,14 @@
+use std::{collections::BTreeMap, fmt, marker::PhantomData};
+
 use ruma_common::serde::Raw;
-use serde::{de::DeserializeOwned, Deserialize, Deserializer};
-
-use super::{BundledMessageLikeRelations, BundledThread, ReferenceChunk};
-
-#[derive(Deserialize)]
-struct BundledMessageLikeRelationsJsonRepr<E> {
-    #[serde(rename = "m.replace")]
-    replace: Option<Raw<Box<E>>>,
-    #[serde(rename = "m.thread")]
-    thread: Option<Box<BundledThread>>,
-    #[serde(rename = "m.reference")]
-    reference: Option<Box<ReferenceChunk>>,
-}
+use serde::{
+    de::{self, DeserializeOwned, Deserializer, MapAccess, Visitor},
+    ser::{SerializeMap, Serializer},
+    Deserialize, Serialize,
+};
+use serde_json::Value as JsonValue;
+
+use super::{BundledMessageLikeRelations, BundledStateRelations};
 
 impl<'de, E> Deserialize<'de> for BundledMessageLikeRelations<E>
 where
@@ -21,15 +18,178 @@ where
     where
         D: Deserializer<'de>,
     {
-        let BundledMessageLikeRelationsJsonRepr { replace, thread, reference } =
-            BundledMessageLikeRelationsJsonRepr::deserialize(deserializer)?;
+        struct BundledMessageLikeRelationsVisitor<E>(PhantomData<E>);
+
+        impl<'de, E> Visitor<'de> for BundledMessageLikeRelationsVisitor<E>
+        where
+            E: DeserializeOwned,
+        {
+            type Value = BundledMessageLikeRelations<E>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map of bundled aggregations")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut replace: Option<Option<Raw<Box<E>>>> = None;
+                let mut thread = None;
+                let mut reference = None;
+                #[cfg(feature = "unstable-msc2677")]
+                let mut annotation = None;
+                let mut other = BTreeMap::new();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "m.replace" => set_once(&mut replace, "m.replace", &mut map)?,
+                        "m.thread" => set_once(&mut thread, "m.thread", &mut map)?,
+                        "m.reference" => set_once(&mut reference, "m.reference", &mut map)?,
+                        #[cfg(feature = "unstable-msc2677")]
+                        "m.annotation" => set_once(&mut annotation, "m.annotation", &mut map)?,
+                        _ => insert_other(&mut other, key, &mut map)?,
+                    }
+                }
+
+                let (replace, has_invalid_replacement) =
+                    match replace.flatten().as_ref().map(Raw::deserialize).transpose() {
+                        Ok(replace) => (replace, false),
+                        Err(_) => (None, true),
+                    };
+
+                Ok(BundledMessageLikeRelations {
+                    replace,
+                    has_invalid_replacement,
+                    thread: thread.flatten(),
+                    reference: reference.flatten(),
+                    #[cfg(feature = "unstable-msc2677")]
+                    annotation: annotation.flatten(),
+                    other,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(BundledMessageLikeRelationsVisitor(PhantomData))
+    }
+}
+
+impl<E> Serialize for BundledMessageLikeRelations<E>
+where
+    E: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
 
-        let (replace, has_invalid_replacement) =
-            match replace.as_ref().map(Raw::deserialize).transpose() {
-                Ok(replace) => (replace, false),
-                Err(_) => (None, true),
-            };
+        if let Some(replace) = &self.replace {
+            map.serialize_entry("m.replace", replace)?;
+        }
+        if let Some(thread) = &self.thread {
+            map.serialize_entry("m.thread", thread)?;
+        }
+        if let Some(reference) = &self.reference {
+            map.serialize_entry("m.reference", reference)?;
+        }
+        #[cfg(feature = "unstable-msc2677")]
+        if let Some(annotation) = &self.annotation {
+            map.serialize_entry("m.annotation", annotation)?;
+        }
+        for (rel_type, aggregation) in &self.other {
+            map.serialize_entry(rel_type, aggregation)?;
+        }
 
-        Ok(BundledMessageLikeRelations { replace, has_invalid_replacement, thread, reference })
+        map.end()
     }
 }
+
+impl<'de> Deserialize<'de> for BundledStateRelations {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BundledStateRelationsVisitor;
+
+        impl<'de> Visitor<'de> for BundledStateRelationsVisitor {
+            type Value = BundledStateRelations;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map of bundled aggregations")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut thread = None;
+                let mut reference = None;
+                let mut other = BTreeMap::new();
+
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "m.thread" => set_once(&mut thread, "m.thread", &mut map)?,
+                        "m.reference" => set_once(&mut reference, "m.reference", &mut map)?,
+                        _ => insert_other(&mut other, key, &mut map)?,
+                    }
+                }
+
+                Ok(BundledStateRelations {
+                    thread: thread.flatten(),
+                    reference: reference.flatten(),
+                    other,
+                })
+            }
+        }
+
+        deserializer.deserialize_map(BundledStateRelationsVisitor)
+    }
+}
+
+impl Serialize for BundledStateRelations {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+
+        if let Some(thread) = &self.thread {
+            map.serialize_entry("m.thread", thread)?;
+        }
+        if let Some(reference) = &self.reference {
+            map.serialize_entry("m.reference", reference)?;
+        }
+        for (rel_type, aggregation) in &self.other {
+            map.serialize_entry(rel_type, aggregation)?;
+        }
+
+        map.end()
+    }
+}
+
+/// Deserializes the value of a known relation type, which must only appear once.
+fn set_once<'de, T, A>(
+    field: &mut Option<Option<T>>,
+    rel_type: &'static str,
+    map: &mut A,
+) -> Result<(), A::Error>
+where
+    T: Deserialize<'de>,
+    A: MapAccess<'de>,
+{
+    if field.is_some() {
+        return Err(de::Error::duplicate_field(rel_type));
+    }
+
+    *field = Some(map.next_value()?);
+    Ok(())
+}
+
+/// Keeps the aggregation of an unknown relation type as-is.
+fn insert_other<'de, A>(
+    other: &mut BTreeMap<String, JsonValue>,
+    rel_type: String,
+    map: &mut A,
+) -> Result<(), A::Error>
+where
+    A: MapAccess<'de>,
+{
+    let aggregation = map.next_value()?;
+    other.insert(rel_type, aggregation);
+    Ok(())
+}
@@ -102,7 +102,11 @@ pub struct ImageInfo {
     /// This uses the unstable prefix in
     /// [MSC2448](https://github.com/matrix-org/matrix-spec-proposals/pull/2448).
     #[cfg(feature = "unstable-msc2448")]
-    #[serde(rename = "xyz.amorgan.blurhash", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "xyz.amorgan.blurhash",
+        alias = "blurhash",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub blurhash: Option<String>,
 }
 
@@ -132,6 +136,18 @@ pub struct ThumbnailInfo {
     /// The file size of the thumbnail in bytes.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<UInt>,
+
+    /// The [BlurHash](https://blurha.sh) for this thumbnail.
+    ///
+    /// This uses the unstable prefix in
+    /// [MSC2448](https://github.com/matrix-org/matrix-spec-proposals/pull/2448).
+    #[cfg(feature = "unstable-msc2448")]
+    #[serde(
+        rename = "xyz.amorgan.blurhash",
+        alias = "blurhash",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub blurhash: Option<String>,
 }
 
 impl ThumbnailInfo {
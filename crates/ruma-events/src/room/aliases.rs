@@ -1,6 +1,8 @@
 //! Types for the `m.room.aliases` event.
 
-use ruma_common::{OwnedRoomAliasId, OwnedServerName, RoomVersionId};
+use ruma_common::{
+    room_version_rules::RedactionRules, OwnedRoomAliasId, OwnedServerName, RoomVersionId,
+};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -28,16 +30,9 @@ impl RedactContent for RoomAliasesEventContent {
     type Redacted = RedactedRoomAliasesEventContent;
 
     fn redact(self, version: &RoomVersionId) -> RedactedRoomAliasesEventContent {
-        // We compare the long way to avoid pre version 6 behavior if/when
-        // a new room version is introduced.
-        let aliases = match version {
-            RoomVersionId::V1
-            | RoomVersionId::V2
-            | RoomVersionId::V3
-            | RoomVersionId::V4
-            | RoomVersionId::V5 => Some(self.aliases),
-            _ => None,
-        };
+        let aliases = RedactionRules::for_room_version(version)
+            .keep_room_aliases_aliases
+            .then_some(self.aliases);
 
         RedactedRoomAliasesEventContent { aliases }
     }
@@ -67,7 +67,11 @@ pub struct ImageInfo {
     /// This uses the unstable prefix in
     /// [MSC2448](https://github.com/matrix-org/matrix-spec-proposals/pull/2448).
     #[cfg(feature = "unstable-msc2448")]
-    #[serde(rename = "xyz.amorgan.blurhash", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "xyz.amorgan.blurhash",
+        alias = "blurhash",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub blurhash: Option<String>,
 }
 
@@ -77,3 +81,34 @@ impl ImageInfo {
         Self::default()
     }
 }
+
+#[cfg(all(test, feature = "unstable-msc2448"))]
+mod tests {
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
+
+    use super::RoomAvatarEventContent;
+
+    #[test]
+    fn avatar_blurhash_serde() {
+        let json_data = json!({
+            "url": "mxc://notareal.hs/avatar",
+            "info": {
+                "blurhash": "LEHV6nWB2yk8pyo0adR*.7kCMdnj",
+            },
+        });
+
+        let content = from_json_value::<RoomAvatarEventContent>(json_data).unwrap();
+        let info = content.info.as_ref().unwrap();
+        assert_eq!(info.blurhash.as_deref(), Some("LEHV6nWB2yk8pyo0adR*.7kCMdnj"));
+
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({
+                "url": "mxc://notareal.hs/avatar",
+                "info": {
+                    "xyz.amorgan.blurhash": "LEHV6nWB2yk8pyo0adR*.7kCMdnj",
+                },
+            })
+        );
+    }
+}
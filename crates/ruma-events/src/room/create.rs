@@ -2,7 +2,12 @@
 //!
 //! [`m.room.create`]: https://spec.matrix.org/latest/client-server-api/#mroomcreate
 
-use ruma_common::{room::RoomType, OwnedEventId, OwnedRoomId, OwnedUserId, RoomVersionId};
+use std::collections::BTreeSet;
+
+use ruma_common::{
+    room::RoomType, room_version_rules::RedactionRules, OwnedEventId, OwnedRoomId, OwnedUserId,
+    RoomId, RoomVersionId,
+};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -86,27 +91,67 @@ impl RedactContent for RoomCreateEventContent {
     type Redacted = RedactedRoomCreateEventContent;
 
     fn redact(self, version: &RoomVersionId) -> Self::Redacted {
-        #[allow(deprecated)]
-        match version {
-            RoomVersionId::V1
-            | RoomVersionId::V2
-            | RoomVersionId::V3
-            | RoomVersionId::V4
-            | RoomVersionId::V5
-            | RoomVersionId::V6
-            | RoomVersionId::V7
-            | RoomVersionId::V8
-            | RoomVersionId::V9
-            | RoomVersionId::V10 => Self {
+        if RedactionRules::for_room_version(version).keep_room_create_content {
+            self
+        } else {
+            #[allow(deprecated)]
+            Self {
                 room_version: default_room_version_id(),
                 creator: self.creator,
                 ..Self::new_v11()
-            },
-            _ => self,
+            }
         }
     }
 }
 
+impl RoomCreateEvent {
+    /// Get the chain of rooms that were upgraded to this room, starting with the direct
+    /// predecessor of this room.
+    ///
+    /// `get_create_content` is called with the ID of each predecessor to get the content of its
+    /// `m.room.create` event. The chain ends with the first room that has no predecessor, or whose
+    /// `m.room.create` event is unknown.
+    ///
+    /// Returns an error if a room appears twice in the chain, which can happen when rooms point
+    /// at each other.
+    pub fn predecessors<F>(
+        &self,
+        mut get_create_content: F,
+    ) -> Result<Vec<PreviousRoom>, PredecessorCycleError>
+    where
+        F: FnMut(&RoomId) -> Option<RoomCreateEventContent>,
+    {
+        let (room_id, content) = match self {
+            Self::Original(ev) => (&ev.room_id, &ev.content),
+            Self::Redacted(ev) => (&ev.room_id, &ev.content),
+        };
+
+        let mut seen_rooms = BTreeSet::from([room_id.clone()]);
+        let mut predecessors = Vec::new();
+        let mut next = content.predecessor.clone();
+
+        while let Some(predecessor) = next {
+            if !seen_rooms.insert(predecessor.room_id.clone()) {
+                return Err(PredecessorCycleError { room_id: predecessor.room_id });
+            }
+
+            next = get_create_content(&predecessor.room_id).and_then(|c| c.predecessor);
+            predecessors.push(predecessor);
+        }
+
+        Ok(predecessors)
+    }
+}
+
+/// An error encountered when the chain of predecessors of a room contains a cycle.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+#[error("room `{room_id}` appears twice in the chain of predecessors")]
+pub struct PredecessorCycleError {
+    /// The first room that was found twice in the chain.
+    pub room_id: OwnedRoomId,
+}
+
 /// A reference to an old room replaced during a room version upgrade.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -146,11 +191,37 @@ impl RedactedStateEventContent for RedactedRoomCreateEventContent {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use assert_matches2::assert_matches;
-    use ruma_common::{owned_user_id, RoomVersionId};
+    use ruma_common::{
+        owned_event_id, owned_room_id, owned_user_id, room_id, MilliSecondsSinceUnixEpoch,
+        OwnedRoomId, RoomVersionId,
+    };
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::{RoomCreateEventContent, RoomType};
+    use super::{PreviousRoom, RoomCreateEvent, RoomCreateEventContent, RoomType};
+    use crate::{EmptyStateKey, OriginalStateEvent, StateUnsigned};
+
+    fn create_content(predecessor: Option<&str>) -> RoomCreateEventContent {
+        let mut content = RoomCreateEventContent::new_v11();
+        content.predecessor = predecessor.map(|room_id| {
+            PreviousRoom::new(room_id.try_into().unwrap(), owned_event_id!("$last:example.org"))
+        });
+        content
+    }
+
+    fn create_event(room_id: OwnedRoomId, content: RoomCreateEventContent) -> RoomCreateEvent {
+        RoomCreateEvent::Original(OriginalStateEvent {
+            content,
+            event_id: owned_event_id!("$create:example.org"),
+            sender: owned_user_id!("@carl:example.org"),
+            origin_server_ts: MilliSecondsSinceUnixEpoch(1_u32.into()),
+            room_id,
+            state_key: EmptyStateKey,
+            unsigned: StateUnsigned::new(),
+        })
+    }
 
     #[test]
     fn serialization() {
@@ -227,4 +298,50 @@ mod tests {
         assert_matches!(content.predecessor, None);
         assert_eq!(content.room_type, Some(RoomType::Space));
     }
+
+    #[test]
+    fn predecessors_chain() {
+        let rooms = BTreeMap::from([
+            (room_id!("!b:example.org"), create_content(Some("!a:example.org"))),
+            (room_id!("!a:example.org"), create_content(None)),
+        ]);
+        let event =
+            create_event(owned_room_id!("!c:example.org"), create_content(Some("!b:example.org")));
+
+        let predecessors = event.predecessors(|room_id| rooms.get(room_id).cloned()).unwrap();
+        let predecessors: Vec<_> = predecessors.iter().map(|p| p.room_id.as_str()).collect();
+        assert_eq!(predecessors, ["!b:example.org", "!a:example.org"]);
+    }
+
+    #[test]
+    fn predecessors_unknown_room() {
+        let event =
+            create_event(owned_room_id!("!c:example.org"), create_content(Some("!b:example.org")));
+
+        let predecessors = event.predecessors(|_| None).unwrap();
+        assert_eq!(predecessors.len(), 1);
+        assert_eq!(predecessors[0].room_id, "!b:example.org");
+
+        let event = create_event(owned_room_id!("!c:example.org"), create_content(None));
+        assert!(event.predecessors(|_| unreachable!()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn predecessors_cycle() {
+        let rooms = BTreeMap::from([
+            (room_id!("!b:example.org"), create_content(Some("!a:example.org"))),
+            (room_id!("!a:example.org"), create_content(Some("!b:example.org"))),
+        ]);
+        let event =
+            create_event(owned_room_id!("!c:example.org"), create_content(Some("!b:example.org")));
+
+        let error = event.predecessors(|room_id| rooms.get(room_id).cloned()).unwrap_err();
+        assert_eq!(error.room_id, "!b:example.org");
+
+        // A room pointing to itself.
+        let event =
+            create_event(owned_room_id!("!c:example.org"), create_content(Some("!c:example.org")));
+        let error = event.predecessors(|_| unreachable!()).unwrap_err();
+        assert_eq!(error.room_id, "!c:example.org");
+    }
 }
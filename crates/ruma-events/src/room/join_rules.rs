@@ -8,6 +8,7 @@ use ruma_common::{serde::from_raw_json_value, space::SpaceRoomJoinRule, OwnedRoo
 use ruma_macros::EventContent;
 use serde::{
     de::{Deserializer, Error},
+    ser::{SerializeMap, Serializer},
     Deserialize, Serialize,
 };
 use serde_json::{value::RawValue as RawJsonValue, Value as JsonValue};
@@ -80,9 +81,8 @@ impl SyncRoomJoinRulesEvent {
 ///
 /// This type can hold an arbitrary string. To check for values that are not available as a
 /// documented variant here, use its string representation, obtained through `.as_str()`.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
-#[serde(tag = "join_rule", rename_all = "snake_case")]
 pub enum JoinRule {
     /// A user who wishes to join the room must first receive an invite to the room from someone
     /// already inside of the room.
@@ -108,7 +108,6 @@ pub enum JoinRule {
     Public,
 
     #[doc(hidden)]
-    #[serde(skip_serializing)]
     _Custom(PrivOwnedStr),
 }
 
@@ -127,6 +126,22 @@ impl JoinRule {
     }
 }
 
+impl Serialize for JoinRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("join_rule", self.as_str())?;
+
+        if let Self::Restricted(restricted) | Self::KnockRestricted(restricted) = self {
+            map.serialize_entry("allow", &restricted.allow)?;
+        }
+
+        map.end()
+    }
+}
+
 impl<'de> Deserialize<'de> for JoinRule {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -254,6 +269,7 @@ impl<'de> Deserialize<'de> for AllowRule {
 mod tests {
     use assert_matches2::assert_matches;
     use ruma_common::owned_room_id;
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{
         AllowRule, JoinRule, OriginalSyncRoomJoinRulesEvent, Restricted, RoomJoinRulesEventContent,
@@ -317,6 +333,58 @@ mod tests {
         assert_matches!(serde_json::from_str::<OriginalSyncRoomJoinRulesEvent>(json), Ok(_));
     }
 
+    #[test]
+    fn serialize_restricted() {
+        let content = RoomJoinRulesEventContent::restricted(vec![AllowRule::room_membership(
+            owned_room_id!("!mods:example.org"),
+        )]);
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({
+                "join_rule": "restricted",
+                "allow": [{ "type": "m.room_membership", "room_id": "!mods:example.org" }],
+            })
+        );
+
+        let content = RoomJoinRulesEventContent::knock_restricted(vec![]);
+        assert_eq!(
+            to_json_value(&content).unwrap(),
+            json!({ "join_rule": "knock_restricted", "allow": [] })
+        );
+
+        let content = RoomJoinRulesEventContent::new(JoinRule::Knock);
+        assert_eq!(to_json_value(&content).unwrap(), json!({ "join_rule": "knock" }));
+    }
+
+    #[test]
+    fn deserialize_knock_restricted_with_custom_allow_rule() {
+        let json = json!({
+            "join_rule": "knock_restricted",
+            "allow": [
+                { "type": "m.room_membership", "room_id": "!users:example.org" },
+                { "type": "org.msc9000.something", "foo": "bar" },
+            ],
+        });
+        let content = from_json_value::<RoomJoinRulesEventContent>(json.clone()).unwrap();
+        assert_matches!(&content.join_rule, JoinRule::KnockRestricted(restricted));
+        assert_eq!(restricted.allow.len(), 2);
+        assert_eq!(
+            restricted.allow[0],
+            AllowRule::room_membership(owned_room_id!("!users:example.org"))
+        );
+        assert_matches!(&restricted.allow[1], AllowRule::_Custom(_));
+
+        assert_eq!(to_json_value(&content).unwrap(), json);
+    }
+
+    #[test]
+    fn roundtrip_custom_join_rule() {
+        let json = json!({ "join_rule": "org.msc9000.something" });
+        let content = from_json_value::<RoomJoinRulesEventContent>(json.clone()).unwrap();
+        assert_eq!(content.join_rule.as_str(), "org.msc9000.something");
+        assert_eq!(to_json_value(&content).unwrap(), json);
+    }
+
     #[test]
     fn roundtrip_custom_allow_rule() {
         let json = r#"{"type":"org.msc9000.something","foo":"bar"}"#;
@@ -5,13 +5,16 @@
 use std::collections::BTreeMap;
 
 use js_int::Int;
+#[cfg(feature = "canonical-json")]
+use ruma_common::CanonicalJsonObject;
 use ruma_common::{
+    room_version_rules::RedactionRules,
     serde::{CanBeEmpty, Raw, StringEnum},
     OwnedMxcUri, OwnedServerName, OwnedServerSigningKeyId, OwnedTransactionId, OwnedUserId,
     RoomVersionId, UserId,
 };
 use ruma_macros::EventContent;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
     AnyStrippedStateEvent, BundledStateRelations, EventContent, PossiblyRedactedStateEventContent,
@@ -89,7 +92,11 @@ pub struct RoomMemberEventContent {
     /// This uses the unstable prefix in
     /// [MSC2448](https://github.com/matrix-org/matrix-spec-proposals/pull/2448).
     #[cfg(feature = "unstable-msc2448")]
-    #[serde(rename = "xyz.amorgan.blurhash", skip_serializing_if = "Option::is_none")]
+    #[serde(
+        rename = "xyz.amorgan.blurhash",
+        alias = "blurhash",
+        skip_serializing_if = "Option::is_none"
+    )]
     pub blurhash: Option<String>,
 
     /// User-supplied text for why their membership has changed.
@@ -167,17 +174,10 @@ impl RedactContent for RoomMemberEventContent {
         RedactedRoomMemberEventContent {
             membership: self.membership,
             third_party_invite: self.third_party_invite.and_then(|i| i.redact(version)),
-            join_authorized_via_users_server: match version {
-                RoomVersionId::V1
-                | RoomVersionId::V2
-                | RoomVersionId::V3
-                | RoomVersionId::V4
-                | RoomVersionId::V5
-                | RoomVersionId::V6
-                | RoomVersionId::V7
-                | RoomVersionId::V8 => None,
-                _ => self.join_authorized_via_users_server,
-            },
+            join_authorized_via_users_server: RedactionRules::for_room_version(version)
+                .keep_room_member_join_authorised_via_users_server
+                .then_some(self.join_authorized_via_users_server)
+                .flatten(),
         }
     }
 }
@@ -317,12 +317,12 @@ pub struct ThirdPartyInvite {
     /// A block of content which has been signed, which servers can use to verify the event.
     ///
     /// Clients should ignore this.
-    pub signed: SignedContent,
+    pub signed: SignedThirdPartyInvite,
 }
 
 impl ThirdPartyInvite {
     /// Creates a new `ThirdPartyInvite` with the given display name and signed content.
-    pub fn new(display_name: String, signed: SignedContent) -> Self {
+    pub fn new(display_name: String, signed: SignedThirdPartyInvite) -> Self {
         Self { display_name, signed }
     }
 
@@ -331,19 +331,9 @@ impl ThirdPartyInvite {
     /// Returns `None` if the field for this object was redacted in the given room version,
     /// otherwise returns the redacted form.
     fn redact(self, version: &RoomVersionId) -> Option<RedactedThirdPartyInvite> {
-        match version {
-            RoomVersionId::V1
-            | RoomVersionId::V2
-            | RoomVersionId::V3
-            | RoomVersionId::V4
-            | RoomVersionId::V5
-            | RoomVersionId::V6
-            | RoomVersionId::V7
-            | RoomVersionId::V8
-            | RoomVersionId::V9
-            | RoomVersionId::V10 => None,
-            _ => Some(RedactedThirdPartyInvite { signed: self.signed }),
-        }
+        RedactionRules::for_room_version(version)
+            .keep_room_member_third_party_invite_signed
+            .then_some(RedactedThirdPartyInvite { signed: self.signed })
     }
 }
 
@@ -354,7 +344,7 @@ pub struct RedactedThirdPartyInvite {
     /// A block of content which has been signed, which servers can use to verify the event.
     ///
     /// Clients should ignore this.
-    pub signed: SignedContent,
+    pub signed: SignedThirdPartyInvite,
 }
 
 /// A block of content which has been signed, which servers can use to verify a third party
@@ -386,6 +376,90 @@ impl SignedContent {
     }
 }
 
+/// The signed content of a third party invite, generated by the identity server.
+///
+/// The JSON is kept as it was received, so the signatures of the identity server can be verified
+/// even if it contains fields that are unknown to Ruma.
+#[derive(Clone, Debug)]
+pub struct SignedThirdPartyInvite {
+    json: Raw<SignedContent>,
+    content: SignedContent,
+}
+
+impl SignedThirdPartyInvite {
+    /// Creates a new `SignedThirdPartyInvite` by serializing the given content.
+    pub fn new(content: SignedContent) -> serde_json::Result<Self> {
+        Ok(Self { json: Raw::new(&content)?, content })
+    }
+
+    /// Creates a new `SignedThirdPartyInvite` from the given JSON.
+    ///
+    /// Returns an error if the JSON doesn't deserialize to a `SignedContent`.
+    pub fn from_json(json: Raw<SignedContent>) -> serde_json::Result<Self> {
+        let content = json.deserialize()?;
+        Ok(Self { json, content })
+    }
+
+    /// The typed fields of the signed content.
+    pub fn content(&self) -> &SignedContent {
+        &self.content
+    }
+
+    /// The invited Matrix user ID.
+    pub fn mxid(&self) -> &UserId {
+        &self.content.mxid
+    }
+
+    /// The token of the third party invite.
+    pub fn token(&self) -> &str {
+        &self.content.token
+    }
+
+    /// The signatures of the identity server.
+    pub fn signatures(
+        &self,
+    ) -> &BTreeMap<OwnedServerName, BTreeMap<OwnedServerSigningKeyId, String>> {
+        &self.content.signatures
+    }
+
+    /// The JSON of the signed content, as it was received.
+    ///
+    /// This is the form that must be used to verify the signatures of the identity server.
+    pub fn json(&self) -> &Raw<SignedContent> {
+        &self.json
+    }
+
+    /// The JSON of the signed content, as it was received, as a canonical JSON object.
+    ///
+    /// This is the form that is needed to verify the signatures of the identity server, for
+    /// example with `ruma_signatures::verify_json()`.
+    ///
+    /// Returns an error if the JSON is not valid canonical JSON.
+    #[cfg(feature = "canonical-json")]
+    pub fn to_canonical_object(&self) -> serde_json::Result<CanonicalJsonObject> {
+        self.json.deserialize_as()
+    }
+}
+
+impl Serialize for SignedThirdPartyInvite {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.json.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignedThirdPartyInvite {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = <Raw<SignedContent> as Deserialize<'de>>::deserialize(deserializer)?;
+        Self::from_json(json).map_err(de::Error::custom)
+    }
+}
+
 impl OriginalRoomMemberEvent {
     /// Obtain the details about this event that are required to calculate a membership change.
     ///
@@ -582,12 +656,12 @@ mod tests {
     use maplit::btreemap;
     use ruma_common::{
         mxc_uri, owned_server_signing_key_id, serde::CanBeEmpty, server_name, user_id,
-        MilliSecondsSinceUnixEpoch,
+        MilliSecondsSinceUnixEpoch, RoomVersionId,
     };
-    use serde_json::{from_value as from_json_value, json};
+    use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
     use super::{MembershipState, RoomMemberEventContent};
-    use crate::OriginalStateEvent;
+    use crate::{OriginalStateEvent, RedactContent};
 
     #[test]
     fn serde_with_no_prev_content() {
@@ -705,16 +779,16 @@ mod tests {
 
         let third_party_invite = ev.content.third_party_invite.unwrap();
         assert_eq!(third_party_invite.display_name, "alice");
-        assert_eq!(third_party_invite.signed.mxid, "@alice:example.org");
+        assert_eq!(third_party_invite.signed.mxid(), "@alice:example.org");
         assert_eq!(
-            third_party_invite.signed.signatures,
+            *third_party_invite.signed.signatures(),
             btreemap! {
                 server_name!("magic.forest").to_owned() => btreemap! {
                     owned_server_signing_key_id!("ed25519:3") => "foobar".to_owned()
                 }
             }
         );
-        assert_eq!(third_party_invite.signed.token, "abc123");
+        assert_eq!(third_party_invite.signed.token(), "abc123");
     }
 
     #[test]
@@ -775,16 +849,16 @@ mod tests {
 
         let third_party_invite = prev_content.third_party_invite.unwrap();
         assert_eq!(third_party_invite.display_name, "alice");
-        assert_eq!(third_party_invite.signed.mxid, "@alice:example.org");
+        assert_eq!(third_party_invite.signed.mxid(), "@alice:example.org");
         assert_eq!(
-            third_party_invite.signed.signatures,
+            *third_party_invite.signed.signatures(),
             btreemap! {
                 server_name!("magic.forest").to_owned() => btreemap! {
                     owned_server_signing_key_id!("ed25519:3") => "foobar".to_owned()
                 }
             }
         );
-        assert_eq!(third_party_invite.signed.token, "abc123");
+        assert_eq!(third_party_invite.signed.token(), "abc123");
     }
 
     #[test]
@@ -820,4 +894,99 @@ mod tests {
             Some(user_id!("@notcarl:example.com"))
         );
     }
+
+    #[test]
+    fn redact_join_authorized_via_users_server() {
+        let content = from_json_value::<RoomMemberEventContent>(json!({
+            "displayname": "Alice",
+            "join_authorised_via_users_server": "@bob:localhost",
+            "membership": "join",
+        }))
+        .unwrap();
+
+        let redacted = content.clone().redact(&RoomVersionId::V8);
+        assert_eq!(redacted.membership, MembershipState::Join);
+        assert_eq!(redacted.join_authorized_via_users_server, None);
+
+        let redacted = content.redact(&RoomVersionId::V9);
+        assert_eq!(redacted.membership, MembershipState::Join);
+        assert_eq!(
+            redacted.join_authorized_via_users_server.as_deref(),
+            Some(user_id!("@bob:localhost"))
+        );
+    }
+
+    #[test]
+    fn redact_third_party_invite() {
+        let content = from_json_value::<RoomMemberEventContent>(json!({
+            "membership": "invite",
+            "third_party_invite": {
+                "display_name": "alice",
+                "signed": {
+                    "mxid": "@alice:example.org",
+                    "signatures": {},
+                    "token": "abc123"
+                }
+            }
+        }))
+        .unwrap();
+
+        let redacted = content.clone().redact(&RoomVersionId::V10);
+        assert_eq!(redacted.membership, MembershipState::Invite);
+        assert_matches!(redacted.third_party_invite, None);
+
+        let redacted = content.redact(&RoomVersionId::V11);
+        assert_matches!(redacted.third_party_invite, Some(third_party_invite));
+        assert_eq!(third_party_invite.signed.mxid(), "@alice:example.org");
+        assert_eq!(third_party_invite.signed.token(), "abc123");
+    }
+
+    #[test]
+    #[cfg(feature = "canonical-json")]
+    fn signed_third_party_invite_to_canonical_object() {
+        use js_int::int;
+        use ruma_common::CanonicalJsonValue;
+
+        let content = from_json_value::<RoomMemberEventContent>(json!({
+            "membership": "invite",
+            "third_party_invite": {
+                "display_name": "alice",
+                "signed": {
+                    "mxid": "@alice:example.org",
+                    "signatures": {
+                        "magic.forest": {
+                            "ed25519:3": "fQpGIW1Snz+pwLZu6sTy2aHy/DYWWTspTJRPyNp0PKkymfIsNffysMl6ObMMFdIJhk6g6pwlIqZ54rxo8SLmAg"
+                        }
+                    },
+                    "token": "abc123",
+                    "org.example.custom": 1
+                }
+            }
+        }))
+        .unwrap();
+
+        let signed = content.third_party_invite.unwrap().signed.to_canonical_object().unwrap();
+        assert_eq!(
+            signed.get("mxid"),
+            Some(&CanonicalJsonValue::String("@alice:example.org".to_owned()))
+        );
+        assert_eq!(signed.get("token"), Some(&CanonicalJsonValue::String("abc123".to_owned())));
+        assert_matches!(signed.get("signatures"), Some(CanonicalJsonValue::Object(signatures)));
+        assert!(signatures.contains_key("magic.forest"));
+        assert_eq!(signed.get("org.example.custom"), Some(&CanonicalJsonValue::Integer(int!(1))));
+    }
+
+    #[test]
+    fn membership_state_serde() {
+        let content =
+            from_json_value::<RoomMemberEventContent>(json!({ "membership": "knock" })).unwrap();
+        assert_eq!(content.membership, MembershipState::Knock);
+        assert_eq!(to_json_value(&content).unwrap(), json!({ "membership": "knock" }));
+
+        let content =
+            from_json_value::<RoomMemberEventContent>(json!({ "membership": "io.ruma.lurk" }))
+                .unwrap();
+        assert_eq!(content.membership.as_str(), "io.ruma.lurk");
+        assert_eq!(to_json_value(&content).unwrap(), json!({ "membership": "io.ruma.lurk" }));
+    }
 }
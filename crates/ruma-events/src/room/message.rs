@@ -6,7 +6,7 @@ use std::borrow::Cow;
 
 use ruma_common::{
     serde::{JsonObject, Raw, StringEnum},
-    OwnedEventId, RoomId,
+    EventId, OwnedEventId, RoomId,
 };
 #[cfg(feature = "html")]
 use ruma_html::{sanitize_html, HtmlSanitizerMode, RemoveReplyFallback};
@@ -50,7 +50,7 @@ pub use self::{
     file::{FileInfo, FileMessageEventContent},
     image::ImageMessageEventContent,
     key_verification_request::KeyVerificationRequestEventContent,
-    location::{LocationInfo, LocationMessageEventContent},
+    location::{GeoUri, GeoUriError, LocationInfo, LocationMessageEventContent},
     notice::NoticeMessageEventContent,
     relation::{Relation, RelationWithoutReplacement},
     relation_serde::deserialize_relation,
@@ -230,7 +230,8 @@ impl RoomMessageEventContent {
     ///
     /// The first argument after `self` can be `&OriginalRoomMessageEvent` or
     /// `&OriginalSyncRoomMessageEvent` if you don't want to create `ReplacementMetadata` separately
-    /// before calling this function.
+    /// before calling this function. If the given message is itself a replacement, the new
+    /// replacement relates to the original event instead.
     ///
     /// This takes the content and sets it in `m.new_content`, and modifies the `content` to include
     /// a fallback.
@@ -765,17 +766,31 @@ impl ReplacementMetadata {
     pub fn new(event_id: OwnedEventId, mentions: Option<Mentions>) -> Self {
         Self { event_id, mentions }
     }
+
+    /// Creates a new `ReplacementMetadata` from the event ID and content of a message.
+    ///
+    /// If the message is itself a replacement, the metadata points to the original event and uses
+    /// the mentions of the replacement content, because an edit must always relate to the original
+    /// event.
+    fn from_message(event_id: &EventId, content: &RoomMessageEventContent) -> Self {
+        match &content.relates_to {
+            Some(Relation::Replacement(replacement)) => {
+                Self::new(replacement.event_id.clone(), replacement.new_content.mentions.clone())
+            }
+            _ => Self::new(event_id.to_owned(), content.mentions.clone()),
+        }
+    }
 }
 
 impl From<&OriginalRoomMessageEvent> for ReplacementMetadata {
     fn from(value: &OriginalRoomMessageEvent) -> Self {
-        ReplacementMetadata::new(value.event_id.to_owned(), value.content.mentions.clone())
+        ReplacementMetadata::from_message(&value.event_id, &value.content)
     }
 }
 
 impl From<&OriginalSyncRoomMessageEvent> for ReplacementMetadata {
     fn from(value: &OriginalSyncRoomMessageEvent) -> Self {
-        ReplacementMetadata::new(value.event_id.to_owned(), value.content.mentions.clone())
+        ReplacementMetadata::from_message(&value.event_id, &value.content)
     }
 }
 
@@ -813,6 +828,10 @@ impl FormattedBody {
 
     /// Creates a new HTML-formatted message body by parsing the Markdown in `body`.
     ///
+    /// Raw HTML in the Markdown is escaped, unless it is a tag allowed by the spec without
+    /// attributes. Links and images with URLs that are not allowed by the spec are replaced by
+    /// their text.
+    ///
     /// Returns `None` if no Markdown formatting was found.
     #[cfg(feature = "markdown")]
     pub fn markdown(body: impl AsRef<str>) -> Option<Self> {
@@ -858,17 +877,43 @@ pub struct CustomEventContent {
 
 #[cfg(feature = "markdown")]
 pub(crate) fn parse_markdown(text: &str) -> Option<String> {
-    use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd};
+    use pulldown_cmark::{CowStr, Event, LinkType, Options, Parser, Tag, TagEnd};
 
     const OPTIONS: Options = Options::ENABLE_TABLES.union(Options::ENABLE_STRIKETHROUGH);
 
     let mut found_first_paragraph = false;
     let mut previous_event_was_text = false;
+    let mut is_in_disallowed_link = false;
+    let mut is_in_disallowed_image = false;
 
     let parser_events: Vec<_> = Parser::new_ext(text, OPTIONS)
-        .map(|event| match event {
-            Event::SoftBreak => Event::HardBreak,
-            _ => event,
+        .filter_map(|event| match event {
+            Event::SoftBreak => Some(Event::HardBreak),
+            // Raw HTML that is not allowed by the spec is escaped.
+            Event::Html(html) | Event::InlineHtml(html) if !is_allowed_raw_html(&html) => {
+                Some(Event::Text(html))
+            }
+            // Links and images with disallowed URLs are replaced by their text. Email autolinks
+            // don't have a scheme, `mailto:` is added when they are rendered.
+            Event::Start(Tag::Link { link_type, ref dest_url, .. })
+                if link_type != LinkType::Email && !is_allowed_link_url(dest_url) =>
+            {
+                is_in_disallowed_link = true;
+                None
+            }
+            Event::End(TagEnd::Link) if is_in_disallowed_link => {
+                is_in_disallowed_link = false;
+                None
+            }
+            Event::Start(Tag::Image { ref dest_url, .. }) if !dest_url.starts_with("mxc://") => {
+                is_in_disallowed_image = true;
+                None
+            }
+            Event::End(TagEnd::Image) if is_in_disallowed_image => {
+                is_in_disallowed_image = false;
+                None
+            }
+            _ => Some(event),
         })
         .collect();
     let has_markdown = parser_events.iter().any(|ref event| {
@@ -920,11 +965,83 @@ pub(crate) fn parse_markdown(text: &str) -> Option<String> {
     Some(html_body)
 }
 
+/// Whether the given raw HTML found in markdown can be kept as-is.
+///
+/// Only single opening or closing tags of the elements allowed by the spec, without attributes,
+/// are kept.
+#[cfg(feature = "markdown")]
+fn is_allowed_raw_html(html: &str) -> bool {
+    const ALLOWED_ELEMENTS: &[&str] = &[
+        "a",
+        "b",
+        "blockquote",
+        "br",
+        "caption",
+        "code",
+        "del",
+        "details",
+        "div",
+        "em",
+        "font",
+        "h1",
+        "h2",
+        "h3",
+        "h4",
+        "h5",
+        "h6",
+        "hr",
+        "i",
+        "li",
+        "ol",
+        "p",
+        "pre",
+        "s",
+        "span",
+        "strong",
+        "sub",
+        "summary",
+        "sup",
+        "table",
+        "tbody",
+        "td",
+        "th",
+        "thead",
+        "tr",
+        "u",
+        "ul",
+    ];
+
+    let Some(tag) = html.trim_end().strip_prefix('<').and_then(|tag| tag.strip_suffix('>')) else {
+        return false;
+    };
+    let name = tag.strip_prefix('/').unwrap_or(tag);
+    let name = name.strip_suffix('/').unwrap_or(name).trim_end();
+
+    ALLOWED_ELEMENTS.iter().any(|element| element.eq_ignore_ascii_case(name))
+}
+
+/// Whether the given URL can be used as the destination of a link.
+///
+/// Relative URLs are allowed, URLs with a scheme are only allowed if the scheme is allowed by the
+/// spec.
+#[cfg(feature = "markdown")]
+fn is_allowed_link_url(url: &str) -> bool {
+    const ALLOWED_SCHEMES: &[&str] = &["https", "http", "ftp", "mailto", "magnet"];
+
+    // A colon before any path, query or fragment delimiter means that the URL has a scheme.
+    match url.find([':', '/', '?', '#']).map(|idx| url.split_at(idx)) {
+        Some((scheme, rest)) if rest.starts_with(':') => {
+            ALLOWED_SCHEMES.iter().any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+        }
+        _ => true,
+    }
+}
+
 #[cfg(all(test, feature = "markdown"))]
 mod tests {
     use assert_matches2::assert_matches;
 
-    use super::parse_markdown;
+    use super::{is_allowed_link_url, is_allowed_raw_html, parse_markdown};
 
     #[test]
     fn detect_markdown() {
@@ -956,4 +1073,34 @@ mod tests {
         let text = "Hello w&#8853;rld.";
         assert_matches!(parse_markdown(text), Some(_));
     }
+
+    #[test]
+    fn allowed_raw_html() {
+        assert!(is_allowed_raw_html("<span>"));
+        assert!(is_allowed_raw_html("</SPAN>"));
+        assert!(is_allowed_raw_html("<br/>"));
+        assert!(is_allowed_raw_html("<br />\n"));
+
+        assert!(!is_allowed_raw_html("<script>"));
+        assert!(!is_allowed_raw_html("</iframe>"));
+        assert!(!is_allowed_raw_html(r#"<span onclick="alert(1)">"#));
+        assert!(!is_allowed_raw_html("<!-- comment -->"));
+        assert!(!is_allowed_raw_html("<p>paragraph</p>"));
+    }
+
+    #[test]
+    fn allowed_link_url() {
+        assert!(is_allowed_link_url("https://ruma.dev"));
+        assert!(is_allowed_link_url("MAILTO:alice@example.org"));
+
+        assert!(is_allowed_link_url("/relative/path"));
+        assert!(is_allowed_link_url("relative/path:with-colon"));
+        assert!(is_allowed_link_url("#anchor"));
+        assert!(is_allowed_link_url("?query=a:b"));
+
+        assert!(!is_allowed_link_url("javascript:alert(1)"));
+        assert!(!is_allowed_link_url("JavaScript:alert(1)"));
+        assert!(!is_allowed_link_url("data:text/html,<script>alert(1)</script>"));
+        assert!(!is_allowed_link_url("java\tscript:alert(1)"));
+    }
 }
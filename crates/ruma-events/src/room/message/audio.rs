@@ -88,6 +88,14 @@ impl AudioMessageEventContent {
     pub fn info(self, info: impl Into<Option<Box<AudioInfo>>>) -> Self {
         Self { info: info.into(), ..self }
     }
+
+    /// Whether this audio message is a voice message.
+    ///
+    /// This is the case if the `org.matrix.msc3245.voice` field is present.
+    #[cfg(feature = "unstable-msc3245-v1-compat")]
+    pub fn is_voice_message(&self) -> bool {
+        self.voice.is_some()
+    }
 }
 
 /// Metadata about an audio clip.
@@ -191,6 +199,28 @@ impl UnstableAmplitude {
     pub fn get(&self) -> UInt {
         self.0
     }
+
+    /// Normalizes the given amplitude samples into a waveform.
+    ///
+    /// The samples can use any scale. They are converted to absolute values, and scaled so that
+    /// the loudest sample has the value [`UnstableAmplitude::MAX`]. Samples that are not finite
+    /// are treated as silence.
+    pub fn normalize_waveform(samples: impl IntoIterator<Item = f32>) -> Vec<Self> {
+        let samples: Vec<f32> = samples
+            .into_iter()
+            .map(|sample| if sample.is_finite() { sample.abs() } else { 0.0 })
+            .collect();
+        let max = samples.iter().copied().fold(0.0, f32::max);
+
+        samples
+            .into_iter()
+            .map(|sample| {
+                let value = if max > 0.0 { sample / max * f32::from(Self::MAX) } else { 0.0 };
+                // The value is between 0 and `MAX`, so the cast can't truncate.
+                Self::new(value.round() as u16)
+            })
+            .collect()
+    }
 }
 
 #[cfg(feature = "unstable-msc3245-v1-compat")]
@@ -210,3 +240,29 @@ impl<'de> Deserialize<'de> for UnstableAmplitude {
         Ok(Self(uint.min(Self::MAX.into())))
     }
 }
+
+#[cfg(all(test, feature = "unstable-msc3245-v1-compat"))]
+mod tests {
+    use js_int::uint;
+
+    use super::UnstableAmplitude;
+
+    #[test]
+    fn normalize_waveform() {
+        let waveform = UnstableAmplitude::normalize_waveform([0.0, -0.25, 0.5, f32::NAN, 1.0]);
+        let values: Vec<_> = waveform.iter().map(UnstableAmplitude::get).collect();
+        assert_eq!(values, [uint!(0), uint!(256), uint!(512), uint!(0), uint!(1024)]);
+
+        let waveform = UnstableAmplitude::normalize_waveform([4000.0, 2000.0]);
+        let values: Vec<_> = waveform.iter().map(UnstableAmplitude::get).collect();
+        assert_eq!(values, [uint!(1024), uint!(512)]);
+    }
+
+    #[test]
+    fn normalize_silent_waveform() {
+        let waveform = UnstableAmplitude::normalize_waveform([0.0, 0.0]);
+        assert_eq!(waveform, [UnstableAmplitude::new(0), UnstableAmplitude::new(0)]);
+
+        assert!(UnstableAmplitude::normalize_waveform([]).is_empty());
+    }
+}
@@ -1,12 +1,15 @@
 //! `Deserialize` implementation for RoomMessageEventContent and MessageType.
 
+use std::fmt;
+
 use ruma_common::serde::from_raw_json_value;
 use serde::{de, Deserialize};
 use serde_json::value::RawValue as RawJsonValue;
 
 use super::{
-    relation_serde::deserialize_relation, MessageType, RoomMessageEventContent,
-    RoomMessageEventContentWithoutRelation,
+    relation_serde::{EventWithRelatesToDeHelper, RelatesToDeHelper},
+    EmoteMessageEventContent, FormattedBody, MessageType, NoticeMessageEventContent,
+    RoomMessageEventContent, RoomMessageEventContentWithoutRelation, TextMessageEventContent,
 };
 use crate::Mentions;
 
@@ -17,12 +20,11 @@ impl<'de> Deserialize<'de> for RoomMessageEventContent {
     {
         let json = Box::<RawJsonValue>::deserialize(deserializer)?;
 
-        let mut deserializer = serde_json::Deserializer::from_str(json.get());
-        let relates_to = deserialize_relation(&mut deserializer).map_err(de::Error::custom)?;
-
-        let MentionsDeHelper { mentions } = from_raw_json_value(&json)?;
+        let RoomMessageEventContentDeHelper { msgtype, mentions, relates_to, new_content } =
+            from_raw_json_value(&json)?;
+        let relates_to = EventWithRelatesToDeHelper { relates_to, new_content }.into_relation()?;
 
-        Ok(Self { msgtype: from_raw_json_value(&json)?, relates_to, mentions })
+        Ok(Self { msgtype: deserialize_message_type(&msgtype, &json)?, relates_to, mentions })
     }
 }
 
@@ -33,14 +35,35 @@ impl<'de> Deserialize<'de> for RoomMessageEventContentWithoutRelation {
     {
         let json = Box::<RawJsonValue>::deserialize(deserializer)?;
 
-        let MentionsDeHelper { mentions } = from_raw_json_value(&json)?;
+        let RoomMessageEventContentWithoutRelationDeHelper { msgtype, mentions } =
+            from_raw_json_value(&json)?;
 
-        Ok(Self { msgtype: from_raw_json_value(&json)?, mentions })
+        Ok(Self { msgtype: deserialize_message_type(&msgtype, &json)?, mentions })
     }
 }
 
+/// Helper struct to get all the fields of a `RoomMessageEventContent` that are not part of its
+/// `MessageType` in a single pass.
+#[derive(Deserialize)]
+struct RoomMessageEventContentDeHelper {
+    msgtype: String,
+
+    #[serde(rename = "m.mentions")]
+    mentions: Option<Mentions>,
+
+    #[serde(rename = "m.relates_to")]
+    relates_to: Option<RelatesToDeHelper>,
+
+    #[serde(rename = "m.new_content")]
+    new_content: Option<RoomMessageEventContentWithoutRelation>,
+}
+
+/// Helper struct to get the fields of a `RoomMessageEventContentWithoutRelation` that are not part
+/// of its `MessageType` in a single pass.
 #[derive(Deserialize)]
-struct MentionsDeHelper {
+struct RoomMessageEventContentWithoutRelationDeHelper {
+    msgtype: String,
+
     #[serde(rename = "m.mentions")]
     mentions: Option<Mentions>,
 }
@@ -60,22 +83,135 @@ impl<'de> Deserialize<'de> for MessageType {
         let json = Box::<RawJsonValue>::deserialize(deserializer)?;
         let MessageTypeDeHelper { msgtype } = from_raw_json_value(&json)?;
 
-        Ok(match msgtype.as_ref() {
-            "m.audio" => Self::Audio(from_raw_json_value(&json)?),
-            "m.emote" => Self::Emote(from_raw_json_value(&json)?),
-            "m.file" => Self::File(from_raw_json_value(&json)?),
-            "m.image" => Self::Image(from_raw_json_value(&json)?),
-            "m.location" => Self::Location(from_raw_json_value(&json)?),
-            "m.notice" => Self::Notice(from_raw_json_value(&json)?),
-            "m.server_notice" => Self::ServerNotice(from_raw_json_value(&json)?),
-            "m.text" => Self::Text(from_raw_json_value(&json)?),
-            "m.video" => Self::Video(from_raw_json_value(&json)?),
-            "m.key.verification.request" => Self::VerificationRequest(from_raw_json_value(&json)?),
-            _ => Self::_Custom(from_raw_json_value(&json)?),
-        })
+        deserialize_message_type(&msgtype, &json)
+    }
+}
+
+/// Deserialize the `MessageType` with the given `msgtype` from its JSON representation.
+fn deserialize_message_type<E: de::Error>(
+    msgtype: &str,
+    json: &RawJsonValue,
+) -> Result<MessageType, E> {
+    Ok(match msgtype {
+        "m.audio" => MessageType::Audio(from_raw_json_value(json)?),
+        "m.emote" => MessageType::Emote(from_raw_json_value(json)?),
+        "m.file" => MessageType::File(from_raw_json_value(json)?),
+        "m.image" => MessageType::Image(from_raw_json_value(json)?),
+        "m.location" => MessageType::Location(from_raw_json_value(json)?),
+        "m.notice" => MessageType::Notice(from_raw_json_value(json)?),
+        "m.server_notice" => MessageType::ServerNotice(from_raw_json_value(json)?),
+        "m.text" => MessageType::Text(from_raw_json_value(json)?),
+        "m.video" => MessageType::Video(from_raw_json_value(json)?),
+        "m.key.verification.request" => {
+            MessageType::VerificationRequest(from_raw_json_value(json)?)
+        }
+        _ => MessageType::_Custom(from_raw_json_value(json)?),
+    })
+}
+
+/// Helper struct to deserialize the message types with a plain-text and an optional formatted
+/// body in a single pass, without buffering the content like `#[serde(flatten)]` would.
+#[derive(Deserialize)]
+struct FormattedMessageDeHelper {
+    body: String,
+
+    // Invalid values are ignored, like with an `Option<FormattedBody>` with `#[serde(flatten)]`.
+    #[serde(default, deserialize_with = "deserialize_string_or_ignore")]
+    format: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_string_or_ignore")]
+    formatted_body: Option<String>,
+}
+
+impl FormattedMessageDeHelper {
+    fn into_parts(self) -> (String, Option<FormattedBody>) {
+        let formatted = match (self.format, self.formatted_body) {
+            (Some(format), Some(body)) => Some(FormattedBody { format: format.into(), body }),
+            _ => None,
+        };
+
+        (self.body, formatted)
+    }
+}
+
+/// Deserialize a string, or `None` if the value is of any other type.
+fn deserialize_string_or_ignore<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    struct StringOrIgnoreVisitor;
+
+    impl<'de> de::Visitor<'de> for StringOrIgnoreVisitor {
+        type Value = Option<String>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("any value")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(Some(v.to_owned()))
+        }
+
+        fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+            Ok(Some(v))
+        }
+
+        fn visit_bool<E: de::Error>(self, _v: bool) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_i64<E: de::Error>(self, _v: i64) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_u64<E: de::Error>(self, _v: u64) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_f64<E: de::Error>(self, _v: f64) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+            Ok(None)
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            while map.next_entry::<de::IgnoredAny, de::IgnoredAny>()?.is_some() {}
+            Ok(None)
+        }
     }
+
+    deserializer.deserialize_any(StringOrIgnoreVisitor)
+}
+
+macro_rules! impl_formatted_message_deserialize {
+    ($($ty:ident),*) => {
+        $(
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: de::Deserializer<'de>,
+                {
+                    let (body, formatted) =
+                        FormattedMessageDeHelper::deserialize(deserializer)?.into_parts();
+                    Ok(Self { body, formatted })
+                }
+            }
+        )*
+    };
 }
 
+impl_formatted_message_deserialize!(
+    EmoteMessageEventContent,
+    NoticeMessageEventContent,
+    TextMessageEventContent
+);
+
 #[allow(unreachable_pub)] // https://github.com/rust-lang/rust/issues/112615
 #[cfg(feature = "unstable-msc3488")]
 pub(in super::super) mod msc3488 {
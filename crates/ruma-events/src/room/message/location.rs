@@ -1,3 +1,5 @@
+use std::{fmt, str::FromStr};
+
 #[cfg(feature = "unstable-msc3488")]
 use ruma_common::MilliSecondsSinceUnixEpoch;
 use serde::{Deserialize, Serialize};
@@ -95,6 +97,14 @@ impl LocationMessageEventContent {
         &self.geo_uri
     }
 
+    /// Parse the `geo:` URI of this `LocationMessageEventContent`.
+    ///
+    /// The URI is not validated during deserialization, so this returns an error if it is
+    /// malformed.
+    pub fn parsed_geo_uri(&self) -> Result<GeoUri, GeoUriError> {
+        GeoUri::parse(self.geo_uri())
+    }
+
     /// Get the plain text representation of this `LocationMessageEventContent`.
     pub fn plain_text_representation(&self) -> &str {
         #[cfg(feature = "unstable-msc3488")]
@@ -135,3 +145,241 @@ impl LocationInfo {
         Self::default()
     }
 }
+
+/// A parsed `geo:` URI, as defined in [RFC 5870].
+///
+/// Only the WGS-84 coordinate reference system is supported. Parameters other than `crs` and `u`
+/// are ignored when parsing.
+///
+/// ```
+/// # use ruma_events::room::message::GeoUri;
+/// let uri = GeoUri::parse("geo:51.5008,0.1247;u=35").unwrap();
+/// assert_eq!(uri.latitude(), 51.5008);
+/// assert_eq!(uri.longitude(), 0.1247);
+/// assert_eq!(uri.uncertainty(), Some(35.0));
+/// assert_eq!(uri.to_string(), "geo:51.5008,0.1247;u=35");
+/// ```
+///
+/// [RFC 5870]: https://datatracker.ietf.org/doc/html/rfc5870
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoUri {
+    latitude: f64,
+    longitude: f64,
+    altitude: Option<f64>,
+    uncertainty: Option<f64>,
+}
+
+impl GeoUri {
+    /// Creates a new `GeoUri` with the given latitude and longitude, in decimal degrees.
+    ///
+    /// Returns an error if the latitude is not between -90 and 90, or if the longitude is not
+    /// between -180 and 180.
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, GeoUriError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(GeoUriError::LatitudeOutOfRange);
+        }
+
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(GeoUriError::LongitudeOutOfRange);
+        }
+
+        Ok(Self { latitude, longitude, altitude: None, uncertainty: None })
+    }
+
+    /// Parses a `geo:` URI.
+    pub fn parse(uri: &str) -> Result<Self, GeoUriError> {
+        let rest = uri
+            .get(..4)
+            .filter(|scheme| scheme.eq_ignore_ascii_case("geo:"))
+            .map(|_| &uri[4..])
+            .ok_or(GeoUriError::InvalidScheme)?;
+
+        let mut parts = rest.split(';');
+        let coordinates = parts.next().unwrap_or_default();
+
+        let mut coordinates = coordinates.split(',').map(parse_decimal);
+        let (Some(latitude), Some(longitude), altitude, None) =
+            (coordinates.next(), coordinates.next(), coordinates.next(), coordinates.next())
+        else {
+            return Err(GeoUriError::InvalidCoordinates);
+        };
+
+        let mut geo_uri = Self::new(
+            latitude.ok_or(GeoUriError::InvalidCoordinates)?,
+            longitude.ok_or(GeoUriError::InvalidCoordinates)?,
+        )?;
+        geo_uri.altitude =
+            altitude.map(|a| a.ok_or(GeoUriError::InvalidCoordinates)).transpose()?;
+
+        for param in parts {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+
+            if name.eq_ignore_ascii_case("crs") {
+                if !value.eq_ignore_ascii_case("wgs84") {
+                    return Err(GeoUriError::UnsupportedCrs);
+                }
+            } else if name.eq_ignore_ascii_case("u") {
+                geo_uri = geo_uri.with_uncertainty(
+                    parse_decimal(value).ok_or(GeoUriError::InvalidUncertainty)?,
+                )?;
+            }
+        }
+
+        Ok(geo_uri)
+    }
+
+    /// The latitude of this location, in decimal degrees.
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// The longitude of this location, in decimal degrees.
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// The altitude of this location, in meters.
+    pub fn altitude(&self) -> Option<f64> {
+        self.altitude
+    }
+
+    /// The uncertainty of this location, in meters.
+    pub fn uncertainty(&self) -> Option<f64> {
+        self.uncertainty
+    }
+
+    /// Set the altitude of this location, in meters.
+    ///
+    /// Returns an error if the altitude is not a finite number.
+    pub fn with_altitude(mut self, altitude: f64) -> Result<Self, GeoUriError> {
+        if !altitude.is_finite() {
+            return Err(GeoUriError::InvalidCoordinates);
+        }
+
+        self.altitude = Some(altitude);
+        Ok(self)
+    }
+
+    /// Set the uncertainty of this location, in meters.
+    ///
+    /// Returns an error if the uncertainty is not a finite, positive number.
+    pub fn with_uncertainty(mut self, uncertainty: f64) -> Result<Self, GeoUriError> {
+        if !uncertainty.is_finite() || uncertainty < 0.0 {
+            return Err(GeoUriError::InvalidUncertainty);
+        }
+
+        self.uncertainty = Some(uncertainty);
+        Ok(self)
+    }
+}
+
+/// Parses a decimal number of the form `[-]digits[.digits]`, as allowed in a `geo:` URI.
+fn parse_decimal(s: &str) -> Option<f64> {
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    let (integer, fraction) = digits.split_once('.').unwrap_or((digits, "0"));
+
+    let is_valid = !integer.is_empty()
+        && !fraction.is_empty()
+        && integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit());
+
+    is_valid.then(|| s.parse().ok()).flatten()
+}
+
+impl fmt::Display for GeoUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "geo:{},{}", self.latitude, self.longitude)?;
+
+        if let Some(altitude) = self.altitude {
+            write!(f, ",{altitude}")?;
+        }
+
+        if let Some(uncertainty) = self.uncertainty {
+            write!(f, ";u={uncertainty}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for GeoUri {
+    type Err = GeoUriError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// An error encountered when trying to parse a [`GeoUri`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum GeoUriError {
+    /// The URI does not use the `geo` scheme.
+    #[error("URI does not use the `geo` scheme")]
+    InvalidScheme,
+
+    /// The coordinates are not two or three decimal numbers separated by commas.
+    #[error("coordinates must be two or three decimal numbers separated by commas")]
+    InvalidCoordinates,
+
+    /// The latitude is not between -90 and 90.
+    #[error("latitude must be between -90 and 90")]
+    LatitudeOutOfRange,
+
+    /// The longitude is not between -180 and 180.
+    #[error("longitude must be between -180 and 180")]
+    LongitudeOutOfRange,
+
+    /// The uncertainty is not a positive decimal number.
+    #[error("uncertainty must be a positive decimal number")]
+    InvalidUncertainty,
+
+    /// The coordinate reference system is not WGS-84.
+    #[error("only the WGS-84 coordinate reference system is supported")]
+    UnsupportedCrs,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GeoUri, GeoUriError};
+
+    #[test]
+    fn parse_geo_uri() {
+        let uri = GeoUri::parse("geo:51.5008,0.1247").unwrap();
+        assert_eq!(uri.latitude(), 51.5008);
+        assert_eq!(uri.longitude(), 0.1247);
+        assert_eq!(uri.altitude(), None);
+        assert_eq!(uri.uncertainty(), None);
+
+        let uri = GeoUri::parse("GEO:-33.8688,151.2093,58;crs=WGS84;u=12.5;foo=bar").unwrap();
+        assert_eq!(uri.latitude(), -33.8688);
+        assert_eq!(uri.longitude(), 151.2093);
+        assert_eq!(uri.altitude(), Some(58.0));
+        assert_eq!(uri.uncertainty(), Some(12.5));
+    }
+
+    #[test]
+    fn parse_invalid_geo_uri() {
+        assert_eq!(GeoUri::parse("").unwrap_err(), GeoUriError::InvalidScheme);
+        assert_eq!(GeoUri::parse("https://51.5,0.1").unwrap_err(), GeoUriError::InvalidScheme);
+        assert_eq!(GeoUri::parse("geo:51.5").unwrap_err(), GeoUriError::InvalidCoordinates);
+        assert_eq!(GeoUri::parse("geo:1,2,3,4").unwrap_err(), GeoUriError::InvalidCoordinates);
+        assert_eq!(GeoUri::parse("geo:1e2,0").unwrap_err(), GeoUriError::InvalidCoordinates);
+        assert_eq!(GeoUri::parse("geo:NaN,0").unwrap_err(), GeoUriError::InvalidCoordinates);
+        assert_eq!(GeoUri::parse("geo:.5,0").unwrap_err(), GeoUriError::InvalidCoordinates);
+        assert_eq!(GeoUri::parse("geo:90.1,0").unwrap_err(), GeoUriError::LatitudeOutOfRange);
+        assert_eq!(GeoUri::parse("geo:0,-181").unwrap_err(), GeoUriError::LongitudeOutOfRange);
+        assert_eq!(GeoUri::parse("geo:0,0;u=-1").unwrap_err(), GeoUriError::InvalidUncertainty);
+        assert_eq!(GeoUri::parse("geo:0,0;u=").unwrap_err(), GeoUriError::InvalidUncertainty);
+        assert_eq!(GeoUri::parse("geo:0,0;crs=moon").unwrap_err(), GeoUriError::UnsupportedCrs);
+    }
+
+    #[test]
+    fn display_geo_uri() {
+        let uri = GeoUri::new(51.5008, 0.1247).unwrap();
+        assert_eq!(uri.to_string(), "geo:51.5008,0.1247");
+
+        let uri = uri.with_altitude(-12.0).unwrap().with_uncertainty(35.0).unwrap();
+        assert_eq!(uri.to_string(), "geo:51.5008,0.1247,-12;u=35");
+        assert_eq!(GeoUri::parse(&uri.to_string()).unwrap(), uri);
+    }
+}
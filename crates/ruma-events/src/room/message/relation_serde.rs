@@ -27,40 +27,7 @@ where
     D: Deserializer<'de>,
     C: Deserialize<'de>,
 {
-    let EventWithRelatesToDeHelper { relates_to, new_content } =
-        EventWithRelatesToDeHelper::deserialize(deserializer)?;
-    let Some(relates_to) = relates_to else {
-        return Ok(None);
-    };
-
-    let RelatesToDeHelper { in_reply_to, relation } = relates_to;
-
-    let rel = match relation {
-        RelationDeHelper::Known(relation) => match relation {
-            KnownRelationDeHelper::Replacement(ReplacementJsonRepr { event_id }) => {
-                match new_content {
-                    Some(new_content) => {
-                        Relation::Replacement(Replacement { event_id, new_content })
-                    }
-                    None => return Err(de::Error::missing_field("m.new_content")),
-                }
-            }
-            KnownRelationDeHelper::Thread(ThreadDeHelper { event_id, is_falling_back })
-            | KnownRelationDeHelper::ThreadUnstable(ThreadUnstableDeHelper {
-                event_id,
-                is_falling_back,
-            }) => Relation::Thread(Thread { event_id, in_reply_to, is_falling_back }),
-        },
-        RelationDeHelper::Unknown(c) => {
-            if let Some(in_reply_to) = in_reply_to {
-                Relation::Reply { in_reply_to }
-            } else {
-                Relation::_Custom(c)
-            }
-        }
-    };
-
-    Ok(Some(rel))
+    EventWithRelatesToDeHelper::deserialize(deserializer)?.into_relation()
 }
 
 impl<C> Serialize for Relation<C>
@@ -80,10 +47,48 @@ where
 #[derive(Deserialize)]
 pub(crate) struct EventWithRelatesToDeHelper<C> {
     #[serde(rename = "m.relates_to")]
-    relates_to: Option<RelatesToDeHelper>,
+    pub(super) relates_to: Option<RelatesToDeHelper>,
 
     #[serde(rename = "m.new_content")]
-    new_content: Option<C>,
+    pub(super) new_content: Option<C>,
+}
+
+impl<C> EventWithRelatesToDeHelper<C> {
+    pub(super) fn into_relation<E: de::Error>(self) -> Result<Option<Relation<C>>, E> {
+        let Self { relates_to, new_content } = self;
+        let Some(relates_to) = relates_to else {
+            return Ok(None);
+        };
+
+        let RelatesToDeHelper { in_reply_to, relation } = relates_to;
+
+        let rel = match relation {
+            RelationDeHelper::Known(relation) => match relation {
+                KnownRelationDeHelper::Replacement(ReplacementJsonRepr { event_id }) => {
+                    match new_content {
+                        Some(new_content) => {
+                            Relation::Replacement(Replacement { event_id, new_content })
+                        }
+                        None => return Err(de::Error::missing_field("m.new_content")),
+                    }
+                }
+                KnownRelationDeHelper::Thread(ThreadDeHelper { event_id, is_falling_back })
+                | KnownRelationDeHelper::ThreadUnstable(ThreadUnstableDeHelper {
+                    event_id,
+                    is_falling_back,
+                }) => Relation::Thread(Thread { event_id, in_reply_to, is_falling_back }),
+            },
+            RelationDeHelper::Unknown(c) => {
+                if let Some(in_reply_to) = in_reply_to {
+                    Relation::Reply { in_reply_to }
+                } else {
+                    Relation::_Custom(c)
+                }
+            }
+        };
+
+        Ok(Some(rel))
+    }
 }
 
 #[derive(Deserialize)]
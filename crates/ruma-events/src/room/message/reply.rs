@@ -144,10 +144,16 @@ pub(super) fn plain_and_formatted_reply_body(
 
 #[cfg(test)]
 mod tests {
-    use ruma_common::{owned_event_id, owned_room_id, owned_user_id, MilliSecondsSinceUnixEpoch};
+    use ruma_common::{
+        owned_event_id, owned_mxc_uri, owned_room_id, owned_user_id, MilliSecondsSinceUnixEpoch,
+    };
 
     use super::OriginalRoomMessageEvent;
-    use crate::{room::message::RoomMessageEventContent, MessageLikeUnsigned};
+    use crate::{
+        relation::InReplyTo,
+        room::message::{ImageMessageEventContent, MessageType, Relation, RoomMessageEventContent},
+        MessageLikeUnsigned,
+    };
 
     #[test]
     fn fallback_multiline() {
@@ -176,4 +182,106 @@ mod tests {
             </mx-reply>",
         );
     }
+
+    #[test]
+    fn fallback_emote() {
+        let (plain_quote, html_quote) = super::get_message_quote_fallbacks(
+            (&OriginalRoomMessageEvent {
+                content: RoomMessageEventContent::emote_plain(
+                    "feels like today is going to be a great day",
+                ),
+                event_id: owned_event_id!("$1598361704261elfgc:localhost"),
+                sender: owned_user_id!("@alice:example.org"),
+                origin_server_ts: MilliSecondsSinceUnixEpoch::now(),
+                room_id: owned_room_id!("!n8f893n9:example.org"),
+                unsigned: MessageLikeUnsigned::new(),
+            })
+                .into(),
+        );
+
+        assert_eq!(
+            plain_quote,
+            "> * <@alice:example.org> feels like today is going to be a great day"
+        );
+        assert_eq!(
+            html_quote,
+            "<mx-reply>\
+                <blockquote>\
+                    <a href=\"https://matrix.to/#/!n8f893n9:example.org/$1598361704261elfgc:localhost\">In reply to</a> \
+                    * <a href=\"https://matrix.to/#/@alice:example.org\">@alice:example.org</a>\
+                    <br>\
+                    feels like today is going to be a great day\
+                </blockquote>\
+            </mx-reply>",
+        );
+    }
+
+    #[test]
+    fn fallback_image() {
+        let (plain_quote, html_quote) = super::get_message_quote_fallbacks(
+            (&OriginalRoomMessageEvent {
+                content: RoomMessageEventContent::new(MessageType::Image(
+                    ImageMessageEventContent::plain(
+                        "cat.png".to_owned(),
+                        owned_mxc_uri!("mxc://example.org/abcdef"),
+                    ),
+                )),
+                event_id: owned_event_id!("$1598361704261elfgc:localhost"),
+                sender: owned_user_id!("@alice:example.org"),
+                origin_server_ts: MilliSecondsSinceUnixEpoch::now(),
+                room_id: owned_room_id!("!n8f893n9:example.org"),
+                unsigned: MessageLikeUnsigned::new(),
+            })
+                .into(),
+        );
+
+        assert_eq!(plain_quote, "> <@alice:example.org> sent an image.");
+        assert_eq!(
+            html_quote,
+            "<mx-reply>\
+                <blockquote>\
+                    <a href=\"https://matrix.to/#/!n8f893n9:example.org/$1598361704261elfgc:localhost\">In reply to</a> \
+                    <a href=\"https://matrix.to/#/@alice:example.org\">@alice:example.org</a>\
+                    <br>\
+                    sent an image.\
+                </blockquote>\
+            </mx-reply>",
+        );
+    }
+
+    #[test]
+    fn fallback_reply_to_reply() {
+        let mut content = RoomMessageEventContent::text_plain(
+            "> <@bob:example.org> This is the original message\n\nThis is a reply",
+        );
+        content.relates_to = Some(Relation::Reply {
+            in_reply_to: InReplyTo::new(owned_event_id!("$original:example.org")),
+        });
+
+        let (plain_quote, html_quote) = super::get_message_quote_fallbacks(
+            (&OriginalRoomMessageEvent {
+                content,
+                event_id: owned_event_id!("$1598361704261elfgc:localhost"),
+                sender: owned_user_id!("@alice:example.org"),
+                origin_server_ts: MilliSecondsSinceUnixEpoch::now(),
+                room_id: owned_room_id!("!n8f893n9:example.org"),
+                unsigned: MessageLikeUnsigned::new(),
+            })
+                .into(),
+        );
+
+        // The previous reply fallback is not quoted again.
+        assert_eq!(plain_quote, "> <@alice:example.org> This is a reply");
+        assert_eq!(
+            html_quote,
+            "<mx-reply>\
+                <blockquote>\
+                    <a href=\"https://matrix.to/#/!n8f893n9:example.org/$1598361704261elfgc:localhost\">In reply to</a> \
+                    <a href=\"https://matrix.to/#/@alice:example.org\">@alice:example.org</a>\
+                    <br>\
+                    This is a reply\
+                </blockquote>\
+            </mx-reply>",
+        );
+    }
 }
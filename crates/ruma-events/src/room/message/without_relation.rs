@@ -257,7 +257,8 @@ impl RoomMessageEventContentWithoutRelation {
     ///
     /// The first argument after `self` can be `&OriginalRoomMessageEvent` or
     /// `&OriginalSyncRoomMessageEvent` if you don't want to create `ReplacementMetadata` separately
-    /// before calling this function.
+    /// before calling this function. If the given message is itself a replacement, the new
+    /// replacement relates to the original event instead.
     ///
     /// This takes the content and sets it in `m.new_content`, and modifies the `content` to include
     /// a fallback.
@@ -8,6 +8,7 @@ use js_int::{int, Int};
 use ruma_common::{
     power_levels::{default_power_level, NotificationPowerLevels},
     push::PushConditionPowerLevelsCtx,
+    room_version_rules::RedactionRules,
     OwnedUserId, RoomVersionId, UserId,
 };
 use ruma_macros::EventContent;
@@ -151,18 +152,10 @@ impl RedactContent for RoomPowerLevelsEventContent {
             ..
         } = self;
 
-        let invite = match version {
-            RoomVersionId::V1
-            | RoomVersionId::V2
-            | RoomVersionId::V3
-            | RoomVersionId::V4
-            | RoomVersionId::V5
-            | RoomVersionId::V6
-            | RoomVersionId::V7
-            | RoomVersionId::V8
-            | RoomVersionId::V9
-            | RoomVersionId::V10 => int!(0),
-            _ => invite,
+        let invite = if RedactionRules::for_room_version(version).keep_room_power_levels_invite {
+            invite
+        } else {
+            int!(0)
         };
 
         RedactedRoomPowerLevelsEventContent {
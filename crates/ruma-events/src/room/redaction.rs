@@ -7,8 +7,8 @@ use js_int::Int;
 #[cfg(feature = "canonical-json")]
 use ruma_common::canonical_json::RedactionEvent;
 use ruma_common::{
-    serde::CanBeEmpty, EventId, MilliSecondsSinceUnixEpoch, OwnedEventId, OwnedRoomId,
-    OwnedTransactionId, OwnedUserId, RoomId, RoomVersionId, UserId,
+    room_version_rules::RedactionRules, serde::CanBeEmpty, EventId, MilliSecondsSinceUnixEpoch,
+    OwnedEventId, OwnedRoomId, OwnedTransactionId, OwnedUserId, RoomId, RoomVersionId, UserId,
 };
 use ruma_macros::{Event, EventContent};
 use serde::{Deserialize, Serialize};
@@ -215,19 +215,10 @@ impl RedactContent for RoomRedactionEventContent {
     type Redacted = RedactedRoomRedactionEventContent;
 
     fn redact(self, version: &RoomVersionId) -> Self::Redacted {
-        let redacts = match version {
-            RoomVersionId::V1
-            | RoomVersionId::V2
-            | RoomVersionId::V3
-            | RoomVersionId::V4
-            | RoomVersionId::V5
-            | RoomVersionId::V6
-            | RoomVersionId::V7
-            | RoomVersionId::V8
-            | RoomVersionId::V9
-            | RoomVersionId::V10 => None,
-            _ => self.redacts,
-        };
+        let redacts = RedactionRules::for_room_version(version)
+            .keep_room_redaction_redacts
+            .then_some(self.redacts)
+            .flatten();
 
         RedactedRoomRedactionEventContent { redacts }
     }
@@ -316,6 +307,21 @@ impl RoomRedactionEvent {
     pub fn as_original(&self) -> Option<&OriginalRoomRedactionEvent> {
         as_variant!(self, Self::Original)
     }
+
+    /// Convert this full event into a sync event (one without a `room_id` field), and return the
+    /// room ID separately.
+    pub fn into_sync_event_and_room_id(self) -> (SyncRoomRedactionEvent, OwnedRoomId) {
+        match self {
+            Self::Original(ev) => {
+                let (ev, room_id) = ev.into_sync_event_and_room_id();
+                (SyncRoomRedactionEvent::Original(ev), room_id)
+            }
+            Self::Redacted(ev) => {
+                let (ev, room_id) = ev.into_sync_event_and_room_id();
+                (SyncRoomRedactionEvent::Redacted(ev), room_id)
+            }
+        }
+    }
 }
 
 impl SyncRoomRedactionEvent {
@@ -401,6 +407,24 @@ impl OriginalRoomRedactionEvent {
     pub fn redacts(&self, room_version: &RoomVersionId) -> &EventId {
         redacts(room_version, self.redacts.as_deref(), self.content.redacts.as_deref())
     }
+
+    /// Convert this full event into a sync event, one without a `room_id` field, and return the
+    /// room ID separately.
+    pub fn into_sync_event_and_room_id(self) -> (OriginalSyncRoomRedactionEvent, OwnedRoomId) {
+        let Self { content, redacts, event_id, sender, origin_server_ts, room_id, unsigned } = self;
+
+        (
+            OriginalSyncRoomRedactionEvent {
+                content,
+                redacts,
+                event_id,
+                sender,
+                origin_server_ts,
+                unsigned,
+            },
+            room_id,
+        )
+    }
 }
 
 impl OriginalSyncRoomRedactionEvent {
@@ -54,6 +54,9 @@ impl RoomServerAclEventContent {
     }
 
     /// Returns true if and only if the server is allowed by the ACL rules.
+    ///
+    /// The patterns are matched against the host of the server name, without the port. A server
+    /// that matches a `deny` pattern is never allowed, even if it also matches an `allow` pattern.
     pub fn is_allowed(&self, server_name: &ServerName) -> bool {
         if !self.allow_ip_literals && server_name.is_ip_literal() {
             return false;
@@ -178,4 +181,47 @@ mod tests {
         assert!(!acl_event.is_allowed(server_name!("[2001:db8:1234::2]")));
         assert!(acl_event.is_allowed(server_name!("[2001:db8:1234::1]")));
     }
+
+    #[test]
+    fn acl_empty_allow_denies_everyone() {
+        let acl_event = RoomServerAclEventContent {
+            allow_ip_literals: true,
+            allow: Vec::new(),
+            deny: Vec::new(),
+        };
+        assert!(!acl_event.is_allowed(server_name!("matrix.org")));
+        assert!(!acl_event.is_allowed(server_name!("1.1.1.1")));
+    }
+
+    #[test]
+    fn acl_patterns_with_port_never_match() {
+        let acl_event = RoomServerAclEventContent {
+            allow_ip_literals: false,
+            allow: vec!["matrix.org:8448".to_owned(), "conduit.rs".to_owned()],
+            deny: vec!["conduit.rs:8448".to_owned()],
+        };
+        assert!(!acl_event.is_allowed(server_name!("matrix.org")));
+        assert!(!acl_event.is_allowed(server_name!("matrix.org:8448")));
+        assert!(acl_event.is_allowed(server_name!("conduit.rs")));
+        assert!(acl_event.is_allowed(server_name!("conduit.rs:8448")));
+    }
+
+    #[test]
+    fn acl_ipv6_literal() {
+        let acl_event = RoomServerAclEventContent {
+            allow_ip_literals: false,
+            allow: vec!["*".to_owned()],
+            deny: Vec::new(),
+        };
+        assert!(!acl_event.is_allowed(server_name!("[2001:db8:1234::1]")));
+        assert!(!acl_event.is_allowed(server_name!("[2001:db8:1234::1]:8448")));
+
+        let acl_event = RoomServerAclEventContent {
+            allow_ip_literals: true,
+            allow: vec!["*".to_owned()],
+            deny: vec!["[2001:db8:1234::*]".to_owned()],
+        };
+        assert!(!acl_event.is_allowed(server_name!("[2001:db8:1234::1]:8448")));
+        assert!(acl_event.is_allowed(server_name!("[2001:db8:5678::1]:8448")));
+    }
 }
@@ -70,3 +70,10 @@ impl PossiblyRedactedStateEventContent for PossiblyRedactedRoomTombstoneEventCon
 impl StaticEventContent for PossiblyRedactedRoomTombstoneEventContent {
     const TYPE: &'static str = "m.room.tombstone";
 }
+
+impl From<RoomTombstoneEventContent> for PossiblyRedactedRoomTombstoneEventContent {
+    fn from(content: RoomTombstoneEventContent) -> Self {
+        let RoomTombstoneEventContent { body, replacement_room } = content;
+        Self { body: Some(body), replacement_room: Some(replacement_room) }
+    }
+}
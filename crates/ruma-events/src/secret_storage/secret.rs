@@ -5,6 +5,8 @@ use std::collections::BTreeMap;
 use ruma_common::serde::Base64;
 use serde::{Deserialize, Serialize};
 
+use crate::macros::EventContent;
+
 /// A secret and its encrypted contents.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -40,6 +42,69 @@ pub enum SecretEncryptedData {
     },
 }
 
+macro_rules! secret_event_content {
+    ($(#[doc = $doc:literal])* $ev_type:literal => $name:ident) => {
+        $(#[doc = $doc])*
+        #[derive(Clone, Debug, Deserialize, Serialize, EventContent)]
+        #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
+        #[ruma_event(type = $ev_type, kind = GlobalAccountData)]
+        pub struct $name {
+            /// Map from key ID to the encrypted data.
+            ///
+            /// The exact format for the encrypted data is dependent on the key algorithm.
+            pub encrypted: BTreeMap<String, SecretEncryptedData>,
+        }
+
+        impl $name {
+            #[doc = concat!("Create a new `", stringify!($name), "` with the given encrypted content.")]
+            pub fn new(encrypted: BTreeMap<String, SecretEncryptedData>) -> Self {
+                Self { encrypted }
+            }
+        }
+
+        impl From<SecretEventContent> for $name {
+            fn from(content: SecretEventContent) -> Self {
+                Self::new(content.encrypted)
+            }
+        }
+
+        impl From<$name> for SecretEventContent {
+            fn from(content: $name) -> Self {
+                Self::new(content.encrypted)
+            }
+        }
+    };
+}
+
+secret_event_content! {
+    /// The content of an `m.cross_signing.master` event.
+    ///
+    /// The private cross-signing master key, encrypted with the keys of the secret storage.
+    "m.cross_signing.master" => CrossSigningMasterEventContent
+}
+
+secret_event_content! {
+    /// The content of an `m.cross_signing.self_signing` event.
+    ///
+    /// The private cross-signing self-signing key, encrypted with the keys of the secret storage.
+    "m.cross_signing.self_signing" => CrossSigningSelfSigningEventContent
+}
+
+secret_event_content! {
+    /// The content of an `m.cross_signing.user_signing` event.
+    ///
+    /// The private cross-signing user-signing key, encrypted with the keys of the secret storage.
+    "m.cross_signing.user_signing" => CrossSigningUserSigningEventContent
+}
+
+secret_event_content! {
+    /// The content of an `m.megolm_backup.v1` event.
+    ///
+    /// The private key of the server-side key backup, encrypted with the keys of the secret
+    /// storage.
+    "m.megolm_backup.v1" => MegolmBackupV1EventContent
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -48,7 +113,8 @@ mod tests {
     use ruma_common::serde::Base64;
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::{SecretEncryptedData, SecretEventContent};
+    use super::{MegolmBackupV1EventContent, SecretEncryptedData, SecretEventContent};
+    use crate::{AnyGlobalAccountDataEvent, GlobalAccountDataEvent};
 
     #[test]
     fn test_secret_serialization() {
@@ -99,4 +165,63 @@ mod tests {
         assert_eq!(ciphertext.encode(), "dGhpc2lzZGVmaW5pdGVseWNpcGhlcnRleHQ");
         assert_eq!(mac.encode(), "aWRvbnRrbm93d2hhdGFtYWNsb29rc2xpa2U");
     }
+
+    #[test]
+    fn cross_signing_master_event_deserialization() {
+        let json = json!({
+            "type": "m.cross_signing.master",
+            "content": {
+                "encrypted": {
+                    "key_one" : {
+                        "iv": "YWJjZGVmZ2hpamtsbW5vcA",
+                        "ciphertext": "dGhpc2lzZGVmaW5pdGVseWNpcGhlcnRleHQ",
+                        "mac": "aWRvbnRrbm93d2hhdGFtYWNsb29rc2xpa2U"
+                    }
+                }
+            }
+        });
+
+        let event = from_json_value::<AnyGlobalAccountDataEvent>(json.clone()).unwrap();
+        assert_matches!(event, AnyGlobalAccountDataEvent::CrossSigningMaster(event));
+        assert_matches!(
+            event.content.encrypted.get("key_one"),
+            Some(SecretEncryptedData::AesHmacSha2EncryptedData { iv, .. })
+        );
+        assert_eq!(iv.encode(), "YWJjZGVmZ2hpamtsbW5vcA");
+
+        let secret = SecretEventContent::from(event.content.clone());
+        assert_eq!(secret.encrypted.len(), 1);
+
+        assert_eq!(to_json_value(&event).unwrap(), json);
+    }
+
+    #[test]
+    fn megolm_backup_event_serialization() {
+        let mut encrypted = BTreeMap::new();
+        encrypted.insert(
+            "key_one".to_owned(),
+            SecretEncryptedData::AesHmacSha2EncryptedData {
+                iv: Base64::parse("YWJjZGVmZ2hpamtsbW5vcA").unwrap(),
+                ciphertext: Base64::parse("dGhpc2lzZGVmaW5pdGVseWNpcGhlcnRleHQ").unwrap(),
+                mac: Base64::parse("aWRvbnRrbm93d2hhdGFtYWNsb29rc2xpa2U").unwrap(),
+            },
+        );
+        let event = GlobalAccountDataEvent { content: MegolmBackupV1EventContent::new(encrypted) };
+
+        assert_eq!(
+            to_json_value(event).unwrap(),
+            json!({
+                "type": "m.megolm_backup.v1",
+                "content": {
+                    "encrypted": {
+                        "key_one" : {
+                            "iv": "YWJjZGVmZ2hpamtsbW5vcA",
+                            "ciphertext": "dGhpc2lzZGVmaW5pdGVseWNpcGhlcnRleHQ",
+                            "mac": "aWRvbnRrbm93d2hhdGFtYWNsb29rc2xpa2U"
+                        }
+                    }
+                }
+            })
+        );
+    }
 }
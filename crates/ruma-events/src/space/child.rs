@@ -2,9 +2,12 @@
 //!
 //! [`m.space.child`]: https://spec.matrix.org/latest/client-server-api/#mspacechild
 
+use std::ops::Deref;
+
 use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedRoomId, OwnedServerName, OwnedUserId};
 use ruma_macros::{Event, EventContent};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value as JsonValue;
 
 /// The content of an `m.space.child` event.
 ///
@@ -25,12 +28,15 @@ pub struct SpaceChildEventContent {
     /// Rooms are sorted based on a lexicographic ordering of the Unicode codepoints of the
     /// characters in `order` values. Rooms with no `order` come last, in ascending numeric order
     /// of the origin_server_ts of their m.room.create events, or ascending lexicographic order of
-    /// their room_ids in case of equal `origin_server_ts`. `order`s which are not strings, or do
-    /// not consist solely of ascii characters in the range `\x20` (space) to `\x7E` (`~`), or
-    /// consist of more than 50 characters, are forbidden and the field should be ignored if
-    /// received.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub order: Option<String>,
+    /// their room_ids in case of equal `origin_server_ts`.
+    ///
+    /// Invalid `order`s are ignored during deserialization.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_order",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub order: Option<SpaceChildOrder>,
 
     /// Space admins can mark particular children of a space as "suggested".
     ///
@@ -51,6 +57,84 @@ impl SpaceChildEventContent {
     }
 }
 
+/// The order of a child of a space.
+///
+/// This is a string of at most 50 ASCII characters in the range `\x20` (space) to `\x7E` (`~`).
+///
+/// To build this, use the `TryFrom` implementations.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct SpaceChildOrder(String);
+
+impl SpaceChildOrder {
+    /// The largest number of characters of a `SpaceChildOrder`.
+    pub const MAX_LENGTH: usize = 50;
+
+    /// The string value of this `SpaceChildOrder`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// An error encountered when trying to convert to a `SpaceChildOrder`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum SpaceChildOrderError {
+    /// There are more than [`SpaceChildOrder::MAX_LENGTH`] characters.
+    #[error("too many characters")]
+    TooLong,
+
+    /// There are characters outside of the `\x20` to `\x7E` range.
+    #[error("invalid characters")]
+    InvalidCharacters,
+}
+
+impl TryFrom<String> for SpaceChildOrder {
+    type Error = SpaceChildOrderError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.len() > Self::MAX_LENGTH {
+            Err(SpaceChildOrderError::TooLong)
+        } else if !value.bytes().all(|b| matches!(b, b'\x20'..=b'\x7E')) {
+            Err(SpaceChildOrderError::InvalidCharacters)
+        } else {
+            Ok(Self(value))
+        }
+    }
+}
+
+impl TryFrom<&str> for SpaceChildOrder {
+    type Error = SpaceChildOrderError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_owned())
+    }
+}
+
+impl Deref for SpaceChildOrder {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl PartialEq<&str> for SpaceChildOrder {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+/// Deserializes an `order`, ignoring invalid values as required by the spec.
+fn deserialize_order<'de, D>(deserializer: D) -> Result<Option<SpaceChildOrder>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<JsonValue>::deserialize(deserializer)? {
+        Some(JsonValue::String(order)) => order.try_into().ok(),
+        _ => None,
+    })
+}
+
 /// An `m.space.child` event represented as a Stripped State Event with an added `origin_server_ts`
 /// key.
 #[derive(Clone, Debug, Event)]
@@ -75,13 +159,15 @@ mod tests {
     use ruma_common::{server_name, MilliSecondsSinceUnixEpoch};
     use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
-    use super::{HierarchySpaceChildEvent, SpaceChildEventContent};
+    use super::{
+        HierarchySpaceChildEvent, SpaceChildEventContent, SpaceChildOrder, SpaceChildOrderError,
+    };
 
     #[test]
     fn space_child_serialization() {
         let content = SpaceChildEventContent {
             via: vec![server_name!("example.com").to_owned()],
-            order: Some("uwu".to_owned()),
+            order: Some("uwu".try_into().unwrap()),
             suggested: false,
         };
 
@@ -124,4 +210,58 @@ mod tests {
         assert_eq!(ev.content.order, None);
         assert!(!ev.content.suggested);
     }
+
+    #[test]
+    fn space_child_order_validation() {
+        assert_eq!(SpaceChildOrder::try_from("a").unwrap(), "a");
+        assert_eq!(SpaceChildOrder::try_from(" ~").unwrap(), " ~");
+        SpaceChildOrder::try_from("a".repeat(50)).unwrap();
+        assert_eq!(
+            SpaceChildOrder::try_from("a".repeat(51)).unwrap_err(),
+            SpaceChildOrderError::TooLong
+        );
+        assert_eq!(
+            SpaceChildOrder::try_from("\n").unwrap_err(),
+            SpaceChildOrderError::InvalidCharacters
+        );
+        assert_eq!(
+            SpaceChildOrder::try_from("é").unwrap_err(),
+            SpaceChildOrderError::InvalidCharacters
+        );
+    }
+
+    #[test]
+    fn space_child_valid_order_deserialization() {
+        let json = json!({
+            "via": ["example.org"],
+            "order": "abc",
+        });
+
+        let content = from_json_value::<SpaceChildEventContent>(json).unwrap();
+        assert_eq!(content.order.unwrap(), "abc");
+    }
+
+    #[test]
+    fn space_child_invalid_order_deserialization() {
+        let content = from_json_value::<SpaceChildEventContent>(json!({
+            "via": ["example.org"],
+            "order": "a".repeat(51),
+        }))
+        .unwrap();
+        assert_eq!(content.order, None);
+
+        let content = from_json_value::<SpaceChildEventContent>(json!({
+            "via": ["example.org"],
+            "order": "\u{1F600}",
+        }))
+        .unwrap();
+        assert_eq!(content.order, None);
+
+        let content = from_json_value::<SpaceChildEventContent>(json!({
+            "via": ["example.org"],
+            "order": 42,
+        }))
+        .unwrap();
+        assert_eq!(content.order, None);
+    }
 }
@@ -8,7 +8,7 @@ use serde::{de, Deserialize, Serialize};
 
 #[cfg(feature = "compat-encrypted-stickers")]
 use crate::room::EncryptedFile;
-use crate::room::{ImageInfo, MediaSource};
+use crate::room::{message::ImageMessageEventContent, ImageInfo, MediaSource};
 
 /// The source of a sticker media file.
 #[derive(Clone, Debug, Serialize)]
@@ -100,7 +100,7 @@ pub struct StickerEventContent {
 impl StickerEventContent {
     /// Creates a new `StickerEventContent` with the given body, image info and URL.
     pub fn new(body: String, info: ImageInfo, url: OwnedMxcUri) -> Self {
-        Self { body, info, source: StickerMediaSource::Plain(url.clone()) }
+        Self { body, info, source: StickerMediaSource::Plain(url) }
     }
 
     /// Creates a new `StickerEventContent` with the given body, image info, URL, and media source.
@@ -108,3 +108,34 @@ impl StickerEventContent {
         Self { body, info, source }
     }
 }
+
+/// Converts an image message to a sticker.
+///
+/// The `formatted` and `filename` fields of the image message are dropped.
+///
+/// Returns the original image message if its source cannot be represented in a sticker, which is
+/// the case for encrypted images when the `compat-encrypted-stickers` feature is disabled.
+impl TryFrom<ImageMessageEventContent> for StickerEventContent {
+    type Error = ImageMessageEventContent;
+
+    fn try_from(image: ImageMessageEventContent) -> Result<Self, Self::Error> {
+        let source = match image.source {
+            MediaSource::Plain(url) => StickerMediaSource::Plain(url),
+            #[cfg(feature = "compat-encrypted-stickers")]
+            MediaSource::Encrypted(file) => StickerMediaSource::Encrypted(file),
+            #[cfg(not(feature = "compat-encrypted-stickers"))]
+            MediaSource::Encrypted(_) => return Err(image),
+        };
+        let info = image.info.map(|info| *info).unwrap_or_default();
+
+        Ok(Self::with_source(image.body, info, source))
+    }
+}
+
+/// Converts a sticker to an image message.
+impl From<StickerEventContent> for ImageMessageEventContent {
+    fn from(sticker: StickerEventContent) -> Self {
+        let StickerEventContent { body, info, source } = sticker;
+        Self::new(body, source.into()).info(Box::new(info))
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! [`m.typing`]: https://spec.matrix.org/latest/client-server-api/#mtyping
 
-use ruma_common::OwnedUserId;
+use ruma_common::{OwnedUserId, UserId};
 use ruma_macros::EventContent;
 use serde::{Deserialize, Serialize};
 
@@ -22,4 +22,9 @@ impl TypingEventContent {
     pub fn new(user_ids: Vec<OwnedUserId>) -> Self {
         Self { user_ids }
     }
+
+    /// Whether the given user is typing in this room.
+    pub fn contains(&self, user_id: &UserId) -> bool {
+        self.user_ids.iter().any(|id| id == user_id)
+    }
 }
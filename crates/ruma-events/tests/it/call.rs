@@ -2,7 +2,9 @@ use assert_matches2::assert_matches;
 #[cfg(feature = "unstable-msc2747")]
 use assign::assign;
 use js_int::uint;
-use ruma_common::{room_id, serde::CanBeEmpty, MilliSecondsSinceUnixEpoch, VoipVersionId};
+use ruma_common::{
+    owned_user_id, room_id, serde::CanBeEmpty, MilliSecondsSinceUnixEpoch, VoipVersionId,
+};
 #[cfg(feature = "unstable-msc2747")]
 use ruma_events::call::CallCapabilities;
 use ruma_events::{
@@ -13,8 +15,9 @@ use ruma_events::{
         invite::CallInviteEventContent,
         negotiate::CallNegotiateEventContent,
         reject::CallRejectEventContent,
+        sdp_stream_metadata_changed::CallSdpStreamMetadataChangedEventContent,
         select_answer::CallSelectAnswerEventContent,
-        SessionDescription,
+        SessionDescription, StreamMetadata, StreamPurpose,
     },
     AnyMessageLikeEvent, AnySyncMessageLikeEvent, MessageLikeEvent,
 };
@@ -247,6 +250,143 @@ fn invite_v1_event_deserialization() {
     assert_eq!(content.offer.sdp, "not a real sdp");
 }
 
+#[test]
+fn invite_v1_event_invitee_and_stream_metadata_serialization() {
+    let mut content = CallInviteEventContent::version_1(
+        "abcdef".into(),
+        "9876".into(),
+        uint!(60000),
+        SessionDescription::new("offer".to_owned(), "not a real sdp".to_owned()),
+    );
+    content.invitee = Some(owned_user_id!("@bob:notareal.hs"));
+    let mut screenshare = StreamMetadata::new(StreamPurpose::ScreenShare);
+    screenshare.audio_muted = true;
+    content.sdp_stream_metadata = [
+        ("usermedia-stream".to_owned(), StreamMetadata::new(StreamPurpose::UserMedia)),
+        ("screenshare-stream".to_owned(), screenshare),
+    ]
+    .into();
+
+    assert_eq!(
+        to_json_value(&content).unwrap(),
+        json!({
+            "call_id": "abcdef",
+            "party_id": "9876",
+            "lifetime": 60000,
+            "version": "1",
+            "offer": {
+                "type": "offer",
+                "sdp": "not a real sdp",
+            },
+            "invitee": "@bob:notareal.hs",
+            "sdp_stream_metadata": {
+                "screenshare-stream": {
+                    "purpose": "m.screenshare",
+                    "audio_muted": true,
+                },
+                "usermedia-stream": {
+                    "purpose": "m.usermedia",
+                },
+            },
+        })
+    );
+}
+
+#[test]
+fn invite_v1_event_invitee_and_stream_metadata_deserialization() {
+    let json_data = json!({
+        "call_id": "abcdef",
+        "party_id": "9876",
+        "lifetime": 60000,
+        "version": "1",
+        "offer": {
+            "type": "offer",
+            "sdp": "not a real sdp",
+        },
+        "invitee": "@bob:notareal.hs",
+        "sdp_stream_metadata": {
+            "usermedia-stream": {
+                "purpose": "m.usermedia",
+                "video_muted": true,
+            },
+        },
+    });
+
+    let content = from_json_value::<CallInviteEventContent>(json_data).unwrap();
+    assert_eq!(content.invitee.unwrap(), "@bob:notareal.hs");
+    assert_eq!(content.sdp_stream_metadata.len(), 1);
+    let metadata = &content.sdp_stream_metadata["usermedia-stream"];
+    assert_eq!(metadata.purpose, StreamPurpose::UserMedia);
+    assert!(!metadata.audio_muted);
+    assert!(metadata.video_muted);
+}
+
+#[test]
+fn sdp_stream_metadata_changed_event_serialization() {
+    let content = CallSdpStreamMetadataChangedEventContent::new(
+        "abcdef".into(),
+        "9876".into(),
+        VoipVersionId::V1,
+        [("screenshare-stream".to_owned(), StreamMetadata::new(StreamPurpose::ScreenShare))].into(),
+    );
+
+    assert_eq!(
+        to_json_value(&content).unwrap(),
+        json!({
+            "call_id": "abcdef",
+            "party_id": "9876",
+            "version": "1",
+            "sdp_stream_metadata": {
+                "screenshare-stream": {
+                    "purpose": "m.screenshare",
+                },
+            },
+        })
+    );
+}
+
+#[test]
+fn sdp_stream_metadata_changed_event_deserialization() {
+    for event_type in
+        ["m.call.sdp_stream_metadata_changed", "org.matrix.call.sdp_stream_metadata_changed"]
+    {
+        let json_data = json!({
+            "content": {
+                "call_id": "abcdef",
+                "party_id": "9876",
+                "version": "1",
+                "sdp_stream_metadata": {
+                    "screenshare-stream": {
+                        "purpose": "m.screenshare",
+                        "audio_muted": true,
+                    },
+                },
+            },
+            "event_id": "$event:notareal.hs",
+            "origin_server_ts": 134_829_848,
+            "room_id": "!roomid:notareal.hs",
+            "sender": "@user:notareal.hs",
+            "type": event_type,
+        });
+
+        let event = from_json_value::<AnyMessageLikeEvent>(json_data).unwrap();
+        assert_matches!(
+            event,
+            AnyMessageLikeEvent::CallSdpStreamMetadataChanged(MessageLikeEvent::Original(
+                message_event
+            ))
+        );
+        let content = message_event.content;
+        assert_eq!(content.call_id, "abcdef");
+        assert_eq!(content.party_id, "9876");
+        assert_eq!(content.version, VoipVersionId::V1);
+        let metadata = &content.sdp_stream_metadata["screenshare-stream"];
+        assert_eq!(metadata.purpose, StreamPurpose::ScreenShare);
+        assert!(metadata.audio_muted);
+        assert!(!metadata.video_muted);
+    }
+}
+
 #[test]
 fn answer_v1_event_serialization() {
     let content = CallAnswerEventContent::version_1(
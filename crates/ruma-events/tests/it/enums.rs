@@ -6,13 +6,14 @@ use ruma_events::{
         aliases::RoomAliasesEventContent,
         message::{MessageType, RoomMessageEventContent},
         power_levels::RoomPowerLevelsEventContent,
+        redaction::SyncRoomRedactionEvent,
     },
     AnyEphemeralRoomEvent, AnyMessageLikeEvent, AnyStateEvent, AnySyncMessageLikeEvent,
     AnySyncStateEvent, AnySyncTimelineEvent, AnyTimelineEvent, EphemeralRoomEventType,
     GlobalAccountDataEventType, MessageLikeEvent, MessageLikeEventType, OriginalMessageLikeEvent,
     OriginalStateEvent, OriginalSyncMessageLikeEvent, OriginalSyncStateEvent,
     RoomAccountDataEventType, StateEvent, StateEventType, SyncMessageLikeEvent, SyncStateEvent,
-    ToDeviceEventType,
+    TimelineEventType, ToDeviceEventType,
 };
 use serde_json::{from_value as from_json_value, json, Value as JsonValue};
 
@@ -253,6 +254,65 @@ fn alias_event_field_access() {
     assert_eq!(deser.event_type().to_string(), "m.room.aliases");
 }
 
+#[test]
+fn message_event_into_sync_event_and_room_id() {
+    let event = from_json_value::<AnyTimelineEvent>(message_event()).unwrap();
+
+    let (sync_event, room_id) = event.into_sync_event_and_room_id();
+    assert_eq!(room_id, "!room:room.com");
+    assert_matches!(
+        sync_event,
+        AnySyncTimelineEvent::MessageLike(AnySyncMessageLikeEvent::RoomMessage(
+            SyncMessageLikeEvent::Original(ev)
+        ))
+    );
+    assert_eq!(ev.event_id, "$152037280074GZeOm:localhost");
+    assert_eq!(ev.content.body(), "baba");
+}
+
+#[test]
+fn state_event_into_sync_event_and_room_id() {
+    let event = from_json_value::<AnyTimelineEvent>(aliases_event()).unwrap();
+
+    let (sync_event, room_id) = event.into_sync_event_and_room_id();
+    assert_eq!(room_id, "!room:room.com");
+    assert_matches!(
+        sync_event,
+        AnySyncTimelineEvent::State(AnySyncStateEvent::RoomAliases(SyncStateEvent::Original(ev)))
+    );
+    assert_eq!(ev.state_key, "room.com");
+    assert_eq!(ev.content.aliases, vec![room_alias_id!("#somewhere:localhost")]);
+}
+
+#[test]
+fn redaction_event_into_sync_event_and_room_id() {
+    let json_data = json!({
+        "content": {
+            "reason": "Spamming",
+        },
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "redacts": "$152037280074GZeOm:localhost",
+        "room_id": "!room:room.com",
+        "sender": "@example:localhost",
+        "type": "m.room.redaction",
+        "unsigned": {},
+    });
+    let event = from_json_value::<AnyTimelineEvent>(json_data).unwrap();
+
+    let (sync_event, room_id) = event.into_sync_event_and_room_id();
+    assert_eq!(room_id, "!room:room.com");
+    assert_matches!(
+        sync_event,
+        AnySyncTimelineEvent::MessageLike(AnySyncMessageLikeEvent::RoomRedaction(
+            SyncRoomRedactionEvent::Original(ev)
+        ))
+    );
+    assert_eq!(ev.event_id, "$h29iv0s8:example.com");
+    assert_eq!(ev.redacts.unwrap(), "$152037280074GZeOm:localhost");
+    assert_eq!(ev.content.reason.as_deref(), Some("Spamming"));
+}
+
 #[test]
 fn ephemeral_event_deserialization() {
     let json_data = json!({
@@ -282,3 +342,35 @@ fn serialize_and_deserialize_from_display_form() {
     serde_json_eq(StateEventType::RoomCreate, json!("m.room.create"));
     serde_json_eq(EphemeralRoomEventType::Typing, json!("m.typing"));
 }
+
+#[test]
+fn event_type_from_str_and_display() {
+    assert_eq!(StateEventType::from("m.room.name"), StateEventType::RoomName);
+    assert_eq!(StateEventType::RoomName.to_string(), "m.room.name");
+
+    let custom = StateEventType::from("com.example.state");
+    assert_eq!(custom.to_string(), "com.example.state");
+    serde_json_eq(custom, json!("com.example.state"));
+
+    assert_eq!(
+        GlobalAccountDataEventType::from("m.secret_storage.key.abc"),
+        GlobalAccountDataEventType::SecretStorageKey("abc".to_owned())
+    );
+    assert_eq!(
+        GlobalAccountDataEventType::SecretStorageKey("abc".to_owned()).to_string(),
+        "m.secret_storage.key.abc"
+    );
+}
+
+#[test]
+fn event_type_into_timeline_event_type() {
+    assert_eq!(TimelineEventType::from(StateEventType::RoomName), TimelineEventType::RoomName);
+    assert_eq!(
+        TimelineEventType::from(MessageLikeEventType::RoomMessage),
+        TimelineEventType::RoomMessage
+    );
+
+    let custom = TimelineEventType::from(StateEventType::from("com.example.state"));
+    assert_eq!(custom.to_string(), "com.example.state");
+    assert_eq!(custom, TimelineEventType::from("com.example.state"));
+}
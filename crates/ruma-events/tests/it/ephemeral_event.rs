@@ -3,7 +3,7 @@ use js_int::uint;
 use maplit::btreemap;
 use ruma_common::{event_id, owned_event_id, owned_user_id, user_id, MilliSecondsSinceUnixEpoch};
 use ruma_events::{
-    receipt::{Receipt, ReceiptEventContent, ReceiptType},
+    receipt::{Receipt, ReceiptEventContent, ReceiptThread, ReceiptType},
     typing::TypingEventContent,
     AnyEphemeralRoomEvent,
 };
@@ -37,6 +37,8 @@ fn deserialize_ephemeral_typing() {
     );
     assert_eq!(typing_event.content.user_ids.len(), 1);
     assert_eq!(typing_event.content.user_ids[0], "@carl:example.com");
+    assert!(typing_event.content.contains(user_id!("@carl:example.com")));
+    assert!(!typing_event.content.contains(user_id!("@alice:example.com")));
     assert_eq!(typing_event.room_id, "!roomid:room.com");
 }
 
@@ -94,3 +96,63 @@ fn deserialize_ephemeral_receipt() {
     let user_receipt = type_receipts.get(user_id).unwrap();
     assert_eq!(user_receipt.ts, Some(MilliSecondsSinceUnixEpoch(uint!(1))));
 }
+
+#[test]
+fn receipt_content_user_receipt() {
+    let json_data = json!({
+        "$main:example.com": {
+            "m.read": {
+                "@carl:example.com": { "ts": 1, "thread_id": "main" },
+            },
+        },
+        "$thread:example.com": {
+            "m.read": {
+                "@carl:example.com": { "ts": 2, "thread_id": "$root:example.com" },
+            },
+            "m.read.private": {
+                "@carl:example.com": { "ts": 3 },
+            },
+        },
+    });
+    let content = from_json_value::<ReceiptEventContent>(json_data).unwrap();
+    let user_id = user_id!("@carl:example.com");
+
+    let (event_id, receipt) = content.user_receipt(user_id, ReceiptType::ReadPrivate).unwrap();
+    assert_eq!(event_id, "$thread:example.com");
+    assert_eq!(receipt.ts, Some(MilliSecondsSinceUnixEpoch(uint!(3))));
+    assert_eq!(receipt.thread, ReceiptThread::Unthreaded);
+
+    let (event_id, receipt) =
+        content.user_receipt_in_thread(user_id, ReceiptType::Read, &ReceiptThread::Main).unwrap();
+    assert_eq!(event_id, "$main:example.com");
+    assert_eq!(receipt.ts, Some(MilliSecondsSinceUnixEpoch(uint!(1))));
+
+    let thread = ReceiptThread::Thread(owned_event_id!("$root:example.com"));
+    let (event_id, receipt) =
+        content.user_receipt_in_thread(user_id, ReceiptType::Read, &thread).unwrap();
+    assert_eq!(event_id, "$thread:example.com");
+    assert_eq!(receipt.ts, Some(MilliSecondsSinceUnixEpoch(uint!(2))));
+
+    assert!(content
+        .user_receipt_in_thread(user_id, ReceiptType::Read, &ReceiptThread::Unthreaded)
+        .is_none());
+    assert!(content.user_receipt(user_id!("@dave:example.com"), ReceiptType::Read).is_none());
+}
+
+#[test]
+fn receipt_content_unknown_receipt_type_roundtrip() {
+    let json_data = json!({
+        "$h29iv0s8:example.com": {
+            "io.ruma.receipt": {
+                "@carl:example.com": { "ts": 1, "thread_id": "io.ruma.thread" },
+            },
+        },
+    });
+
+    let content = from_json_value::<ReceiptEventContent>(json_data.clone()).unwrap();
+    let receipt_type = ReceiptType::from("io.ruma.receipt");
+    let (_, receipt) = content.user_receipt(user_id!("@carl:example.com"), receipt_type).unwrap();
+    assert_eq!(receipt.thread.as_str(), Some("io.ruma.thread"));
+
+    assert_eq!(to_json_value(&content).unwrap(), json_data);
+}
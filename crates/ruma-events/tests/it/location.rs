@@ -231,3 +231,62 @@ fn room_message_unstable_serialization() {
         })
     );
 }
+
+#[test]
+fn room_message_unstable_roundtrip() {
+    let json_data = json!({
+        "body": "Alice was at geo:51.5008,0.1247;u=35",
+        "geo_uri": "geo:51.5008,0.1247;u=35",
+        "msgtype": "m.location",
+        "org.matrix.msc1767.text": "Alice was at geo:51.5008,0.1247;u=35",
+        "org.matrix.msc3488.location": {
+            "uri": "geo:51.5008,0.1247;u=35",
+        },
+        "org.matrix.msc3488.asset": {
+            "type": "m.pin",
+        },
+        "org.matrix.msc3488.ts": 1_636_829_458,
+    });
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data.clone()).unwrap();
+    assert_matches!(&event_content.msgtype, MessageType::Location(content));
+    assert_eq!(content.asset_type(), AssetType::Pin);
+    assert_eq!(content.ts, Some(MilliSecondsSinceUnixEpoch(uint!(1_636_829_458))));
+    assert_eq!(content.parsed_geo_uri().unwrap().uncertainty(), Some(35.0));
+
+    assert_eq!(to_json_value(&event_content).unwrap(), json_data);
+}
+
+#[test]
+fn room_message_stable_roundtrip() {
+    let json_data = json!({
+        "body": "Alice was at geo:51.5008,0.1247;u=35",
+        "geo_uri": "geo:51.5008,0.1247;u=35",
+        "msgtype": "m.location",
+    });
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data.clone()).unwrap();
+    assert_matches!(&event_content.msgtype, MessageType::Location(content));
+    assert!(content.message.is_none());
+    assert!(content.location.is_none());
+    assert!(content.asset.is_none());
+    assert_eq!(content.ts, None);
+
+    assert_eq!(to_json_value(&event_content).unwrap(), json_data);
+}
+
+#[test]
+fn room_message_unstable_location_preferred_for_geo_uri() {
+    let json_data = json!({
+        "body": "Alice was at geo:51.5008,0.1247",
+        "geo_uri": "geo:not-a-location",
+        "msgtype": "m.location",
+        "org.matrix.msc3488.location": {
+            "uri": "geo:51.5008,0.1247",
+        },
+    });
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::Location(content));
+    assert_eq!(content.parsed_geo_uri().unwrap().latitude(), 51.5008);
+}
@@ -460,6 +460,50 @@ fn replacement_unstable_start_content_serialization() {
     );
 }
 
+#[test]
+fn unstable_start_content_disclosed_roundtrip() {
+    let json_data = json!({
+        "org.matrix.msc1767.text": "What should we order?\n1. Pizza\n2. Sushi",
+        "org.matrix.msc3381.poll.start": {
+            "kind": "org.matrix.msc3381.poll.disclosed",
+            "max_selections": 1,
+            "question": { "org.matrix.msc1767.text": "What should we order?" },
+            "answers": [
+                { "id": "pizza", "org.matrix.msc1767.text": "Pizza" },
+                { "id": "sushi", "org.matrix.msc1767.text": "Sushi" },
+            ],
+        },
+    });
+
+    let content = from_json_value::<UnstablePollStartEventContent>(json_data.clone()).unwrap();
+    assert_matches!(&content, UnstablePollStartEventContent::New(new_content));
+    assert_eq!(new_content.poll_start.kind, PollKind::Disclosed);
+
+    assert_eq!(to_json_value(&content).unwrap(), json_data);
+}
+
+#[test]
+fn unstable_start_content_custom_kind_roundtrip() {
+    let json_data = json!({
+        "org.matrix.msc1767.text": "What should we order?\n1. Pizza\n2. Sushi",
+        "org.matrix.msc3381.poll.start": {
+            "kind": "io.ruma.poll.secret",
+            "max_selections": 1,
+            "question": { "org.matrix.msc1767.text": "What should we order?" },
+            "answers": [
+                { "id": "pizza", "org.matrix.msc1767.text": "Pizza" },
+                { "id": "sushi", "org.matrix.msc1767.text": "Sushi" },
+            ],
+        },
+    });
+
+    let content = from_json_value::<UnstablePollStartEventContent>(json_data.clone()).unwrap();
+    assert_matches!(&content, UnstablePollStartEventContent::New(new_content));
+    assert_eq!(new_content.poll_start.kind.as_str(), "io.ruma.poll.secret");
+
+    assert_eq!(to_json_value(&content).unwrap(), json_data);
+}
+
 #[test]
 fn unstable_start_event_content_deserialization_missing_poll_start() {
     let json_data = json!({
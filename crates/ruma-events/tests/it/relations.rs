@@ -1,9 +1,19 @@
 use assert_matches2::assert_matches;
 use assign::assign;
+use js_int::uint;
 use ruma_common::{owned_event_id, serde::Raw};
+#[cfg(feature = "unstable-msc2677")]
 use ruma_events::{
-    relation::{CustomRelation, InReplyTo, Replacement, Thread},
+    relation::{AnnotationChunk, BundledAnnotation},
+    MessageLikeEventType,
+};
+use ruma_events::{
+    relation::{
+        BundledMessageLikeRelations, BundledStateRelations, CustomRelation, InReplyTo, Replacement,
+        Thread,
+    },
     room::message::{MessageType, Relation, RoomMessageEventContent},
+    AnySyncMessageLikeEvent, SyncMessageLikeEvent,
 };
 use serde_json::{
     from_value as from_json_value, json, to_value as to_json_value, Value as JsonValue,
@@ -301,12 +311,34 @@ fn thread_serialization_roundtrip() {
 
     assert_matches!(deser_content.msgtype, MessageType::Text(deser_msg));
     assert_eq!(deser_msg.body, body);
-    assert_matches!(content.relates_to.unwrap(), Relation::Thread(deser_thread));
+    assert_matches!(deser_content.relates_to.unwrap(), Relation::Thread(deser_thread));
     assert_eq!(deser_thread.event_id, thread.event_id);
     assert_eq!(deser_thread.in_reply_to.unwrap().event_id, thread.in_reply_to.unwrap().event_id);
     assert_eq!(deser_thread.is_falling_back, thread.is_falling_back);
 }
 
+#[test]
+fn thread_json_roundtrip() {
+    let json = json!({
+        "msgtype": "m.text",
+        "body": "<text msg>",
+        "m.relates_to": {
+            "rel_type": "m.thread",
+            "event_id": "$1598361704261elfgc",
+            "m.in_reply_to": {
+                "event_id": "$latesteventid",
+            },
+            "is_falling_back": true,
+        },
+    });
+
+    let content = from_json_value::<RoomMessageEventContent>(json.clone()).unwrap();
+    assert_matches!(&content.relates_to, Some(Relation::Thread(thread)));
+    assert!(thread.is_falling_back);
+
+    assert_eq!(to_json_value(content).unwrap(), json);
+}
+
 #[test]
 fn custom_deserialize() {
     let relation_json = json!({
@@ -387,3 +419,117 @@ fn custom_serialization_roundtrip() {
     assert_eq!(deser_relation.get("event_id").unwrap().as_str().unwrap(), event_id);
     assert_eq!(deser_relation.get("key").unwrap().as_str().unwrap(), key);
 }
+
+#[test]
+fn bundled_aggregations_deserialize() {
+    let json = json!({
+        "content": {
+            "body": "Hello",
+            "msgtype": "m.text",
+        },
+        "event_id": "$root",
+        "origin_server_ts": 1,
+        "sender": "@alice:example.org",
+        "type": "m.room.message",
+        "unsigned": {
+            "m.relations": {
+                "m.annotation": {
+                    "chunk": [
+                        { "type": "m.reaction", "key": "👍", "count": 3 },
+                    ],
+                },
+                "m.reference": {
+                    "chunk": [{ "event_id": "$reference" }],
+                },
+                "m.thread": {
+                    "latest_event": {
+                        "content": {
+                            "body": "Hi!",
+                            "msgtype": "m.text",
+                        },
+                        "event_id": "$latest",
+                        "origin_server_ts": 2,
+                        "room_id": "!room:example.org",
+                        "sender": "@bob:example.org",
+                        "type": "m.room.message",
+                    },
+                    "count": 2,
+                    "current_user_participated": true,
+                },
+            },
+        },
+    });
+
+    let event = from_json_value::<AnySyncMessageLikeEvent>(json).unwrap();
+    assert_matches!(
+        event,
+        AnySyncMessageLikeEvent::RoomMessage(SyncMessageLikeEvent::Original(event))
+    );
+    let relations = event.unsigned.relations;
+    assert!(!relations.is_empty());
+    assert!(!relations.has_replacement());
+
+    let thread = relations.thread.unwrap();
+    assert_eq!(thread.count, uint!(2));
+    assert!(thread.current_user_participated);
+    assert_eq!(thread.latest_event.deserialize().unwrap().event_id(), "$latest");
+
+    let reference = relations.reference.unwrap();
+    assert_eq!(reference.chunk.len(), 1);
+    assert_eq!(reference.chunk[0].event_id, "$reference");
+
+    #[cfg(feature = "unstable-msc2677")]
+    {
+        let annotation = relations.annotation.unwrap();
+        assert_eq!(annotation.chunk.len(), 1);
+        assert_eq!(annotation.chunk[0].event_type, MessageLikeEventType::Reaction);
+        assert_eq!(annotation.chunk[0].key, "👍");
+        assert_eq!(annotation.chunk[0].count, uint!(3));
+        assert_eq!(annotation.next_batch, None);
+    }
+}
+
+#[test]
+#[cfg(feature = "unstable-msc2677")]
+fn bundled_annotation_serialize() {
+    let chunk = AnnotationChunk::new(vec![BundledAnnotation::reaction("👍".to_owned(), uint!(3))]);
+    assert_eq!(
+        to_json_value(chunk).unwrap(),
+        json!({
+            "chunk": [{ "type": "m.reaction", "key": "👍", "count": 3 }],
+        })
+    );
+}
+
+#[test]
+fn bundled_aggregations_unknown_relation_type_roundtrip() {
+    let relations_json = json!({
+        "m.reference": {
+            "chunk": [{ "event_id": "$reference" }],
+        },
+        "org.example.custom": {
+            "chunk": [{ "key": "value" }],
+            "count": 1,
+        },
+    });
+
+    let relations =
+        from_json_value::<BundledMessageLikeRelations<JsonValue>>(relations_json.clone()).unwrap();
+    assert!(!relations.is_empty());
+    assert_eq!(relations.reference.as_ref().unwrap().chunk[0].event_id, "$reference");
+    assert_eq!(relations.other.len(), 1);
+    assert_eq!(
+        relations.other["org.example.custom"],
+        json!({ "chunk": [{ "key": "value" }], "count": 1 })
+    );
+    assert_eq!(to_json_value(&relations).unwrap(), relations_json);
+
+    let relations_json = json!({
+        "org.example.custom": { "count": 1 },
+    });
+
+    let relations = from_json_value::<BundledStateRelations>(relations_json.clone()).unwrap();
+    assert!(!relations.is_empty());
+    assert_eq!(relations.other.len(), 1);
+    assert_eq!(to_json_value(&relations).unwrap(), relations_json);
+}
@@ -13,11 +13,12 @@ use ruma_events::{
         message::{
             AddMentions, AudioMessageEventContent, EmoteMessageEventContent,
             FileMessageEventContent, ForwardThread, ImageMessageEventContent,
-            KeyVerificationRequestEventContent, MessageType, OriginalRoomMessageEvent,
+            KeyVerificationRequestEventContent, LimitType, MessageType, OriginalRoomMessageEvent,
             OriginalSyncRoomMessageEvent, Relation, ReplyWithinThread, RoomMessageEventContent,
-            TextMessageEventContent, VideoMessageEventContent,
+            ServerNoticeMessageEventContent, ServerNoticeType, TextMessageEventContent, VideoInfo,
+            VideoMessageEventContent,
         },
-        EncryptedFileInit, JsonWebKeyInit, MediaSource,
+        EncryptedFile, EncryptedFileInit, JsonWebKeyInit, MediaSource,
     },
     AnySyncTimelineEvent, EventContent, Mentions, MessageLikeUnsigned, RawExt,
 };
@@ -75,6 +76,89 @@ fn custom_msgtype_deserialization() {
     assert_eq!(custom_event.data(), Cow::Owned(expected_json_data));
 }
 
+#[test]
+fn custom_msgtype_content_roundtrip() {
+    let json_data = json!({
+        "msgtype": "com.example.custom",
+        "body": "my custom message",
+        "com.example.data": {
+            "nested": [1, 2, 3],
+            "flag": true,
+        },
+        "com.example.count": 42,
+    });
+
+    let content = from_json_value::<RoomMessageEventContent>(json_data.clone()).unwrap();
+    assert_eq!(content.msgtype(), "com.example.custom");
+    assert_eq!(content.body(), "my custom message");
+    assert_eq!(content.msgtype.data()["com.example.count"], 42);
+
+    assert_eq!(to_json_value(&content).unwrap(), json_data);
+}
+
+#[test]
+fn server_notice_msgtype_serialization() {
+    let mut server_notice = ServerNoticeMessageEventContent::new(
+        "Monthly active users limit reached".to_owned(),
+        ServerNoticeType::UsageLimitReached,
+    );
+    server_notice.admin_contact = Some("mailto:admin@example.org".to_owned());
+    server_notice.limit_type = Some(LimitType::MonthlyActiveUser);
+    let content = RoomMessageEventContent::new(MessageType::ServerNotice(server_notice));
+
+    assert_eq!(
+        to_json_value(&content).unwrap(),
+        json!({
+            "msgtype": "m.server_notice",
+            "body": "Monthly active users limit reached",
+            "server_notice_type": "m.server_notice.usage_limit_reached",
+            "admin_contact": "mailto:admin@example.org",
+            "limit_type": "monthly_active_user",
+        })
+    );
+}
+
+#[test]
+fn server_notice_msgtype_deserialization() {
+    let json_data = json!({
+        "msgtype": "m.server_notice",
+        "body": "Something happened",
+        "server_notice_type": "com.example.notice",
+    });
+
+    let content = from_json_value::<RoomMessageEventContent>(json_data).unwrap();
+    assert_eq!(content.msgtype(), "m.server_notice");
+    assert_matches!(content.msgtype, MessageType::ServerNotice(server_notice));
+    assert_eq!(server_notice.body, "Something happened");
+    assert_eq!(server_notice.server_notice_type.as_str(), "com.example.notice");
+    assert_eq!(server_notice.admin_contact, None);
+    assert_eq!(server_notice.limit_type, None);
+}
+
+#[test]
+fn text_msgtype_invalid_formatted_body_deserialization() {
+    let content = from_json_value::<RoomMessageEventContent>(json!({
+        "body": "Hello",
+        "format": "org.matrix.custom.html",
+        "formatted_body": { "html": "<b>Hello</b>" },
+        "msgtype": "m.text",
+    }))
+    .unwrap();
+    assert_matches!(content.msgtype, MessageType::Text(text));
+    assert_eq!(text.body, "Hello");
+    assert_matches!(text.formatted, None);
+
+    let content = from_json_value::<RoomMessageEventContent>(json!({
+        "body": "Hello",
+        "format": 1,
+        "formatted_body": "<b>Hello</b>",
+        "msgtype": "m.notice",
+    }))
+    .unwrap();
+    assert_matches!(content.msgtype, MessageType::Notice(notice));
+    assert_matches!(notice.formatted, None);
+}
+
 #[test]
 fn text_msgtype_formatted_body_serialization() {
     let message_event_content =
@@ -188,6 +272,43 @@ line 2
     );
 }
 
+#[test]
+#[cfg(feature = "html")]
+fn sanitize_formatted_body() {
+    use ruma_events::room::message::FormattedBody;
+    use ruma_html::{HtmlSanitizerMode, RemoveReplyFallback};
+
+    let mut content = RoomMessageEventContent::text_html(
+        "> <@alice:example.org> Hello\n\nReply with a spoiler and an image",
+        "<mx-reply><blockquote>Hello</blockquote></mx-reply>\
+        <span data-mx-spoiler=\"plot\" onclick=\"steal()\">Reply</span> with \
+        <font data-mx-color=\"#ff0000\">a spoiler</font> and an image\
+        <img src=\"https://example.org/image.png\"><img src=\"mxc://example.org/image\">\
+        <script>steal()</script>",
+    );
+    content.msgtype.sanitize(HtmlSanitizerMode::Strict, RemoveReplyFallback::Yes);
+
+    assert_matches!(
+        content.msgtype,
+        MessageType::Text(TextMessageEventContent { body, formatted, .. })
+    );
+    assert_eq!(body, "Reply with a spoiler and an image");
+    // Disallowed elements are removed but their children are kept, and deprecated elements are
+    // replaced.
+    assert_eq!(
+        formatted.unwrap().body,
+        "<span data-mx-spoiler=\"plot\">Reply</span> with \
+        <span data-mx-color=\"#ff0000\">a spoiler</span> and an image\
+        <img src=\"mxc://example.org/image\">steal()"
+    );
+
+    // Plain text bodies are not modified.
+    let mut formatted =
+        FormattedBody { format: "io.ruma.plain".into(), body: "<script>".to_owned() };
+    formatted.sanitize_html(HtmlSanitizerMode::Strict, RemoveReplyFallback::Yes);
+    assert_eq!(formatted.body, "<script>");
+}
+
 #[test]
 #[cfg(feature = "markdown")]
 fn markdown_detection() {
@@ -235,6 +356,75 @@ fn markdown_options() {
     assert_eq!(formatted_body.unwrap().body, "<p>A message with a <del>strike</del></p>\n");
 }
 
+#[test]
+#[cfg(feature = "markdown")]
+fn markdown_code_blocks_and_links() {
+    use ruma_events::room::message::FormattedBody;
+
+    let formatted_body = FormattedBody::markdown("```rust\nlet a = 1 < 2;\n```");
+    assert_eq!(
+        formatted_body.unwrap().body,
+        "<pre><code class=\"language-rust\">let a = 1 &lt; 2;\n</code></pre>\n"
+    );
+
+    let formatted_body = FormattedBody::markdown("Use `cargo test`, see [ruma](https://ruma.dev)");
+    assert_eq!(
+        formatted_body.unwrap().body,
+        "<p>Use <code>cargo test</code>, see <a href=\"https://ruma.dev\">ruma</a></p>\n"
+    );
+
+    // Autolinks are kept, `mailto:` is added to email addresses.
+    let formatted_body =
+        FormattedBody::markdown("Mail <alice@example.org> or see <https://ruma.dev>");
+    assert_eq!(
+        formatted_body.unwrap().body,
+        "<p>Mail <a href=\"mailto:alice@example.org\">alice@example.org</a> or see \
+         <a href=\"https://ruma.dev\">https://ruma.dev</a></p>\n"
+    );
+
+    // Relative links and anchors are kept.
+    let formatted_body = FormattedBody::markdown("See [the docs](/docs) or [below](#below)");
+    assert_eq!(
+        formatted_body.unwrap().body,
+        "<p>See <a href=\"/docs\">the docs</a> or <a href=\"#below\">below</a></p>\n"
+    );
+
+    // Links with disallowed schemes are replaced by their text.
+    let formatted_body = FormattedBody::markdown("A **[link](javascript:alert(1))**");
+    assert_eq!(formatted_body.unwrap().body, "<p>A <strong>link</strong></p>\n");
+
+    // Images that are not hosted on the homeserver are replaced by their alt text.
+    let formatted_body = FormattedBody::markdown("An ![image](https://ruma.dev/logo.png) _here_");
+    assert_eq!(formatted_body.unwrap().body, "<p>An image <em>here</em></p>\n");
+}
+
+#[test]
+#[cfg(feature = "markdown")]
+fn markdown_disallowed_html() {
+    use ruma_events::room::message::FormattedBody;
+
+    // Allowed elements are kept.
+    let formatted_body = FormattedBody::markdown("Some <span>inline</span> HTML");
+    assert_eq!(formatted_body.unwrap().body, "<p>Some <span>inline</span> HTML</p>\n");
+
+    // Disallowed elements are escaped.
+    let formatted_body = FormattedBody::markdown("Hi <script>alert(1)</script> **there**");
+    assert_eq!(
+        formatted_body.unwrap().body,
+        "<p>Hi &lt;script&gt;alert(1)&lt;/script&gt; <strong>there</strong></p>\n"
+    );
+
+    let formatted_body = FormattedBody::markdown("<iframe src=\"https://ruma.dev\"></iframe>");
+    assert_eq!(
+        formatted_body.unwrap().body,
+        "&lt;iframe src=\"https://ruma.dev\"&gt;&lt;/iframe&gt;"
+    );
+
+    // Allowed elements with attributes are escaped.
+    let formatted_body = FormattedBody::markdown("A <span onclick=\"alert(1)\">span");
+    assert_eq!(formatted_body.unwrap().body, "<p>A &lt;span onclick=\"alert(1)\"&gt;span</p>\n");
+}
+
 #[test]
 fn verification_request_msgtype_deserialization() {
     let user_id = user_id!("@example2:localhost");
@@ -871,6 +1061,96 @@ fn make_replacement_with_reply() {
     assert_matches!(content.mentions, None);
 }
 
+#[test]
+fn make_replacement_of_replacement() {
+    let first_edit_json = json!({
+        "content": {
+            "body": "* Hello, World!",
+            "msgtype": "m.text",
+            "m.new_content": {
+                "body": "Hello, World!",
+                "msgtype": "m.text",
+                "m.mentions": {
+                    "user_ids": ["@alice:notareal.hs"],
+                },
+            },
+            "m.relates_to": {
+                "rel_type": "m.replace",
+                "event_id": "$original_event",
+            },
+        },
+        "event_id": "$first_edit",
+        "origin_server_ts": 134_829_848,
+        "room_id": "!roomid:notareal.hs",
+        "sender": "@user:notareal.hs",
+        "type": "m.room.message",
+    });
+    let first_edit: OriginalSyncRoomMessageEvent = from_json_value(first_edit_json).unwrap();
+
+    let content = RoomMessageEventContent::text_plain("Hello, Matrix!")
+        .add_mentions(Mentions::with_user_ids([
+            owned_user_id!("@alice:notareal.hs"),
+            owned_user_id!("@bob:notareal.hs"),
+        ]))
+        .make_replacement(&first_edit, None);
+
+    // The replacement relates to the original event, not to the first edit.
+    assert_matches!(content.relates_to, Some(Relation::Replacement(replacement)));
+    assert_eq!(replacement.event_id, "$original_event");
+    assert_matches!(replacement.new_content.msgtype, MessageType::Text(new_text));
+    assert_eq!(new_text.body, "Hello, Matrix!");
+
+    // Only the mentions that were not in the first edit are in the fallback.
+    assert_eq!(
+        content.mentions.unwrap().user_ids,
+        BTreeSet::from([owned_user_id!("@bob:notareal.hs")])
+    );
+}
+
+#[test]
+fn make_replacement_with_different_msgtype() {
+    let original_message_json = json!({
+        "content": {
+            "body": "Hello, World!",
+            "msgtype": "m.text",
+        },
+        "event_id": "$143273582443PhrSn",
+        "origin_server_ts": 134_829_848,
+        "room_id": "!roomid:notareal.hs",
+        "sender": "@user:notareal.hs",
+        "type": "m.room.message",
+    });
+    let original_message: OriginalSyncRoomMessageEvent =
+        from_json_value(original_message_json).unwrap();
+
+    let content = RoomMessageEventContent::notice_plain("Hello, World!")
+        .make_replacement(&original_message, None);
+
+    assert_matches!(&content.msgtype, MessageType::Notice(notice));
+    assert_eq!(notice.body, "* Hello, World!");
+    assert_matches!(&content.relates_to, Some(Relation::Replacement(replacement)));
+    assert_matches!(&replacement.new_content.msgtype, MessageType::Notice(new_notice));
+    assert_eq!(new_notice.body, "Hello, World!");
+
+    assert_eq!(
+        to_json_value(&content).unwrap(),
+        json!({
+            "body": "* Hello, World!",
+            "format": "org.matrix.custom.html",
+            "formatted_body": "* ",
+            "msgtype": "m.notice",
+            "m.new_content": {
+                "body": "Hello, World!",
+                "msgtype": "m.notice",
+            },
+            "m.relates_to": {
+                "rel_type": "m.replace",
+                "event_id": "$143273582443PhrSn",
+            },
+        })
+    );
+}
+
 #[test]
 fn audio_msgtype_serialization() {
     let message_event_content =
@@ -904,6 +1184,37 @@ fn audio_msgtype_deserialization() {
     assert_eq!(url, "mxc://notareal.hs/file");
 }
 
+#[cfg(feature = "unstable-msc3245-v1-compat")]
+#[test]
+fn voice_msgtype_v1_compat_roundtrip() {
+    use std::time::Duration;
+
+    let json_data = json!({
+        "body": "Voice message",
+        "url": "mxc://notareal.hs/file",
+        "msgtype": "m.audio",
+        "info": {
+            "duration": 5300,
+            "mimetype": "audio/ogg",
+        },
+        "org.matrix.msc1767.audio": {
+            "duration": 5300,
+            "waveform": [0, 256, 1024],
+        },
+        "org.matrix.msc3245.voice": {},
+    });
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data.clone()).unwrap();
+    assert_matches!(&event_content.msgtype, MessageType::Audio(content));
+    assert!(content.is_voice_message());
+    let audio = content.audio.as_ref().unwrap();
+    assert_eq!(audio.duration, Duration::from_millis(5300));
+    assert_eq!(audio.waveform.len(), 3);
+    assert_eq!(audio.waveform[2].get(), uint!(1024));
+
+    assert_eq!(to_json_value(&event_content).unwrap(), json_data);
+}
+
 #[test]
 fn file_msgtype_plain_content_serialization() {
     let message_event_content =
@@ -1049,6 +1360,60 @@ fn image_msgtype_deserialization() {
     assert_eq!(url, "mxc://notareal.hs/file");
 }
 
+#[cfg(feature = "unstable-msc2448")]
+#[test]
+fn image_msgtype_blurhash_roundtrip() {
+    let json_data = json!({
+        "body": "Upload: my_image.jpg",
+        "url": "mxc://notareal.hs/file",
+        "msgtype": "m.image",
+        "info": {
+            "xyz.amorgan.blurhash": "LEHV6nWB2yk8pyo0adR*.7kCMdnj",
+            "thumbnail_url": "mxc://notareal.hs/thumbnail",
+            "thumbnail_info": {
+                "xyz.amorgan.blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH",
+            },
+        },
+    });
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data.clone()).unwrap();
+    assert_matches!(&event_content.msgtype, MessageType::Image(content));
+    let info = content.info.as_ref().unwrap();
+    assert_eq!(info.blurhash.as_deref(), Some("LEHV6nWB2yk8pyo0adR*.7kCMdnj"));
+    assert_eq!(
+        info.thumbnail_info.as_ref().unwrap().blurhash.as_deref(),
+        Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH")
+    );
+
+    assert_eq!(to_json_value(&event_content).unwrap(), json_data);
+}
+
+#[cfg(feature = "unstable-msc2448")]
+#[test]
+fn image_msgtype_stable_blurhash_deserialization() {
+    let json_data = json!({
+        "body": "Upload: my_image.jpg",
+        "url": "mxc://notareal.hs/file",
+        "msgtype": "m.image",
+        "info": {
+            "blurhash": "LEHV6nWB2yk8pyo0adR*.7kCMdnj",
+            "thumbnail_url": "mxc://notareal.hs/thumbnail",
+            "thumbnail_info": {
+                "blurhash": "LKO2?U%2Tw=w]~RBVZRi};RPxuwH",
+            },
+        },
+    });
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::Image(content));
+    let info = content.info.unwrap();
+    assert_eq!(info.blurhash.as_deref(), Some("LEHV6nWB2yk8pyo0adR*.7kCMdnj"));
+    assert_eq!(
+        info.thumbnail_info.unwrap().blurhash.as_deref(),
+        Some("LKO2?U%2Tw=w]~RBVZRi};RPxuwH")
+    );
+}
+
 #[cfg(not(feature = "unstable-msc3488"))]
 #[test]
 fn location_msgtype_serialization() {
@@ -1082,6 +1447,28 @@ fn location_msgtype_deserialization() {
     assert_matches!(event_content.msgtype, MessageType::Location(content));
     assert_eq!(content.body, "Alice was at geo:51.5008,0.1247;u=35");
     assert_eq!(content.geo_uri, "geo:51.5008,0.1247;u=35");
+
+    let geo_uri = content.parsed_geo_uri().unwrap();
+    assert_eq!(geo_uri.latitude(), 51.5008);
+    assert_eq!(geo_uri.longitude(), 0.1247);
+    assert_eq!(geo_uri.uncertainty(), Some(35.0));
+}
+
+#[test]
+fn location_msgtype_malformed_geo_uri() {
+    let json_data = json!({
+        "body": "Alice was somewhere",
+        "geo_uri": "geo:somewhere",
+        "msgtype": "m.location",
+    });
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data.clone()).unwrap();
+    assert_matches!(&event_content.msgtype, MessageType::Location(content));
+    assert_eq!(content.geo_uri, "geo:somewhere");
+    content.parsed_geo_uri().unwrap_err();
+
+    #[cfg(not(feature = "unstable-msc3488"))]
+    assert_eq!(to_json_value(&event_content).unwrap(), json_data);
 }
 
 #[test]
@@ -1206,6 +1593,77 @@ fn video_msgtype_deserialization() {
     assert_eq!(url, "mxc://notareal.hs/file");
 }
 
+#[test]
+fn video_msgtype_encrypted_thumbnail_serde() {
+    let encrypted_file_json = |url: &str| {
+        json!({
+            "url": url,
+            "key": {
+                "kty": "oct",
+                "key_ops": ["encrypt", "decrypt"],
+                "alg": "A256CTR",
+                "k": "TLlG_OpX807zzQuuwv4QZGJ21_u7weemFGYJFszMn9A",
+                "ext": true
+            },
+            "iv": "S22dq3NAX8wAAAAAAAAAAA",
+            "hashes": {
+                "sha256": "aWOHudBnDkJ9IwaR1Nd8XKoI7DOrqDTwt6xDPfVGN6Q"
+            },
+            "v": "v2",
+        })
+    };
+    let encrypted_file =
+        |url: &str| from_json_value::<EncryptedFile>(encrypted_file_json(url)).unwrap();
+
+    let mut info = VideoInfo::new();
+    info.thumbnail_source =
+        Some(MediaSource::Encrypted(Box::new(encrypted_file("mxc://notareal.hs/thumb"))));
+    let content = VideoMessageEventContent::encrypted(
+        "Upload: my_video.mp4".to_owned(),
+        encrypted_file("mxc://notareal.hs/file"),
+    )
+    .info(Box::new(info));
+
+    let json_data = json!({
+        "body": "Upload: my_video.mp4",
+        "file": encrypted_file_json("mxc://notareal.hs/file"),
+        "info": {
+            "thumbnail_file": encrypted_file_json("mxc://notareal.hs/thumb"),
+        },
+        "msgtype": "m.video",
+    });
+    assert_eq!(
+        to_json_value(RoomMessageEventContent::new(MessageType::Video(content))).unwrap(),
+        json_data
+    );
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::Video(content));
+    assert_matches!(content.source, MediaSource::Encrypted(file));
+    assert_eq!(file.url, "mxc://notareal.hs/file");
+    assert_matches!(content.info.unwrap().thumbnail_source, Some(MediaSource::Encrypted(file)));
+    assert_eq!(file.url, "mxc://notareal.hs/thumb");
+
+    // When both the plain and encrypted sources are set, the encrypted one is used.
+    let json_data = json!({
+        "body": "Upload: my_video.mp4",
+        "url": "mxc://notareal.hs/plain",
+        "file": encrypted_file_json("mxc://notareal.hs/file"),
+        "info": {
+            "thumbnail_url": "mxc://notareal.hs/plain_thumb",
+            "thumbnail_file": encrypted_file_json("mxc://notareal.hs/thumb"),
+        },
+        "msgtype": "m.video",
+    });
+
+    let event_content = from_json_value::<RoomMessageEventContent>(json_data).unwrap();
+    assert_matches!(event_content.msgtype, MessageType::Video(content));
+    assert_matches!(content.source, MediaSource::Encrypted(file));
+    assert_eq!(file.url, "mxc://notareal.hs/file");
+    assert_matches!(content.info.unwrap().thumbnail_source, Some(MediaSource::Encrypted(file)));
+    assert_eq!(file.url, "mxc://notareal.hs/thumb");
+}
+
 #[test]
 #[allow(deprecated)]
 fn set_mentions() {
@@ -1288,6 +1746,35 @@ fn make_replacement_then_add_mentions() {
     assert!(replacement.new_content.mentions.is_none());
 }
 
+#[test]
+fn mentions_serialization() {
+    // Empty mentions must still be serialized, to disable mentions based on the body.
+    let content = RoomMessageEventContent::text_plain("no one").add_mentions(Mentions::new());
+    assert_eq!(
+        to_json_value(&content).unwrap(),
+        json!({
+            "body": "no one",
+            "msgtype": "m.text",
+            "m.mentions": {},
+        })
+    );
+
+    let mut mentions = Mentions::with_user_ids([owned_user_id!("@alice:localhost")]);
+    mentions.room = true;
+    let content = RoomMessageEventContent::text_plain("everyone!").add_mentions(mentions);
+    assert_eq!(
+        to_json_value(&content).unwrap(),
+        json!({
+            "body": "everyone!",
+            "msgtype": "m.text",
+            "m.mentions": {
+                "user_ids": ["@alice:localhost"],
+                "room": true,
+            },
+        })
+    );
+}
+
 #[test]
 fn mentions_room_deserialization() {
     let json_data = json!({
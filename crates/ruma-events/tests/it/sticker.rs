@@ -1,9 +1,16 @@
 use assert_matches2::assert_matches;
 use assign::assign;
 use js_int::{uint, UInt};
-use ruma_common::{mxc_uri, serde::CanBeEmpty, MilliSecondsSinceUnixEpoch};
+use ruma_common::{
+    mxc_uri,
+    serde::{Base64, CanBeEmpty},
+    MilliSecondsSinceUnixEpoch,
+};
 use ruma_events::{
-    room::{ImageInfo, MediaSource, ThumbnailInfo},
+    room::{
+        message::ImageMessageEventContent, EncryptedFileInit, ImageInfo, JsonWebKeyInit,
+        MediaSource, ThumbnailInfo,
+    },
     sticker::{StickerEventContent, StickerMediaSource},
     AnyMessageLikeEvent, MessageLikeEvent,
 };
@@ -174,3 +181,80 @@ fn event_deserialization() {
     assert_eq!(thumbnail_info.mimetype.as_deref(), Some("image/png"));
     assert_eq!(thumbnail_info.size, Some(uint!(82595)));
 }
+
+#[test]
+fn image_to_sticker() {
+    let image = ImageMessageEventContent::plain(
+        "my_image.jpg".to_owned(),
+        mxc_uri!("mxc://notareal.hs/file").to_owned(),
+    )
+    .info(Box::new(assign!(ImageInfo::new(), { height: UInt::new(423) })));
+
+    let sticker = StickerEventContent::try_from(image).unwrap();
+    assert_eq!(sticker.body, "my_image.jpg");
+    assert_eq!(sticker.info.height, UInt::new(423));
+    assert_matches!(sticker.source, StickerMediaSource::Plain(url));
+    assert_eq!(url, "mxc://notareal.hs/file");
+
+    // Missing info is replaced by an empty one.
+    let image = ImageMessageEventContent::plain(
+        "my_image.jpg".to_owned(),
+        mxc_uri!("mxc://notareal.hs/file").to_owned(),
+    );
+    let sticker = StickerEventContent::try_from(image).unwrap();
+    assert_eq!(sticker.info.height, None);
+}
+
+#[test]
+fn encrypted_image_to_sticker() {
+    let image = ImageMessageEventContent::encrypted(
+        "my_image.jpg".to_owned(),
+        EncryptedFileInit {
+            url: mxc_uri!("mxc://notareal.hs/file").to_owned(),
+            key: JsonWebKeyInit {
+                kty: "oct".to_owned(),
+                key_ops: vec!["encrypt".to_owned(), "decrypt".to_owned()],
+                alg: "A256CTR".to_owned(),
+                k: Base64::parse("TLlG_OpX807zzQuuwv4QZGJ21_u7weemFGYJFszMn9A").unwrap(),
+                ext: true,
+            }
+            .into(),
+            iv: Base64::parse("S22dq3NAX8wAAAAAAAAAAA").unwrap(),
+            hashes: [(
+                "sha256".to_owned(),
+                Base64::parse("aWOHudBnDkJ9IwaR1Nd8XKoI7DOrqDTwt6xDPfVGN6Q").unwrap(),
+            )]
+            .into(),
+            v: "v2".to_owned(),
+        }
+        .into(),
+    );
+
+    #[cfg(not(feature = "compat-encrypted-stickers"))]
+    {
+        let image = StickerEventContent::try_from(image).unwrap_err();
+        assert_matches!(image.source, MediaSource::Encrypted(_));
+    }
+    #[cfg(feature = "compat-encrypted-stickers")]
+    {
+        let sticker = StickerEventContent::try_from(image).unwrap();
+        assert_matches!(sticker.source, StickerMediaSource::Encrypted(file));
+        assert_eq!(file.url, "mxc://notareal.hs/file");
+    }
+}
+
+#[test]
+fn sticker_to_image() {
+    let sticker = StickerEventContent::new(
+        "Hello".to_owned(),
+        assign!(ImageInfo::new(), { mimetype: Some("image/png".to_owned()) }),
+        mxc_uri!("mxc://notareal.hs/file").to_owned(),
+    );
+
+    let image = ImageMessageEventContent::from(sticker);
+    assert_eq!(image.body, "Hello");
+    assert_eq!(image.filename, None);
+    assert_eq!(image.info.unwrap().mimetype.as_deref(), Some("image/png"));
+    assert_matches!(image.source, MediaSource::Plain(url));
+    assert_eq!(url, "mxc://notareal.hs/file");
+}
@@ -2,8 +2,13 @@ use assert_matches2::assert_matches;
 use js_int::uint;
 use ruma_common::mxc_uri;
 use ruma_events::{
-    room::{join_rules::JoinRule, topic::RoomTopicEventContent},
-    AnyStrippedStateEvent,
+    room::{
+        join_rules::JoinRule,
+        member::{MembershipState, RoomMemberEventContent},
+        name::RoomNameEventContent,
+        topic::RoomTopicEventContent,
+    },
+    AnyStrippedStateEvent, OriginalSyncStateEvent, StateEventType, StrippedStateEvent,
 };
 use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
@@ -82,3 +87,59 @@ fn deserialize_stripped_state_events() {
     assert_eq!(image_info.size, Some(uint!(1024)));
     assert_eq!(image_info.thumbnail_info.unwrap().size, Some(uint!(32)));
 }
+
+#[test]
+fn stripped_state_event_accessors() {
+    let ev = from_json_value::<AnyStrippedStateEvent>(json!({
+        "type": "m.room.member",
+        "state_key": "@bob:localhost",
+        "sender": "@alice:localhost",
+        "content": { "membership": "invite" }
+    }))
+    .unwrap();
+
+    assert_eq!(ev.event_type(), StateEventType::RoomMember);
+    assert_eq!(ev.state_key(), "@bob:localhost");
+    assert_eq!(ev.sender(), "@alice:localhost");
+}
+
+#[test]
+fn sync_state_event_into_stripped() {
+    let ev = from_json_value::<OriginalSyncStateEvent<RoomNameEventContent>>(json!({
+        "type": "m.room.name",
+        "state_key": "",
+        "sender": "@example:localhost",
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "unsigned": { "age": 1234 },
+        "content": { "name": "Ruma" }
+    }))
+    .unwrap();
+
+    let stripped = StrippedStateEvent::from(ev);
+    assert_eq!(stripped.content.name.as_deref(), Some("Ruma"));
+    assert_eq!(stripped.sender, "@example:localhost");
+    assert_eq!(
+        to_json_value(&stripped).unwrap(),
+        json!({
+            "type": "m.room.name",
+            "state_key": "",
+            "sender": "@example:localhost",
+            "content": { "name": "Ruma" }
+        })
+    );
+
+    let ev = from_json_value::<OriginalSyncStateEvent<RoomMemberEventContent>>(json!({
+        "type": "m.room.member",
+        "state_key": "@bob:localhost",
+        "sender": "@alice:localhost",
+        "event_id": "$h29iv0s8:example.com",
+        "origin_server_ts": 1,
+        "content": { "membership": "invite" }
+    }))
+    .unwrap();
+
+    let stripped = StrippedStateEvent::from(ev);
+    assert_eq!(stripped.content.membership, MembershipState::Invite);
+    assert_eq!(stripped.state_key, "@bob:localhost");
+}
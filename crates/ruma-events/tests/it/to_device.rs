@@ -1,6 +1,11 @@
+use assert_matches2::assert_matches;
+use js_int::uint;
 use ruma_common::{owned_room_id, EventEncryptionAlgorithm};
-use ruma_events::room_key::ToDeviceRoomKeyEventContent;
-use serde_json::{json, to_value as to_json_value};
+use ruma_events::{
+    room::encrypted::EncryptedEventScheme, room_key::ToDeviceRoomKeyEventContent,
+    room_key_request::Action, AnyToDeviceEvent,
+};
+use serde_json::{from_value as from_json_value, json, to_value as to_json_value};
 
 #[test]
 fn serialization() {
@@ -21,3 +26,152 @@ fn serialization() {
         })
     );
 }
+
+#[test]
+fn deserialize_dummy() {
+    let json = json!({
+        "content": {},
+        "sender": "@alice:example.org",
+        "type": "m.dummy",
+    });
+
+    assert_matches!(from_json_value::<AnyToDeviceEvent>(json), Ok(AnyToDeviceEvent::Dummy(ev)));
+    assert_eq!(ev.sender, "@alice:example.org");
+}
+
+#[test]
+fn deserialize_room_key() {
+    let json = json!({
+        "content": {
+            "algorithm": "m.megolm.v1.aes-sha2",
+            "room_id": "!testroomid:example.org",
+            "session_id": "SessId",
+            "session_key": "SessKey",
+        },
+        "sender": "@alice:example.org",
+        "type": "m.room_key",
+    });
+
+    assert_matches!(from_json_value::<AnyToDeviceEvent>(json), Ok(AnyToDeviceEvent::RoomKey(ev)));
+    assert_eq!(ev.sender, "@alice:example.org");
+    assert_eq!(ev.content.algorithm, EventEncryptionAlgorithm::MegolmV1AesSha2);
+    assert_eq!(ev.content.room_id, "!testroomid:example.org");
+    assert_eq!(ev.content.session_id, "SessId");
+    assert_eq!(ev.content.session_key, "SessKey");
+}
+
+#[test]
+fn deserialize_room_key_request() {
+    let json = json!({
+        "content": {
+            "action": "request",
+            "body": {
+                "algorithm": "m.megolm.v1.aes-sha2",
+                "room_id": "!testroomid:example.org",
+                "sender_key": "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU",
+                "session_id": "X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ",
+            },
+            "request_id": "1495474790150.19",
+            "requesting_device_id": "RJYKSTBOIE",
+        },
+        "sender": "@alice:example.org",
+        "type": "m.room_key_request",
+    });
+
+    assert_matches!(
+        from_json_value::<AnyToDeviceEvent>(json),
+        Ok(AnyToDeviceEvent::RoomKeyRequest(ev))
+    );
+    assert_eq!(ev.sender, "@alice:example.org");
+    assert_eq!(ev.content.action, Action::Request);
+    assert_eq!(ev.content.request_id, "1495474790150.19");
+    assert_eq!(ev.content.requesting_device_id, "RJYKSTBOIE");
+    let body = ev.content.body.unwrap();
+    assert_eq!(body.algorithm, EventEncryptionAlgorithm::MegolmV1AesSha2);
+    assert_eq!(body.room_id, "!testroomid:example.org");
+    assert_eq!(body.session_id, "X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ");
+
+    let json = json!({
+        "content": {
+            "action": "request_cancellation",
+            "request_id": "1495474790150.19",
+            "requesting_device_id": "RJYKSTBOIE",
+        },
+        "sender": "@alice:example.org",
+        "type": "m.room_key_request",
+    });
+
+    assert_matches!(
+        from_json_value::<AnyToDeviceEvent>(json),
+        Ok(AnyToDeviceEvent::RoomKeyRequest(ev))
+    );
+    assert_eq!(ev.content.action, Action::CancelRequest);
+    assert!(ev.content.body.is_none());
+}
+
+#[test]
+fn deserialize_forwarded_room_key() {
+    let json = json!({
+        "content": {
+            "algorithm": "m.megolm.v1.aes-sha2",
+            "forwarding_curve25519_key_chain": [
+                "hPQNcabIABgGnx3/ACv/jmMmiQHoeFfuLB17tzWp6Hw",
+            ],
+            "room_id": "!testroomid:example.org",
+            "sender_claimed_ed25519_key": "aj40p+aw64yPIdsxoog8jhPu9i7l7NcFRecuOQblE3Y",
+            "sender_key": "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU",
+            "session_id": "X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ",
+            "session_key": "AgAAAADxKHa9uFxcXzwYoNueL5Xqi69IkD4sni8Llf...",
+        },
+        "sender": "@alice:example.org",
+        "type": "m.forwarded_room_key",
+    });
+
+    assert_matches!(
+        from_json_value::<AnyToDeviceEvent>(json),
+        Ok(AnyToDeviceEvent::ForwardedRoomKey(ev))
+    );
+    assert_eq!(ev.sender, "@alice:example.org");
+    assert_eq!(ev.content.algorithm, EventEncryptionAlgorithm::MegolmV1AesSha2);
+    assert_eq!(
+        ev.content.forwarding_curve25519_key_chain,
+        ["hPQNcabIABgGnx3/ACv/jmMmiQHoeFfuLB17tzWp6Hw"]
+    );
+    assert_eq!(ev.content.room_id, "!testroomid:example.org");
+    assert_eq!(
+        ev.content.sender_claimed_ed25519_key,
+        "aj40p+aw64yPIdsxoog8jhPu9i7l7NcFRecuOQblE3Y"
+    );
+    assert_eq!(ev.content.sender_key, "RF3s+E7RkTQTGF2d8Deol0FkQvgII2aJDf3/Jp5mxVU");
+    assert_eq!(ev.content.session_id, "X3lUlvLELLYxeTx4yOVu6UDpasGEVO0Jbu+QFnm0cKQ");
+    assert_eq!(ev.content.session_key, "AgAAAADxKHa9uFxcXzwYoNueL5Xqi69IkD4sni8Llf...");
+}
+
+#[test]
+fn deserialize_olm_encrypted() {
+    let json = json!({
+        "content": {
+            "algorithm": "m.olm.v1.curve25519-aes-sha2",
+            "ciphertext": {
+                "7qZcfnBmbEGzxxaWfBjElJuvn7BZx+hd6BbAu/bjWzQ": {
+                    "body": "encrypted_body",
+                    "type": 0,
+                },
+            },
+            "sender_key": "Szl29ksW/L8yZGWAX+8dY1XyFi+i5wm+DRhTGkbMiwU",
+        },
+        "sender": "@alice:example.org",
+        "type": "m.room.encrypted",
+    });
+
+    assert_matches!(
+        from_json_value::<AnyToDeviceEvent>(json),
+        Ok(AnyToDeviceEvent::RoomEncrypted(ev))
+    );
+    assert_eq!(ev.sender, "@alice:example.org");
+    assert_matches!(ev.content.scheme, EncryptedEventScheme::OlmV1Curve25519AesSha2(content));
+    assert_eq!(content.sender_key, "Szl29ksW/L8yZGWAX+8dY1XyFi+i5wm+DRhTGkbMiwU");
+    let ciphertext = &content.ciphertext["7qZcfnBmbEGzxxaWfBjElJuvn7BZx+hd6BbAu/bjWzQ"];
+    assert_eq!(ciphertext.body, "encrypted_body");
+    assert_eq!(ciphertext.message_type, uint!(0));
+}
@@ -76,4 +76,81 @@ pub mod v1 {
             Self { origin, origin_server_ts, pdus }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use js_int::uint;
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_event_id, owned_room_id,
+            };
+
+            let req = super::Request::new(
+                owned_room_id!("!room:example.org"),
+                vec![owned_event_id!("$first:example.org"), owned_event_id!("$second:example.org")],
+                uint!(20),
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://example.org",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/federation/v1/backfill/!room:example.org");
+            assert_eq!(
+                req.uri().query(),
+                Some("v=%24first%3Aexample.org&v=%24second%3Aexample.org&limit=20")
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use js_int::uint;
+            use ruma_common::api::IncomingRequest as _;
+
+            let req = super::Request::try_from_http_request(
+                http::Request::get(
+                    "https://example.org/_matrix/federation/v1/backfill/!room:example.org\
+                     ?v=%24first%3Aexample.org&limit=5&v=%24second%3Aexample.org",
+                )
+                .body(&[] as &[u8])
+                .unwrap(),
+                &["!room:example.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!room:example.org");
+            assert_eq!(req.v, ["$first:example.org", "$second:example.org"]);
+            assert_eq!(req.limit, uint!(5));
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::api::IncomingResponse as _;
+            use serde_json::{json, to_vec as to_json_vec};
+
+            let body = json!({
+                "origin": "example.org",
+                "origin_server_ts": 1_234_567_890,
+                "pdus": [
+                    { "type": "m.room.message", "room_id": "!room:example.org" },
+                ],
+            });
+
+            let res = super::Response::try_from_http_response(http::Response::new(
+                to_json_vec(&body).unwrap(),
+            ))
+            .unwrap();
+
+            assert_eq!(res.origin, "example.org");
+            assert_eq!(res.origin_server_ts.get(), js_int::uint!(1_234_567_890));
+            assert_eq!(res.pdus.len(), 1);
+        }
+    }
 }
@@ -90,4 +90,88 @@ pub mod v1 {
     fn is_default_limit(val: &UInt) -> bool {
         *val == default_limit()
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use js_int::uint;
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_event_id, owned_room_id,
+            };
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            let mut req = super::Request::new(
+                owned_room_id!("!room:example.org"),
+                vec![owned_event_id!("$earliest:example.org")],
+                vec![owned_event_id!("$latest:example.org")],
+            );
+
+            let http_req = req
+                .clone()
+                .try_into_http_request::<Vec<u8>>(
+                    "https://example.org",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                http_req.uri().path(),
+                "/_matrix/federation/v1/get_missing_events/!room:example.org"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({
+                    "earliest_events": ["$earliest:example.org"],
+                    "latest_events": ["$latest:example.org"],
+                })
+            );
+
+            req.limit = uint!(50);
+            req.min_depth = uint!(3);
+            let http_req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://example.org",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+            assert_eq!(
+                from_json_slice::<JsonValue>(http_req.body()).unwrap(),
+                json!({
+                    "earliest_events": ["$earliest:example.org"],
+                    "latest_events": ["$latest:example.org"],
+                    "limit": 50,
+                    "min_depth": 3,
+                })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request_with_defaults() {
+            use js_int::uint;
+            use ruma_common::api::IncomingRequest as _;
+
+            let req = super::Request::try_from_http_request(
+                http::Request::post(
+                    "https://example.org/_matrix/federation/v1/get_missing_events/!room:example.org",
+                )
+                .body(
+                    br#"{"earliest_events":["$earliest:example.org"],"latest_events":["$latest:example.org"]}"#
+                        .as_slice(),
+                )
+                .unwrap(),
+                &["!room:example.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.limit, uint!(10));
+            assert_eq!(req.min_depth, uint!(0));
+            assert_eq!(req.earliest_events, ["$earliest:example.org"]);
+            assert_eq!(req.latest_events, ["$latest:example.org"]);
+        }
+    }
 }
@@ -34,9 +34,9 @@ pub mod v1 {
         #[ruma_api(path)]
         pub user_id: OwnedUserId,
 
-        /// The room versions the sending has support for.
+        /// The room versions the sending server has support for.
         ///
-        /// Defaults to `vec![RoomVersionId::V1]`.
+        /// Knocking is only supported since room version 7, so this should not be left empty.
         #[ruma_api(query)]
         pub ver: Vec<RoomVersionId>,
     }
@@ -54,9 +54,9 @@ pub mod v1 {
     }
 
     impl Request {
-        /// Creates a `Request` with the given room ID and user ID.
-        pub fn new(room_id: OwnedRoomId, user_id: OwnedUserId) -> Self {
-            Self { room_id, user_id, ver: vec![RoomVersionId::V1] }
+        /// Creates a `Request` with the given room ID, user ID and supported room versions.
+        pub fn new(room_id: OwnedRoomId, user_id: OwnedUserId, ver: Vec<RoomVersionId>) -> Self {
+            Self { room_id, user_id, ver }
         }
     }
 
@@ -66,4 +66,54 @@ pub mod v1 {
             Self { room_version, event }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_room_id, owned_user_id, RoomVersionId,
+            };
+
+            let req = super::Request::new(
+                owned_room_id!("!room:example.org"),
+                owned_user_id!("@alice:remote.org"),
+                vec![RoomVersionId::V7, RoomVersionId::V10, RoomVersionId::V11],
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://example.org",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/federation/v1/make_knock/!room:example.org/@alice:remote.org"
+            );
+            assert_eq!(req.uri().query(), Some("ver=7&ver=10&ver=11"));
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::{api::IncomingRequest as _, RoomVersionId};
+
+            let req = super::Request::try_from_http_request(
+                http::Request::get(
+                    "https://example.org/_matrix/federation/v1/make_knock/!room:example.org/@alice:remote.org?ver=7&ver=11",
+                )
+                .body(&[] as &[u8])
+                .unwrap(),
+                &["!room:example.org", "@alice:remote.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!room:example.org");
+            assert_eq!(req.user_id, "@alice:remote.org");
+            assert_eq!(req.ver, [RoomVersionId::V7, RoomVersionId::V11]);
+        }
+    }
 }
@@ -62,4 +62,45 @@ pub mod v1 {
             Self { knock_room_state }
         }
     }
+
+    #[cfg(all(test, feature = "client"))]
+    mod tests {
+        use assert_matches2::assert_matches;
+        use ruma_common::api::IncomingResponse as _;
+        use ruma_events::AnyStrippedStateEvent;
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Response;
+
+        #[test]
+        fn deserialize_response() {
+            let body = json!({
+                "knock_room_state": [
+                    {
+                        "content": { "name": "Example Room" },
+                        "sender": "@bob:example.org",
+                        "state_key": "",
+                        "type": "m.room.name",
+                    },
+                    {
+                        "content": { "join_rule": "knock" },
+                        "sender": "@bob:example.org",
+                        "state_key": "",
+                        "type": "m.room.join_rules",
+                    },
+                ],
+            });
+
+            let res =
+                Response::try_from_http_response(http::Response::new(to_json_vec(&body).unwrap()))
+                    .unwrap();
+
+            assert_eq!(res.knock_room_state.len(), 2);
+            assert_matches!(
+                res.knock_room_state[0].deserialize().unwrap(),
+                AnyStrippedStateEvent::RoomName(name_event)
+            );
+            assert_eq!(name_event.content.name.as_deref(), Some("Example Room"));
+        }
+    }
 }
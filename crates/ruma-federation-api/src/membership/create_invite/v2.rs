@@ -7,11 +7,15 @@ use ruma_common::OwnedServerName;
 use ruma_common::{
     api::{request, response, Metadata},
     metadata,
-    serde::Raw,
+    serde::{JsonObject, Raw},
     OwnedEventId, OwnedRoomId, RoomVersionId,
 };
 use ruma_events::AnyStrippedStateEvent;
-use serde_json::value::RawValue as RawJsonValue;
+use serde_json::{
+    from_str as from_json_str,
+    value::{to_raw_value as to_raw_json_value, RawValue as RawJsonValue},
+    Value as JsonValue,
+};
 
 const METADATA: Metadata = metadata! {
     method: PUT,
@@ -40,6 +44,7 @@ pub struct Request {
     pub event: Box<RawJsonValue>,
 
     /// An optional list of simplified events to help the receiver of the invite identify the room.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub invite_room_state: Vec<Raw<AnyStrippedStateEvent>>,
 
     /// An optional list of servers the invited homeserver should attempt to join or leave via,
@@ -78,6 +83,65 @@ impl Request {
             via: None,
         }
     }
+
+    /// Returns a copy of the invite event with the `invite_room_state` of this request added to
+    /// its `unsigned` object.
+    ///
+    /// This is where the invited server is expected to keep the room state when it forwards the
+    /// invite to its clients.
+    ///
+    /// If the `invite_room_state` of this request is empty, the event is returned unchanged, so an
+    /// `invite_room_state` already present in its `unsigned` object is kept.
+    ///
+    /// Returns an error if the event is not a JSON object, or if its `unsigned` field is not a
+    /// JSON object.
+    pub fn event_with_invite_room_state(&self) -> serde_json::Result<Box<RawJsonValue>> {
+        let mut event: JsonObject = from_json_str(self.event.get())?;
+
+        if self.invite_room_state.is_empty() {
+            return Ok(self.event.clone());
+        }
+
+        let unsigned = event.entry("unsigned").or_insert_with(|| JsonObject::new().into());
+        let JsonValue::Object(unsigned) = unsigned else {
+            return Err(serde::de::Error::custom("`unsigned` field of the event is not an object"));
+        };
+        unsigned
+            .insert("invite_room_state".to_owned(), serde_json::to_value(&self.invite_room_state)?);
+
+        to_raw_json_value(&event)
+    }
+
+    /// Moves the `invite_room_state` from the `unsigned` object of the invite event to the
+    /// `invite_room_state` of this request.
+    ///
+    /// The extracted events are appended to the existing ones, and the `unsigned` object is removed
+    /// from the event if it ends up empty. Does nothing if the event doesn't have an
+    /// `unsigned.invite_room_state` field.
+    ///
+    /// Returns an error if the event is not a JSON object, or if the `invite_room_state` field is
+    /// not a list of JSON objects.
+    pub fn extract_invite_room_state(&mut self) -> serde_json::Result<()> {
+        let mut event: JsonObject = from_json_str(self.event.get())?;
+
+        let Some(unsigned) = event.get_mut("unsigned").and_then(JsonValue::as_object_mut) else {
+            return Ok(());
+        };
+        let Some(invite_room_state) = unsigned.remove("invite_room_state") else {
+            return Ok(());
+        };
+
+        if unsigned.is_empty() {
+            event.remove("unsigned");
+        }
+
+        let invite_room_state: Vec<Raw<AnyStrippedStateEvent>> =
+            serde_json::from_value(invite_room_state)?;
+        self.invite_room_state.extend(invite_room_state);
+        self.event = to_raw_json_value(&event)?;
+
+        Ok(())
+    }
 }
 
 impl Response {
@@ -86,3 +150,155 @@ impl Response {
         Self { event }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ruma_common::{owned_event_id, owned_room_id, serde::Raw, RoomVersionId};
+    use serde_json::{
+        from_str as from_json_str, json, to_value as to_json_value,
+        value::to_raw_value as to_raw_json_value, Value as JsonValue,
+    };
+
+    use super::Request;
+
+    fn invite_event(unsigned: Option<JsonValue>) -> JsonValue {
+        let mut event = json!({
+            "content": { "membership": "invite" },
+            "origin_server_ts": 1_549_041_175_876_u64,
+            "sender": "@someone:example.org",
+            "state_key": "@joe:elsewhere.com",
+            "type": "m.room.member",
+        });
+        if let Some(unsigned) = unsigned {
+            event["unsigned"] = unsigned;
+        }
+        event
+    }
+
+    fn room_name_event() -> JsonValue {
+        json!({
+            "content": { "name": "Example Room" },
+            "sender": "@bob:example.org",
+            "state_key": "",
+            "type": "m.room.name",
+        })
+    }
+
+    #[test]
+    fn event_with_invite_room_state() {
+        let request = Request::new(
+            owned_room_id!("!room:example.org"),
+            owned_event_id!("$invite:example.org"),
+            RoomVersionId::V11,
+            to_raw_json_value(&invite_event(Some(json!({ "age": 10 })))).unwrap(),
+            vec![Raw::new(&room_name_event()).unwrap().cast()],
+        );
+
+        let event = request.event_with_invite_room_state().unwrap();
+        assert_eq!(
+            from_json_str::<JsonValue>(event.get()).unwrap(),
+            invite_event(Some(json!({
+                "age": 10,
+                "invite_room_state": [room_name_event()],
+            })))
+        );
+
+        // No room state to add.
+        let unsigned = json!({ "invite_room_state": [room_name_event()] });
+        let request = Request::new(
+            owned_room_id!("!room:example.org"),
+            owned_event_id!("$invite:example.org"),
+            RoomVersionId::V11,
+            to_raw_json_value(&invite_event(Some(unsigned.clone()))).unwrap(),
+            Vec::new(),
+        );
+
+        let event = request.event_with_invite_room_state().unwrap();
+        assert_eq!(from_json_str::<JsonValue>(event.get()).unwrap(), invite_event(Some(unsigned)));
+
+        // The event is not an object.
+        let request = Request::new(
+            owned_room_id!("!room:example.org"),
+            owned_event_id!("$invite:example.org"),
+            RoomVersionId::V11,
+            to_raw_json_value(&json!(["m.room.member"])).unwrap(),
+            Vec::new(),
+        );
+        request.event_with_invite_room_state().unwrap_err();
+    }
+
+    #[test]
+    fn extract_invite_room_state() {
+        let mut request = Request::new(
+            owned_room_id!("!room:example.org"),
+            owned_event_id!("$invite:example.org"),
+            RoomVersionId::V11,
+            to_raw_json_value(&invite_event(Some(json!({
+                "invite_room_state": [room_name_event()],
+            }))))
+            .unwrap(),
+            Vec::new(),
+        );
+
+        request.extract_invite_room_state().unwrap();
+        assert_eq!(request.invite_room_state.len(), 1);
+        assert_eq!(to_json_value(&request.invite_room_state[0]).unwrap(), room_name_event());
+        assert_eq!(from_json_str::<JsonValue>(request.event.get()).unwrap(), invite_event(None));
+
+        // Other unsigned fields are kept.
+        let mut request = Request::new(
+            owned_room_id!("!room:example.org"),
+            owned_event_id!("$invite:example.org"),
+            RoomVersionId::V11,
+            to_raw_json_value(&invite_event(Some(json!({
+                "age": 10,
+                "invite_room_state": [room_name_event()],
+            }))))
+            .unwrap(),
+            Vec::new(),
+        );
+
+        request.extract_invite_room_state().unwrap();
+        assert_eq!(request.invite_room_state.len(), 1);
+        assert_eq!(
+            from_json_str::<JsonValue>(request.event.get()).unwrap(),
+            invite_event(Some(json!({ "age": 10 })))
+        );
+
+        // Nothing to extract.
+        let mut request = Request::new(
+            owned_room_id!("!room:example.org"),
+            owned_event_id!("$invite:example.org"),
+            RoomVersionId::V11,
+            to_raw_json_value(&invite_event(None)).unwrap(),
+            Vec::new(),
+        );
+        request.extract_invite_room_state().unwrap();
+        assert!(request.invite_room_state.is_empty());
+        assert_eq!(from_json_str::<JsonValue>(request.event.get()).unwrap(), invite_event(None));
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn deserialize_request_without_invite_room_state() {
+        use ruma_common::api::IncomingRequest as _;
+        use serde_json::to_vec as to_json_vec;
+
+        let body = json!({
+            "room_version": "11",
+            "event": invite_event(None),
+        });
+        let req = Request::try_from_http_request(
+            http::Request::put(
+                "https://example.org/_matrix/federation/v2/invite/!room:example.org/$invite:example.org",
+            )
+            .body(to_json_vec(&body).unwrap())
+            .unwrap(),
+            &["!room:example.org", "$invite:example.org"],
+        )
+        .unwrap();
+
+        assert_eq!(req.room_version, RoomVersionId::V11);
+        assert!(req.invite_room_state.is_empty());
+    }
+}
@@ -49,4 +49,45 @@ pub mod v1 {
             Self { sub }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request_without_authentication() {
+            use ruma_common::api::{MatrixVersion, OutgoingRequest, SendAccessToken};
+
+            use super::Request;
+
+            let req = Request::new("opaque+token".to_owned())
+                .try_into_http_request::<Vec<u8>>(
+                    "https://example.org",
+                    SendAccessToken::None,
+                    &[MatrixVersion::V1_1],
+                )
+                .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/federation/v1/openid/userinfo");
+            assert_eq!(req.uri().query(), Some("access_token=opaque%2Btoken"));
+            assert_eq!(req.headers().get(http::header::AUTHORIZATION), None);
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn serialize_response() {
+            use ruma_common::{api::OutgoingResponse, owned_user_id};
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            use super::Response;
+
+            let res = Response::new(owned_user_id!("@alice:example.org"))
+                .try_into_http_response::<Vec<u8>>()
+                .unwrap();
+
+            assert_eq!(
+                from_json_slice::<JsonValue>(res.body()).unwrap(),
+                json!({ "sub": "@alice:example.org" })
+            );
+        }
+    }
 }
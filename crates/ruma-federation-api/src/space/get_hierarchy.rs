@@ -70,4 +70,101 @@ pub mod v1 {
             Self { children: Vec::new(), inaccessible_children: Vec::new(), room: room_summary }
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_room_id,
+            };
+
+            let mut req = super::Request::new(owned_room_id!("!space:example.org"));
+            req.suggested_only = true;
+
+            let req = req
+                .try_into_http_request::<Vec<u8>>(
+                    "https://example.org",
+                    SendAccessToken::IfRequired("auth_tok"),
+                    &[MatrixVersion::V1_2],
+                )
+                .unwrap();
+
+            assert_eq!(req.uri().path(), "/_matrix/federation/v1/hierarchy/!space:example.org");
+            assert_eq!(req.uri().query(), Some("suggested_only=true"));
+        }
+
+        #[cfg(feature = "client")]
+        #[test]
+        fn deserialize_response() {
+            use ruma_common::{
+                api::IncomingResponse as _, room::RoomType, space::SpaceRoomJoinRule,
+            };
+            use serde_json::{json, to_vec as to_json_vec};
+
+            let body = json!({
+                "children": [
+                    {
+                        "allowed_room_ids": ["!upstream:example.org"],
+                        "avatar_url": "mxc://example.org/abcdef2",
+                        "canonical_alias": "#general:example.org",
+                        "guest_can_join": false,
+                        "join_rule": "restricted",
+                        "name": "The First Space",
+                        "num_joined_members": 42,
+                        "room_id": "!second:example.org",
+                        "room_type": "m.space",
+                        "topic": "Hello world",
+                        "world_readable": true,
+                    },
+                ],
+                "inaccessible_children": ["!secret:example.org"],
+                "room": {
+                    "allowed_room_ids": [],
+                    "avatar_url": "mxc://example.org/abcdef",
+                    "canonical_alias": "#general:example.org",
+                    "children_state": [
+                        {
+                            "content": { "via": ["remote.example.org"] },
+                            "origin_server_ts": 1_629_413_349_153_u64,
+                            "sender": "@alice:example.org",
+                            "state_key": "!a:example.org",
+                            "type": "m.space.child",
+                        },
+                    ],
+                    "guest_can_join": false,
+                    "join_rule": "public",
+                    "name": "The First Space",
+                    "num_joined_members": 42,
+                    "room_id": "!space:example.org",
+                    "room_type": "m.space",
+                    "topic": "No other spaces were created first, ever",
+                    "world_readable": true,
+                },
+            });
+
+            let res = super::Response::try_from_http_response(http::Response::new(
+                to_json_vec(&body).unwrap(),
+            ))
+            .unwrap();
+
+            assert_eq!(res.room.room_id, "!space:example.org");
+            assert_eq!(res.room.join_rule, SpaceRoomJoinRule::Public);
+            assert_eq!(res.room.room_type, Some(RoomType::Space));
+            assert!(res.room.allowed_room_ids.is_empty());
+            assert_eq!(res.room.children_state.len(), 1);
+            let child_event = res.room.children_state[0].deserialize().unwrap();
+            assert_eq!(child_event.state_key, "!a:example.org");
+            assert_eq!(child_event.content.via.len(), 1);
+
+            assert_eq!(res.children.len(), 1);
+            assert_eq!(res.children[0].room_id, "!second:example.org");
+            assert_eq!(res.children[0].join_rule, SpaceRoomJoinRule::Restricted);
+            assert_eq!(res.children[0].allowed_room_ids, ["!upstream:example.org"]);
+
+            assert_eq!(res.inaccessible_children, ["!secret:example.org"]);
+        }
+    }
 }
@@ -9,14 +9,13 @@ pub mod v1 {
     //!
     //! [spec]: https://spec.matrix.org/latest/server-server-api/#put_matrixfederationv13pidonbind
 
-    use std::collections::BTreeMap;
-
     use ruma_common::{
         api::{request, response, Metadata},
         metadata,
         thirdparty::Medium,
-        OwnedRoomId, OwnedServerName, OwnedServerSigningKeyId, OwnedUserId,
+        OwnedRoomId, OwnedUserId,
     };
+    use ruma_events::room::member::SignedThirdPartyInvite;
     use serde::{Deserialize, Serialize};
 
     const METADATA: Metadata = metadata! {
@@ -90,8 +89,9 @@ pub mod v1 {
         /// The user ID that sent the invite.
         pub sender: OwnedUserId,
 
-        /// Signature from the identity server using a long-term private key.
-        pub signed: BTreeMap<OwnedServerName, BTreeMap<OwnedServerSigningKeyId, String>>,
+        /// The signed content of the invite, with a signature from the identity server using a
+        /// long-term private key.
+        pub signed: SignedThirdPartyInvite,
     }
 
     impl ThirdPartyInvite {
@@ -101,9 +101,69 @@ pub mod v1 {
             mxid: OwnedUserId,
             room_id: OwnedRoomId,
             sender: OwnedUserId,
-            signed: BTreeMap<OwnedServerName, BTreeMap<OwnedServerSigningKeyId, String>>,
+            signed: SignedThirdPartyInvite,
         ) -> Self {
             Self { medium: Medium::Email, address, mxid, room_id, sender, signed }
         }
     }
+
+    #[cfg(all(test, feature = "server"))]
+    mod tests {
+        use ruma_common::{api::IncomingRequest as _, thirdparty::Medium};
+        use serde_json::{json, to_vec as to_json_vec};
+
+        use super::Request;
+
+        #[test]
+        fn deserialize_request() {
+            let body = json!({
+                "address": "alice@example.com",
+                "invites": [
+                    {
+                        "address": "alice@example.com",
+                        "medium": "email",
+                        "mxid": "@alice:matrix.org",
+                        "room_id": "!somewhere:example.org",
+                        "sender": "@bob:matrix.org",
+                        "signed": {
+                            "mxid": "@alice:matrix.org",
+                            "signatures": {
+                                "vector.im": {
+                                    "ed25519:0": "SomeSignatureGoesHere",
+                                },
+                            },
+                            "token": "Hello World",
+                            "org.example.custom": "also signed",
+                        },
+                    },
+                ],
+                "medium": "email",
+                "mxid": "@alice:matrix.org",
+            });
+
+            let req = Request::try_from_http_request(
+                http::Request::put("https://matrix.org/_matrix/federation/v1/3pid/onbind")
+                    .body(to_json_vec(&body).unwrap())
+                    .unwrap(),
+                &[] as &[String],
+            )
+            .unwrap();
+
+            assert_eq!(req.medium, Medium::Email);
+            assert_eq!(req.address, "alice@example.com");
+            assert_eq!(req.mxid, "@alice:matrix.org");
+            assert_eq!(req.invites.len(), 1);
+
+            let invite = &req.invites[0];
+            assert_eq!(invite.room_id, "!somewhere:example.org");
+            assert_eq!(invite.sender, "@bob:matrix.org");
+            assert_eq!(invite.signed.mxid(), "@alice:matrix.org");
+            assert_eq!(invite.signed.token(), "Hello World");
+            assert_eq!(invite.signed.signatures().len(), 1);
+            assert_eq!(
+                invite.signed.json().get_field::<String>("org.example.custom").unwrap().as_deref(),
+                Some("also signed")
+            );
+        }
+    }
 }
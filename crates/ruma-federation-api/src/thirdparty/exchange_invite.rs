@@ -15,12 +15,15 @@ pub mod v1 {
         api::{request, response, Metadata},
         metadata, OwnedRoomId, OwnedUserId,
     };
-    use ruma_events::{room::member::ThirdPartyInvite, StateEventType};
+    use ruma_events::{
+        room::member::{MembershipState, RoomMemberEventContent, ThirdPartyInvite},
+        StateEventType,
+    };
 
     const METADATA: Metadata = metadata! {
         method: PUT,
         rate_limited: false,
-        authentication: AccessToken,
+        authentication: ServerSignatures,
         history: {
             1.0 => "/_matrix/federation/v1/exchange_third_party_invite/:room_id",
         }
@@ -46,7 +49,9 @@ pub mod v1 {
         pub state_key: OwnedUserId,
 
         /// The content of the invite event.
-        pub content: ThirdPartyInvite,
+        ///
+        /// Must have a `membership` of `MembershipState::Invite` and a `third_party_invite`.
+        pub content: RoomMemberEventContent,
     }
 
     /// Response type for the `exchange_invite` endpoint.
@@ -55,13 +60,16 @@ pub mod v1 {
     pub struct Response {}
 
     impl Request {
-        /// Creates a new `Request` for a third party invite exchange
+        /// Creates a new `Request` for a third party invite exchange with the given room ID,
+        /// sender, invited user and third party invite.
         pub fn new(
             room_id: OwnedRoomId,
             sender: OwnedUserId,
             state_key: OwnedUserId,
-            content: ThirdPartyInvite,
+            third_party_invite: ThirdPartyInvite,
         ) -> Self {
+            let mut content = RoomMemberEventContent::new(MembershipState::Invite);
+            content.third_party_invite = Some(third_party_invite);
             Self { room_id, kind: StateEventType::RoomMember, sender, state_key, content }
         }
     }
@@ -72,4 +80,131 @@ pub mod v1 {
             Self {}
         }
     }
+
+    #[cfg(all(test, any(feature = "client", feature = "server")))]
+    mod tests {
+        #[cfg(feature = "client")]
+        #[test]
+        fn serialize_request() {
+            use std::collections::BTreeMap;
+
+            use ruma_common::{
+                api::{MatrixVersion, OutgoingRequest as _, SendAccessToken},
+                owned_room_id, owned_server_name, owned_server_signing_key_id, owned_user_id,
+            };
+            use ruma_events::room::member::{
+                SignedContent, SignedThirdPartyInvite, ThirdPartyInvite,
+            };
+            use serde_json::{from_slice as from_json_slice, json, Value as JsonValue};
+
+            let signed = SignedContent::new(
+                BTreeMap::from([(
+                    owned_server_name!("magic.forest"),
+                    BTreeMap::from([(
+                        owned_server_signing_key_id!("ed25519:3"),
+                        "fQpGIW1Snz+pwLZu6sTy2aHy/DYWWTspTJRPyNp0PKkymfIsNffysMl6ObMMFdIJhk6g6pwlIqZ54rxo8SLmAg".to_owned(),
+                    )]),
+                )]),
+                owned_user_id!("@alice:localhost"),
+                "abc123".to_owned(),
+            );
+            let req = super::Request::new(
+                owned_room_id!("!abc123:matrix.org"),
+                owned_user_id!("@joe:elsewhere.com"),
+                owned_user_id!("@alice:localhost"),
+                ThirdPartyInvite::new(
+                    "alice".to_owned(),
+                    SignedThirdPartyInvite::new(signed).unwrap(),
+                ),
+            )
+            .try_into_http_request::<Vec<u8>>(
+                "https://matrix.org",
+                SendAccessToken::IfRequired("auth_tok"),
+                &[MatrixVersion::V1_1],
+            )
+            .unwrap();
+
+            assert_eq!(
+                req.uri().path(),
+                "/_matrix/federation/v1/exchange_third_party_invite/!abc123:matrix.org"
+            );
+            assert_eq!(
+                from_json_slice::<JsonValue>(req.body()).unwrap(),
+                json!({
+                    "type": "m.room.member",
+                    "sender": "@joe:elsewhere.com",
+                    "state_key": "@alice:localhost",
+                    "content": {
+                        "membership": "invite",
+                        "third_party_invite": {
+                            "display_name": "alice",
+                            "signed": {
+                                "mxid": "@alice:localhost",
+                                "token": "abc123",
+                                "signatures": {
+                                    "magic.forest": {
+                                        "ed25519:3": "fQpGIW1Snz+pwLZu6sTy2aHy/DYWWTspTJRPyNp0PKkymfIsNffysMl6ObMMFdIJhk6g6pwlIqZ54rxo8SLmAg",
+                                    },
+                                },
+                            },
+                        },
+                    },
+                })
+            );
+        }
+
+        #[cfg(feature = "server")]
+        #[test]
+        fn deserialize_request() {
+            use ruma_common::api::IncomingRequest as _;
+            use ruma_events::room::member::MembershipState;
+            use serde_json::{
+                from_str as from_json_str, json, to_vec as to_json_vec, Value as JsonValue,
+            };
+
+            let body = json!({
+                "type": "m.room.member",
+                "room_id": "!abc123:matrix.org",
+                "sender": "@joe:elsewhere.com",
+                "state_key": "@alice:localhost",
+                "content": {
+                    "membership": "invite",
+                    "displayname": "Alice",
+                    "third_party_invite": {
+                        "display_name": "alice",
+                        "signed": {
+                            "mxid": "@alice:localhost",
+                            "token": "abc123",
+                            "signatures": {
+                                "magic.forest": { "ed25519:3": "signature" },
+                            },
+                            "org.example.custom": "also signed",
+                        },
+                    },
+                },
+            });
+
+            let req = super::Request::try_from_http_request(
+                http::Request::put(
+                    "https://matrix.org/_matrix/federation/v1/exchange_third_party_invite/!abc123:matrix.org",
+                )
+                .body(to_json_vec(&body).unwrap())
+                .unwrap(),
+                &["!abc123:matrix.org"],
+            )
+            .unwrap();
+
+            assert_eq!(req.room_id, "!abc123:matrix.org");
+            assert_eq!(req.state_key, "@alice:localhost");
+            assert_eq!(req.content.membership, MembershipState::Invite);
+            assert_eq!(req.content.displayname.as_deref(), Some("Alice"));
+            let signed = req.content.third_party_invite.unwrap().signed;
+            assert_eq!(signed.mxid(), "@alice:localhost");
+            assert_eq!(signed.token(), "abc123");
+            assert_eq!(
+                from_json_str::<JsonValue>(signed.json().json().get()).unwrap(),
+                body["content"]["third_party_invite"]["signed"]
+            );
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! Edu type and variant content structs.
 
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, error::Error, fmt};
 
 use js_int::UInt;
 use ruma_common::{
@@ -10,7 +10,11 @@ use ruma_common::{
     to_device::DeviceIdOrAllDevices,
     OwnedDeviceId, OwnedEventId, OwnedRoomId, OwnedTransactionId, OwnedUserId,
 };
-use ruma_events::{receipt::Receipt, AnyToDeviceEventContent, ToDeviceEventType};
+use ruma_events::{
+    presence::{PresenceEvent, PresenceEventContent},
+    receipt::Receipt,
+    AnyToDeviceEventContent, ToDeviceEventType,
+};
 use serde::{de, Deserialize, Serialize};
 use serde_json::{value::RawValue as RawJsonValue, Value as JsonValue};
 
@@ -131,6 +135,56 @@ impl PresenceUpdate {
     }
 }
 
+impl From<PresenceUpdate> for PresenceEvent {
+    fn from(update: PresenceUpdate) -> Self {
+        let PresenceUpdate { user_id, presence, status_msg, last_active_ago, currently_active } =
+            update;
+
+        let mut content = PresenceEventContent::new(presence);
+        content.status_msg = status_msg;
+        content.last_active_ago = Some(last_active_ago);
+        content.currently_active = Some(currently_active);
+
+        Self { content, sender: user_id }
+    }
+}
+
+/// Convert a client presence event into a federation presence update.
+///
+/// The `avatar_url` and `displayname` of the content are dropped, because they are not part of the
+/// EDU. A missing `currently_active` is set to `false`.
+///
+/// Fails if the `last_active_ago` of the content is missing, because it is required in the EDU.
+impl TryFrom<PresenceEvent> for PresenceUpdate {
+    type Error = MissingLastActiveAgoError;
+
+    fn try_from(event: PresenceEvent) -> Result<Self, Self::Error> {
+        let PresenceEvent { content, sender } = event;
+
+        Ok(Self {
+            user_id: sender,
+            presence: content.presence,
+            status_msg: content.status_msg,
+            last_active_ago: content.last_active_ago.ok_or(MissingLastActiveAgoError)?,
+            currently_active: content.currently_active.unwrap_or_default(),
+        })
+    }
+}
+
+/// An error returned when attempting to convert a `PresenceEvent` without a `last_active_ago` into
+/// a `PresenceUpdate`.
+#[derive(Debug)]
+#[allow(clippy::exhaustive_structs)]
+pub struct MissingLastActiveAgoError;
+
+impl fmt::Display for MissingLastActiveAgoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing last_active_ago in presence event")
+    }
+}
+
+impl Error for MissingLastActiveAgoError {}
+
 /// The content for "m.receipt" Edu.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
@@ -316,11 +370,14 @@ impl SigningKeyUpdateContent {
 mod tests {
     use assert_matches2::assert_matches;
     use js_int::uint;
-    use ruma_common::{room_id, user_id};
-    use ruma_events::ToDeviceEventType;
+    use ruma_common::{mxc_uri, owned_user_id, presence::PresenceState, room_id, user_id};
+    use ruma_events::{
+        presence::{PresenceEvent, PresenceEventContent},
+        ToDeviceEventType,
+    };
     use serde_json::json;
 
-    use super::{DeviceListUpdateContent, Edu, ReceiptContent};
+    use super::{DeviceListUpdateContent, Edu, PresenceUpdate, ReceiptContent};
 
     #[test]
     fn device_list_update_edu() {
@@ -539,4 +596,47 @@ mod tests {
 
         assert_eq!(serde_json::to_value(&edu).unwrap(), json);
     }
+
+    #[test]
+    fn presence_update_to_event() {
+        let mut update = PresenceUpdate::new(
+            owned_user_id!("@john:example.com"),
+            PresenceState::Unavailable,
+            uint!(5000),
+        );
+        update.status_msg = Some("Making cupcakes".to_owned());
+
+        let event = PresenceEvent::from(update);
+        assert_eq!(event.sender, "@john:example.com");
+        assert_eq!(event.content.presence, PresenceState::Unavailable);
+        assert_eq!(event.content.status_msg.as_deref(), Some("Making cupcakes"));
+        assert_eq!(event.content.last_active_ago, Some(uint!(5000)));
+        assert_eq!(event.content.currently_active, Some(false));
+        assert_eq!(event.content.avatar_url, None);
+        assert_eq!(event.content.displayname, None);
+    }
+
+    #[test]
+    fn presence_event_to_update() {
+        let mut content = PresenceEventContent::new(PresenceState::Online);
+        content.avatar_url = Some(mxc_uri!("mxc://example.com/avatar").to_owned());
+        content.last_active_ago = Some(uint!(2000));
+        content.currently_active = Some(true);
+        let event = PresenceEvent { content, sender: owned_user_id!("@john:example.com") };
+
+        let update = PresenceUpdate::try_from(event).unwrap();
+        assert_eq!(update.user_id, "@john:example.com");
+        assert_eq!(update.presence, PresenceState::Online);
+        assert_eq!(update.status_msg, None);
+        assert_eq!(update.last_active_ago, uint!(2000));
+        assert!(update.currently_active);
+    }
+
+    #[test]
+    fn presence_event_without_last_active_ago_to_update() {
+        let content = PresenceEventContent::new(PresenceState::Online);
+        let event = PresenceEvent { content, sender: owned_user_id!("@john:example.com") };
+
+        PresenceUpdate::try_from(event).unwrap_err();
+    }
 }
@@ -1,13 +1,11 @@
-use crate::Error;
+use crate::{validate_chars, Error};
 
 pub fn validate(s: &str) -> Result<(), Error> {
     if s.len() > 255 {
         return Err(Error::MaximumLengthExceeded);
-    } else if !s.chars().all(|c| c.is_alphanumeric() || ".=_-".contains(c)) {
-        return Err(Error::InvalidCharacters);
     } else if s.is_empty() {
         return Err(Error::Empty);
     }
 
-    Ok(())
+    validate_chars(s, 0, |c| c.is_ascii_alphanumeric() || ".=_-".contains(c))
 }
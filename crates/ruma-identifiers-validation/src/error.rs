@@ -10,9 +10,17 @@ pub enum Error {
     #[error("identifier or required part of it is empty")]
     Empty,
 
-    /// The identifier contains invalid characters.
-    #[error("identifier contains invalid characters")]
-    InvalidCharacters,
+    /// The identifier contains an invalid character.
+    #[error("identifier contains invalid character {character:?} at byte {index}")]
+    InvalidCharacter {
+        /// The byte index of the invalid character in the validated string.
+        ///
+        /// This is a `u32` rather than a `usize` to keep this error type small.
+        index: u32,
+
+        /// The invalid character.
+        character: char,
+    },
 
     /// The string isn't a valid Matrix ID.
     #[error("invalid matrix ID: {0}")]
@@ -35,8 +43,8 @@ pub enum Error {
     InvalidVoipVersionId(#[from] VoipVersionIdError),
 
     /// The server name part of the the ID string is not a valid server name.
-    #[error("server name is not a valid IP address or domain name")]
-    InvalidServerName,
+    #[error("invalid server name: {0}")]
+    InvalidServerName(#[from] ServerNameError),
 
     /// The string isn't valid UTF-8.
     #[error("invalid UTF-8")]
@@ -86,6 +94,23 @@ pub enum MxcUriError {
     ServerNameMalformed,
 }
 
+/// An error occurred while validating a server name.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum ServerNameError {
+    /// The host of the server name is empty.
+    #[error("host is empty")]
+    EmptyHost,
+
+    /// The host of the server name is not a valid IP address or domain name.
+    #[error("host is not a valid IP address or domain name")]
+    InvalidHost,
+
+    /// The port of the server name is not a valid port number.
+    #[error("port is not a valid port number")]
+    InvalidPort,
+}
+
 /// An error occurred while validating a `MatrixId`.
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, thiserror::Error)]
 #[non_exhaustive]
@@ -166,10 +191,26 @@ pub enum VoipVersionIdError {
 mod tests {
     use std::mem::size_of;
 
-    use super::Error;
+    use super::{Error, ServerNameError};
 
     #[test]
     fn small_error_type() {
         assert!(size_of::<Error>() <= 8);
     }
+
+    #[test]
+    fn display_invalid_character() {
+        assert_eq!(
+            Error::InvalidCharacter { index: 5, character: '!' }.to_string(),
+            "identifier contains invalid character '!' at byte 5"
+        );
+    }
+
+    #[test]
+    fn display_invalid_server_name() {
+        assert_eq!(
+            Error::InvalidServerName(ServerNameError::InvalidPort).to_string(),
+            "invalid server name: port is not a valid port number"
+        );
+    }
 }
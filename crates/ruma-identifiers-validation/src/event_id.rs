@@ -1,4 +1,4 @@
-use crate::{validate_delimited_id, Error};
+use crate::{validate_chars, validate_delimited_id, validate_id, Error};
 
 pub fn validate(s: &str) -> Result<(), Error> {
     if s.contains(':') {
@@ -9,3 +9,30 @@ pub fn validate(s: &str) -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Validate an event ID in the original format, as used by room versions 1 and 2.
+pub fn validate_original(s: &str) -> Result<(), Error> {
+    validate_delimited_id(s, b'$')
+}
+
+/// Validate an event ID in the format introduced by room version 3, a reference hash encoded with
+/// unpadded standard base64.
+pub fn validate_base64(s: &str) -> Result<(), Error> {
+    validate_hash(s, |c| matches!(c, '+' | '/'))
+}
+
+/// Validate an event ID in the format introduced by room version 4, a reference hash encoded with
+/// unpadded URL-safe base64.
+pub fn validate_url_safe_base64(s: &str) -> Result<(), Error> {
+    validate_hash(s, |c| matches!(c, '-' | '_'))
+}
+
+fn validate_hash(s: &str, is_extra_char: impl Fn(char) -> bool) -> Result<(), Error> {
+    validate_id(s, b'$')?;
+
+    if s.len() == 1 {
+        return Err(Error::Empty);
+    }
+
+    validate_chars(s, 1, |c| c.is_ascii_alphanumeric() || is_extra_char(c))
+}
@@ -1,5 +1,7 @@
 use std::num::NonZeroU8;
 
+#[cfg(not(feature = "compat-key-id"))]
+use crate::validate_chars;
 use crate::Error;
 
 pub fn validate(s: &str) -> Result<NonZeroU8, Error> {
@@ -7,18 +9,16 @@ pub fn validate(s: &str) -> Result<NonZeroU8, Error> {
         NonZeroU8::new(s.find(':').ok_or(Error::MissingColon)? as u8).ok_or(Error::MissingColon)?;
 
     #[cfg(not(feature = "compat-key-id"))]
-    validate_version(&s[colon_idx.get() as usize + 1..])?;
+    validate_version(s, colon_idx.get() as usize + 1)?;
 
     Ok(colon_idx)
 }
 
 #[cfg(not(feature = "compat-key-id"))]
-fn validate_version(version: &str) -> Result<(), Error> {
-    if version.is_empty() {
+fn validate_version(s: &str, start: usize) -> Result<(), Error> {
+    if s.len() == start {
         return Err(Error::Empty);
-    } else if !version.chars().all(|c| c.is_alphanumeric() || c == '_') {
-        return Err(Error::InvalidCharacters);
     }
 
-    Ok(())
+    validate_chars(s, start, |c| c.is_alphanumeric() || c == '_')
 }
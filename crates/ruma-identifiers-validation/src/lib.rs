@@ -48,3 +48,16 @@ fn validate_delimited_id(id: &str, first_byte: u8) -> Result<(), Error> {
     parse_id(id, first_byte)?;
     Ok(())
 }
+
+/// Checks that all the characters of a string, starting at the given byte index, are valid.
+///
+/// Returns an error with the first invalid character and its byte index in the string otherwise.
+fn validate_chars(s: &str, start: usize, is_valid: impl Fn(char) -> bool) -> Result<(), Error> {
+    match s[start..].char_indices().find(|&(_, c)| !is_valid(c)) {
+        Some((index, character)) => {
+            // Identifiers can't be anywhere near 4 GiB, so the index always fits in a `u32`.
+            Err(Error::InvalidCharacter { index: (start + index) as u32, character })
+        }
+        None => Ok(()),
+    }
+}
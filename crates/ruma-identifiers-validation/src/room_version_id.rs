@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{validate_chars, Error};
 
 /// Room version identifiers cannot be more than 32 code points.
 const MAX_CODE_POINTS: usize = 32;
@@ -8,9 +8,7 @@ pub fn validate(s: &str) -> Result<(), Error> {
         Err(Error::Empty)
     } else if s.chars().count() > MAX_CODE_POINTS {
         Err(Error::MaximumLengthExceeded)
-    } else if !s.chars().all(|c| c.is_alphanumeric() || ".-".contains(c)) {
-        Err(Error::InvalidCharacters)
     } else {
-        Ok(())
+        validate_chars(s, 0, |c| c.is_alphanumeric() || ".-".contains(c))
     }
 }
@@ -1,9 +1,15 @@
-use crate::{parse_id, Error};
+use crate::{parse_id, validate_chars, Error};
 
 pub fn validate(s: &str) -> Result<(), Error> {
     let colon_idx = parse_id(s, b'@')?;
     let localpart = &s[1..colon_idx];
-    let _ = localpart_is_fully_conforming(localpart)?;
+    let _ = localpart_is_fully_conforming(localpart).map_err(|error| match error {
+        // Make the index relative to the full user ID.
+        Error::InvalidCharacter { index, character } => {
+            Error::InvalidCharacter { index: index + 1, character }
+        }
+        error => error,
+    })?;
 
     Ok(())
 }
@@ -18,30 +24,51 @@ pub fn validate(s: &str) -> Result<(), Error> {
 /// due to Synapse allowing them over federation. This will likely be fixed in an upcoming room
 /// version; see [MSC2828](https://github.com/matrix-org/matrix-spec-proposals/pull/2828).
 pub fn localpart_is_fully_conforming(localpart: &str) -> Result<bool, Error> {
-    // See https://spec.matrix.org/latest/appendices/#user-identifiers
-    let is_fully_conforming = !localpart.is_empty()
-        && localpart.bytes().all(
-            |b| matches!(b, b'0'..=b'9' | b'a'..=b'z' | b'-' | b'.' | b'=' | b'_' | b'/' | b'+'),
-        );
+    let is_fully_conforming = validate_localpart_strict(localpart).is_ok();
 
     if !is_fully_conforming {
         // If it's not fully conforming, check if it contains characters that are also disallowed
         // for historical user IDs, or is empty. If that's the case, return an error.
         // See https://spec.matrix.org/latest/appendices/#historical-user-ids
         #[cfg(not(feature = "compat-user-id"))]
-        let is_invalid =
-            localpart.is_empty() || localpart.bytes().any(|b| b < 0x21 || b == b':' || b > 0x7E);
+        validate_localpart_historical(localpart)?;
 
         // In compat mode, allow anything except `:` to match Synapse. The `:` check is only needed
         // because this function can be called through `UserId::parse_with_servername`, otherwise
         // it would be impossible for the input to contain a `:`.
         #[cfg(feature = "compat-user-id")]
-        let is_invalid = localpart.as_bytes().contains(&b':');
-
-        if is_invalid {
-            return Err(Error::InvalidCharacters);
-        }
+        validate_chars(localpart, 0, |c| c != ':')?;
     }
 
     Ok(is_fully_conforming)
 }
+
+/// Check whether the given user id localpart conforms to the [latest grammar] of user IDs.
+///
+/// This should be used to validate the localparts of new user IDs, like during registration.
+///
+/// [latest grammar]: https://spec.matrix.org/latest/appendices/#user-identifiers
+pub fn validate_localpart_strict(localpart: &str) -> Result<(), Error> {
+    if localpart.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    validate_chars(
+        localpart,
+        0,
+        |c| matches!(c, '0'..='9' | 'a'..='z' | '-' | '.' | '=' | '_' | '/' | '+'),
+    )
+}
+
+/// Check whether the given user id localpart conforms to the [historical grammar] of user IDs.
+///
+/// Unlike [`localpart_is_fully_conforming`], this is not affected by the `compat-user-id` feature.
+///
+/// [historical grammar]: https://spec.matrix.org/latest/appendices/#historical-user-ids
+pub fn validate_localpart_historical(localpart: &str) -> Result<(), Error> {
+    if localpart.is_empty() {
+        return Err(Error::Empty);
+    }
+
+    validate_chars(localpart, 0, |c| matches!(c, '\x21'..='\x7E') && c != ':')
+}
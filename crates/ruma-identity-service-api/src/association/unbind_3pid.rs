@@ -10,7 +10,7 @@ pub mod v2 {
     use ruma_common::{
         api::{request, response, Metadata},
         metadata,
-        thirdparty::Medium,
+        thirdparty::ThirdPartyId,
         OwnedClientSecret, OwnedSessionId, OwnedUserId,
     };
     use serde::{Deserialize, Serialize};
@@ -67,24 +67,6 @@ pub mod v2 {
         }
     }
 
-    /// A 3PID to unbind.
-    #[derive(Clone, Debug, Serialize, Deserialize)]
-    #[cfg_attr(not(feature = "unstable-exhaustive-types"), non_exhaustive)]
-    pub struct ThirdPartyId {
-        /// A medium matching the medium of identifier to unbind.
-        pub medium: Medium,
-
-        /// The 3PID address to remove.
-        pub address: String,
-    }
-
-    impl ThirdPartyId {
-        /// Creates a new `ThirdPartyId` with the given medium and address.
-        pub fn new(medium: Medium, address: String) -> Self {
-            Self { medium, address }
-        }
-    }
-
     /// A proof that the client owns the 3PID.
     ///
     /// Must be constructed using the same session ID and client secret generated and passed by the
@@ -269,6 +269,18 @@ fn expand_sync_from_into_full(
             }
         }
 
+        #[automatically_derived]
+        impl #impl_generics #full_struct #ty_gen #where_clause {
+            /// Convert this full event into a sync event, one without a room_id field, and return
+            /// the room ID separately.
+            pub fn into_sync_event_and_room_id(
+                self,
+            ) -> (#ident #ty_gen, #ruma_events::exports::ruma_common::OwnedRoomId) {
+                let Self { #( #fields, )* room_id, .. } = self;
+                (#ident { #( #fields, )* }, room_id)
+            }
+        }
+
         #[automatically_derived]
         impl #impl_generics #ident #ty_gen #where_clause {
             /// Convert this sync event into a full event, one with a room_id field.
@@ -593,7 +593,7 @@ fn generate_possibly_redacted_event_content<'a>(
                 .collect::<syn::Result<_>>()?;
 
             if keep_field {
-                Ok(Field { attrs, ..f.clone() })
+                Ok((Field { attrs, ..f.clone() }, false))
             } else if let Some(err) = unsupported_serde_attribute {
                 Err(err)
             } else if f.ident.is_none() {
@@ -612,13 +612,27 @@ fn generate_possibly_redacted_event_content<'a>(
                 let ty = parse_quote! { Option<#old_type> };
                 attrs.push(parse_quote! { #[serde(skip_serializing_if = "Option::is_none")] });
 
-                Ok(Field { attrs, ty, ..f.clone() })
+                Ok((Field { attrs, ty, ..f.clone() }, true))
             }
         })
         .collect::<syn::Result<_>>()?;
 
     // If at least one field needs to change, generate a new struct, else use a type alias.
     if field_changed {
+        let field_conversions: Vec<_> = possibly_redacted_fields
+            .iter()
+            .map(|(f, changed)| {
+                let name = &f.ident;
+                if *changed {
+                    quote! { #name: ::std::option::Option::Some(content.#name) }
+                } else {
+                    quote! { #name: content.#name }
+                }
+            })
+            .collect();
+        let possibly_redacted_fields: Vec<_> =
+            possibly_redacted_fields.into_iter().map(|(f, _)| f).collect();
+
         let possibly_redacted_event_content = generate_event_content_impl(
             &possibly_redacted_ident,
             vis,
@@ -647,6 +661,13 @@ fn generate_possibly_redacted_event_content<'a>(
             #possibly_redacted_event_content
 
             #static_event_content_impl
+
+            #[automatically_derived]
+            impl ::std::convert::From<#ident> for #possibly_redacted_ident {
+                fn from(content: #ident) -> Self {
+                    Self { #( #field_conversions, )* }
+                }
+            }
         })
     } else {
         Ok(quote! {
@@ -70,7 +70,7 @@ pub fn expand_event_enums(input: &EventEnumDecl) -> syn::Result<TokenStream> {
                 .unwrap_or_else(syn::Error::into_compile_error),
         );
         res.extend(
-            expand_from_full_event(kind, V::None, variants)
+            expand_from_full_event(kind, V::None, variants, ruma_events)
                 .unwrap_or_else(syn::Error::into_compile_error),
         );
         res.extend(
@@ -184,8 +184,7 @@ fn expand_deserialize_impl(
 
             Ok(quote! {
                 #variant_attrs #(#ev_types)|* => {
-                    let event = #serde_json::from_str::<#content>(json.get())
-                        .map_err(D::Error::custom)?;
+                    let event = #serde_json::from_str::<#content>(json.get())?;
                     Ok(#self_variant(event))
                 },
             })
@@ -193,6 +192,24 @@ fn expand_deserialize_impl(
         .collect::<syn::Result<_>>()?;
 
     Ok(quote! {
+        #[allow(unused_qualifications)]
+        impl #ident {
+            /// Deserialize the event from the given JSON, dispatching on the already extracted
+            /// event type so the JSON doesn't need to be scanned for it again.
+            pub(crate) fn from_raw_json_with_type(
+                ev_type: &str,
+                json: &#serde_json::value::RawValue,
+            ) -> #serde_json::Result<Self> {
+                match ev_type {
+                    #match_arms
+                    _ => {
+                        let event = #serde_json::from_str(json.get())?;
+                        Ok(Self::_Custom(event))
+                    },
+                }
+            }
+        }
+
         #[allow(unused_qualifications)]
         impl<'de> #serde::de::Deserialize<'de> for #ident {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -205,13 +222,7 @@ fn expand_deserialize_impl(
                 let #ruma_events::EventTypeDeHelper { ev_type, .. } =
                     #ruma_common::serde::from_raw_json_value(&json)?;
 
-                match &*ev_type {
-                    #match_arms
-                    _ => {
-                        let event = #serde_json::from_str(json.get()).map_err(D::Error::custom)?;
-                        Ok(Self::_Custom(event))
-                    },
-                }
+                Self::from_raw_json_with_type(&ev_type, &json).map_err(D::Error::custom)
             }
         }
     })
@@ -245,12 +256,16 @@ fn expand_from_full_event(
     kind: EventKind,
     var: EventEnumVariation,
     variants: &[EventEnumVariant],
+    ruma_events: &TokenStream,
 ) -> syn::Result<TokenStream> {
+    let ruma_common = quote! { #ruma_events::exports::ruma_common };
+
     let ident = kind.to_event_enum_ident(var.into())?;
     let sync = kind.to_event_enum_ident(var.to_sync().into())?;
 
-    let ident_variants = variants.iter().map(|v| v.match_arm(&ident));
+    let ident_variants: Vec<_> = variants.iter().map(|v| v.match_arm(&ident)).collect();
     let self_variants = variants.iter().map(|v| v.ctor(quote! { Self }));
+    let sync_variants = variants.iter().map(|v| v.ctor(&sync));
 
     Ok(quote! {
         #[automatically_derived]
@@ -268,6 +283,26 @@ fn expand_from_full_event(
                 }
             }
         }
+
+        #[automatically_derived]
+        impl #ident {
+            /// Convert this full event into a sync event (one without a `room_id` field), and
+            /// return the room ID separately.
+            pub fn into_sync_event_and_room_id(self) -> (#sync, #ruma_common::OwnedRoomId) {
+                match self {
+                    #(
+                        #ident_variants(event) => {
+                            let (event, room_id) = event.into_sync_event_and_room_id();
+                            (#sync_variants(event), room_id)
+                        },
+                    )*
+                    Self::_Custom(event) => {
+                        let (event, room_id) = event.into_sync_event_and_room_id();
+                        (#sync::_Custom(event), room_id)
+                    },
+                }
+            }
+        }
     })
 }
 
@@ -23,6 +23,19 @@ impl Parse for IdentifierInput {
     }
 }
 
+/// Validates an identifier literal with the given validation function.
+///
+/// Returns an error spanning the literal that contains the validation error on failure.
+pub fn validate_id_literal<T, E: std::fmt::Display>(
+    id: &LitStr,
+    id_name: &str,
+    validate: impl FnOnce(&str) -> Result<T, E>,
+) -> syn::Result<()> {
+    validate(&id.value())
+        .map(|_| ())
+        .map_err(|e| syn::Error::new_spanned(id, format!("Invalid {id_name}: {e}")))
+}
+
 pub fn expand_id_zst(input: ItemStruct) -> syn::Result<TokenStream> {
     let id = &input.ident;
     let owned = format_ident!("Owned{id}");
@@ -55,6 +68,8 @@ pub fn expand_id_zst(input: ItemStruct) -> syn::Result<TokenStream> {
 
     let as_str_docs = format!("Creates a string slice from this `{id}`.");
     let as_bytes_docs = format!("Creates a byte slice from this `{id}`.");
+    let encode_for_path_docs =
+        format!("Percent-encodes this `{id}` to be used as a single segment of a URL path.");
 
     let as_str_impl = match &input.fields {
         Fields::Named(_) | Fields::Unit => {
@@ -110,6 +125,19 @@ pub fn expand_id_zst(input: ItemStruct) -> syn::Result<TokenStream> {
             pub fn as_bytes(&self) -> &[u8] {
                 self.as_str().as_bytes()
             }
+
+            #[doc = #encode_for_path_docs]
+            #[inline]
+            pub fn encode_for_path(&self) -> crate::PathSegmentEncoded<'_> {
+                crate::PathSegmentEncoded(self.as_str())
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics std::borrow::Borrow<str> for #id_ty {
+            fn borrow(&self) -> &str {
+                self.as_str()
+            }
         }
 
         #[automatically_derived]
@@ -320,6 +348,13 @@ fn expand_owned_id(input: &ItemStruct) -> TokenStream {
             }
         }
 
+        #[automatically_derived]
+        impl #impl_generics std::borrow::Borrow<str> for #owned_ty {
+            fn borrow(&self) -> &str {
+                self.as_str()
+            }
+        }
+
         #[automatically_derived]
         impl #impl_generics std::borrow::Borrow<#id_ty> for #owned_ty {
             fn borrow(&self) -> &#id_ty {
@@ -559,6 +594,23 @@ fn expand_checked_impls(input: &ItemStruct, validate: Path) -> TokenStream {
             }
         }
 
+        #[automatically_derived]
+        impl<'de: 'a, 'a, #generic_params> serde::Deserialize<'de> for &'a #id_ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                use serde::de::Error;
+
+                let s = <&'a str>::deserialize(deserializer)?;
+
+                match <&'a #id_ty>::try_from(s) {
+                    Ok(o) => Ok(o),
+                    Err(e) => Err(D::Error::custom(e)),
+                }
+            }
+        }
+
         #[automatically_derived]
         impl<'a, #generic_params> std::convert::TryFrom<&'a str> for &'a #id_ty {
             type Error = crate::IdParseError;
@@ -706,6 +758,16 @@ fn expand_unchecked_impls(input: &ItemStruct) -> TokenStream {
                 Box::<str>::deserialize(deserializer).map(#id::from_box).map(Into::into)
             }
         }
+
+        #[automatically_derived]
+        impl<'de: 'a, 'a> serde::Deserialize<'de> for &'a #id {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                <&'a str>::deserialize(deserializer).map(#id::from_borrowed)
+            }
+        }
     }
 }
 
@@ -10,7 +10,7 @@
 // https://github.com/rust-lang/rust-clippy/issues/9029
 #![allow(clippy::derive_partial_eq_without_eq)]
 
-use identifiers::expand_id_zst;
+use identifiers::{expand_id_zst, validate_id_literal};
 use proc_macro::TokenStream;
 use proc_macro2 as pm2;
 use quote::quote;
@@ -150,7 +150,9 @@ pub fn derive_id_zst(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn device_key_id(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(device_key_id::validate(&id.value()).is_ok(), "Invalid device key id");
+    if let Err(e) = validate_id_literal(&id, "device key ID", device_key_id::validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <&#dollar_crate::DeviceKeyId as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
@@ -163,7 +165,9 @@ pub fn device_key_id(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn event_id(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(event_id::validate(&id.value()).is_ok(), "Invalid event id");
+    if let Err(e) = validate_id_literal(&id, "event ID", event_id::validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <&#dollar_crate::EventId as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
@@ -176,7 +180,9 @@ pub fn event_id(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn room_alias_id(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(room_alias_id::validate(&id.value()).is_ok(), "Invalid room_alias_id");
+    if let Err(e) = validate_id_literal(&id, "room alias ID", room_alias_id::validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <&#dollar_crate::RoomAliasId as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
@@ -189,7 +195,9 @@ pub fn room_alias_id(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn room_id(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(room_id::validate(&id.value()).is_ok(), "Invalid room_id");
+    if let Err(e) = validate_id_literal(&id, "room ID", room_id::validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <&#dollar_crate::RoomId as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
@@ -202,7 +210,9 @@ pub fn room_id(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn room_version_id(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(room_version_id::validate(&id.value()).is_ok(), "Invalid room_version_id");
+    if let Err(e) = validate_id_literal(&id, "room version ID", room_version_id::validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <#dollar_crate::RoomVersionId as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
@@ -215,7 +225,9 @@ pub fn room_version_id(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn server_signing_key_id(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(key_id::validate(&id.value()).is_ok(), "Invalid server_signing_key_id");
+    if let Err(e) = validate_id_literal(&id, "server signing key ID", key_id::validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <&#dollar_crate::ServerSigningKeyId as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
@@ -228,7 +240,16 @@ pub fn server_signing_key_id(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn server_name(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(server_name::validate(&id.value()).is_ok(), "Invalid server_name");
+    // Don't repeat that the server name is invalid in the error message.
+    let validate = |s: &str| {
+        server_name::validate(s).map_err(|error| match error {
+            ruma_identifiers_validation::Error::InvalidServerName(error) => error.to_string(),
+            error => error.to_string(),
+        })
+    };
+    if let Err(e) = validate_id_literal(&id, "server name", validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <&#dollar_crate::ServerName as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
@@ -241,7 +262,9 @@ pub fn server_name(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn mxc_uri(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(mxc_uri::validate(&id.value()).is_ok(), "Invalid mxc://");
+    if let Err(e) = validate_id_literal(&id, "MXC URI", mxc_uri::validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <&#dollar_crate::MxcUri as ::std::convert::From<&str>>::from(#id)
@@ -254,7 +277,9 @@ pub fn mxc_uri(input: TokenStream) -> TokenStream {
 #[proc_macro]
 pub fn user_id(input: TokenStream) -> TokenStream {
     let IdentifierInput { dollar_crate, id } = parse_macro_input!(input as IdentifierInput);
-    assert!(user_id::validate(&id.value()).is_ok(), "Invalid user_id");
+    if let Err(e) = validate_id_literal(&id, "user ID", user_id::validate) {
+        return e.into_compile_error().into();
+    }
 
     let output = quote! {
         <&#dollar_crate::UserId as ::std::convert::TryFrom<&str>>::try_from(#id).unwrap()
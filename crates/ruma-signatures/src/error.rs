@@ -38,6 +38,10 @@ pub enum Error {
     #[error("signature uses an unsupported algorithm: {0}")]
     UnsupportedAlgorithm(String),
 
+    /// The room version is not supported.
+    #[error("room version `{0}` is not supported")]
+    UnsupportedRoomVersion(RoomVersionId),
+
     /// PDU was too large
     #[error("PDU is larger than maximum of 65535 bytes")]
     PduSize,
@@ -9,8 +9,10 @@ use std::{
 use base64::{alphabet, Engine};
 use ruma_common::{
     canonical_json::{redact, JsonType},
+    room_version_rules::EventFormatVersion,
     serde::{base64::Standard, Base64},
-    CanonicalJsonObject, CanonicalJsonValue, OwnedEventId, OwnedServerName, RoomVersionId, UserId,
+    CanonicalJsonObject, CanonicalJsonValue, OwnedEventId, OwnedServerName, RoomVersionId,
+    ServerSigningKeyId, UserId,
 };
 use serde_json::{from_str as from_json_str, to_string as to_json_string};
 use sha2::{digest::Digest, Sha256};
@@ -181,7 +183,7 @@ pub fn canonical_json(object: &CanonicalJsonObject) -> Result<String, Error> {
 /// ```rust
 /// use std::collections::BTreeMap;
 ///
-/// use ruma_common::serde::Base64;
+/// use ruma_common::{serde::Base64, server_signing_key_id};
 ///
 /// const PUBLIC_KEY: &[u8] = b"XGX0JRS2Af3be3knz2fBiRbApjm2Dh61gXDJA8kcJNI";
 ///
@@ -198,7 +200,10 @@ pub fn canonical_json(object: &CanonicalJsonObject) -> Result<String, Error> {
 ///
 /// // Create the `PublicKeyMap` that will inform `verify_json` which signatures to verify.
 /// let mut public_key_set = BTreeMap::new();
-/// public_key_set.insert("ed25519:1".into(), Base64::parse(PUBLIC_KEY.to_owned()).unwrap());
+/// public_key_set.insert(
+///     server_signing_key_id!("ed25519:1").to_owned(),
+///     Base64::parse(PUBLIC_KEY.to_owned()).unwrap(),
+/// );
 /// let mut public_key_map = BTreeMap::new();
 /// public_key_map.insert("domain".into(), public_key_set);
 ///
@@ -234,13 +239,16 @@ pub fn verify_json(
                 _ => return Err(JsonError::not_of_type("signature", JsonType::String)),
             };
 
-            let public_key = public_keys.get(key_id).ok_or_else(|| {
-                JsonError::key_missing(
-                    format!("public_keys of {}", &entity_id),
-                    "signature",
-                    key_id,
-                )
-            })?;
+            let public_key = <&ServerSigningKeyId>::try_from(key_id.as_str())
+                .ok()
+                .and_then(|key_id| public_keys.get(key_id))
+                .ok_or_else(|| {
+                    JsonError::key_missing(
+                        format!("public_keys of {}", &entity_id),
+                        "signature",
+                        key_id,
+                    )
+                })?;
 
             let signature = Base64::<Standard>::parse(signature)
                 .map_err(|e| ParseError::base64("signature", signature, e))?;
@@ -333,8 +341,8 @@ pub fn reference_hash(
 
     let hash = Sha256::digest(json.as_bytes());
 
-    let base64_alphabet = match version {
-        RoomVersionId::V1 | RoomVersionId::V2 | RoomVersionId::V3 => alphabet::STANDARD,
+    let base64_alphabet = match version.rules().map(|rules| rules.event_format) {
+        Some(EventFormatVersion::V1 | EventFormatVersion::V2) => alphabet::STANDARD,
         // Room versions higher than version 3 are url safe base64 encoded
         _ => alphabet::URL_SAFE,
     };
@@ -496,7 +504,7 @@ where
 ///
 /// ```rust
 /// # use std::collections::BTreeMap;
-/// # use ruma_common::RoomVersionId;
+/// # use ruma_common::{server_signing_key_id, RoomVersionId};
 /// # use ruma_common::serde::Base64;
 /// # use ruma_signatures::{verify_event, Verified};
 /// #
@@ -530,7 +538,10 @@ where
 ///
 /// // Create the `PublicKeyMap` that will inform `verify_json` which signatures to verify.
 /// let mut public_key_set = BTreeMap::new();
-/// public_key_set.insert("ed25519:1".into(), Base64::parse(PUBLIC_KEY.to_owned()).unwrap());
+/// public_key_set.insert(
+///     server_signing_key_id!("ed25519:1").to_owned(),
+///     Base64::parse(PUBLIC_KEY.to_owned()).unwrap(),
+/// );
 /// let mut public_key_map = BTreeMap::new();
 /// public_key_map.insert("domain".into(), public_key_set);
 ///
@@ -590,7 +601,10 @@ pub fn verify_event(
                 continue;
             }
 
-            let public_key = match public_keys.get(key_id) {
+            let public_key = match <&ServerSigningKeyId>::try_from(key_id.as_str())
+                .ok()
+                .and_then(|key_id| public_keys.get(key_id))
+            {
                 Some(public_key) => public_key,
                 None => return Err(VerificationError::UnknownPublicKeysForSignature.into()),
             };
@@ -669,8 +683,10 @@ fn servers_to_check_signatures(
         };
     }
 
-    match version {
-        RoomVersionId::V1 | RoomVersionId::V2 => match object.get("event_id") {
+    let rules = version.rules().ok_or_else(|| Error::UnsupportedRoomVersion(version.clone()))?;
+
+    if rules.event_format == EventFormatVersion::V1 {
+        match object.get("event_id") {
             Some(CanonicalJsonValue::String(raw_event_id)) => {
                 let event_id: OwnedEventId =
                     raw_event_id.parse().map_err(|e| Error::from(ParseError::EventId(e)))?;
@@ -685,29 +701,23 @@ fn servers_to_check_signatures(
             _ => {
                 return Err(JsonError::field_missing_from_object("event_id"));
             }
-        },
-        RoomVersionId::V3
-        | RoomVersionId::V4
-        | RoomVersionId::V5
-        | RoomVersionId::V6
-        | RoomVersionId::V7 => {}
-        // TODO: And for all future versions that have join_authorised_via_users_server
-        RoomVersionId::V8 | RoomVersionId::V9 | RoomVersionId::V10 | RoomVersionId::V11 => {
-            if let Some(authorized_user) = object
-                .get("content")
-                .and_then(|c| c.as_object())
-                .and_then(|c| c.get("join_authorised_via_users_server"))
-            {
-                let authorized_user = authorized_user.as_str().ok_or_else(|| {
-                    JsonError::not_of_type("join_authorised_via_users_server", JsonType::String)
-                })?;
-                let authorized_user = <&UserId>::try_from(authorized_user)
-                    .map_err(|e| Error::from(ParseError::UserId(e)))?;
+        }
+    }
 
-                servers_to_check.insert(authorized_user.server_name().to_owned());
-            }
+    if rules.restricted_join_rule {
+        if let Some(authorized_user) = object
+            .get("content")
+            .and_then(|c| c.as_object())
+            .and_then(|c| c.get("join_authorised_via_users_server"))
+        {
+            let authorized_user = authorized_user.as_str().ok_or_else(|| {
+                JsonError::not_of_type("join_authorised_via_users_server", JsonType::String)
+            })?;
+            let authorized_user = <&UserId>::try_from(authorized_user)
+                .map_err(|e| Error::from(ParseError::UserId(e)))?;
+
+            servers_to_check.insert(authorized_user.server_name().to_owned());
         }
-        _ => unimplemented!(),
     }
 
     Ok(servers_to_check)
@@ -988,7 +998,7 @@ mod tests {
             SigningKeyAlgorithm::Ed25519,
             key_pair_sender.version().into(),
         );
-        sender_key_map.insert(version.to_string(), encoded_public_key);
+        sender_key_map.insert(version, encoded_public_key);
         public_key_map.insert("domain-sender".to_owned(), sender_key_map);
 
         let verification_result = verify_event(&public_key_map, &signed_event, &RoomVersionId::V6);
@@ -1151,6 +1161,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn verify_event_with_custom_room_version_is_unsupported() {
+        let key_pair_sender = generate_key_pair("1");
+        let mut signed_event = serde_json::from_str(
+            r#"{
+                "auth_events": [],
+                "content": {},
+                "depth": 3,
+                "hashes": {
+                    "sha256": "5jM4wQpv6lnBo7CLIghJuHdW+s2CMBJPUOGOC89ncos"
+                },
+                "origin": "domain",
+                "origin_server_ts": 1000000,
+                "prev_events": [],
+                "room_id": "!x:domain",
+                "sender": "@name:domain-sender",
+                "type": "X",
+                "unsigned": {
+                    "age_ts": 1000000
+                }
+            }"#,
+        )
+        .unwrap();
+        sign_json("domain-sender", &key_pair_sender, &mut signed_event).unwrap();
+
+        let mut public_key_map = BTreeMap::new();
+        add_key_to_map(&mut public_key_map, "domain-sender", &key_pair_sender);
+
+        let room_version = RoomVersionId::try_from("io.ruma.1").unwrap();
+        let verification_result = verify_event(&public_key_map, &signed_event, &room_version);
+        assert_matches!(verification_result, Err(Error::UnsupportedRoomVersion(version)));
+        assert_eq!(version, room_version);
+    }
+
     fn generate_key_pair(name: &str) -> Ed25519KeyPair {
         let key_content = Ed25519KeyPair::generate().unwrap();
         Ed25519KeyPair::from_der(&key_content, name.to_owned())
@@ -1163,7 +1207,7 @@ mod tests {
         let version =
             ServerSigningKeyId::from_parts(SigningKeyAlgorithm::Ed25519, pair.version().into());
 
-        sender_key_map.insert(version.to_string(), encoded_public_key);
+        sender_key_map.insert(version, encoded_public_key);
     }
 
     fn add_invalid_key_to_map(
@@ -1178,6 +1222,6 @@ mod tests {
             pair.version().into(),
         );
 
-        sender_key_map.insert(version.to_string(), encoded_public_key);
+        sender_key_map.insert(version, encoded_public_key);
     }
 }
@@ -2,6 +2,7 @@ use std::{borrow::Borrow, collections::BTreeSet};
 
 use js_int::{int, Int};
 use ruma_common::{
+    room_version_rules::RoomVersionRules,
     serde::{Base64, Raw},
     OwnedUserId, RoomVersionId, UserId,
 };
@@ -9,7 +10,7 @@ use ruma_events::room::{
     create::RoomCreateEventContent,
     join_rules::{JoinRule, RoomJoinRulesEventContent},
     member::{MembershipState, ThirdPartyInvite},
-    power_levels::RoomPowerLevelsEventContent,
+    power_levels::{RoomPowerLevels, RoomPowerLevelsEventContent},
     third_party_invite::RoomThirdPartyInviteEventContent,
 };
 use serde::{
@@ -24,7 +25,6 @@ use crate::{
         deserialize_power_levels, deserialize_power_levels_content_fields,
         deserialize_power_levels_content_invite, deserialize_power_levels_content_redact,
     },
-    room_version::RoomVersion,
     Error, Event, Result, StateEventType, TimelineEventType,
 };
 
@@ -99,7 +99,8 @@ pub fn auth_types_for_event(
 
                 if membership == MembershipState::Invite {
                     if let Some(Ok(t_id)) = content.third_party_invite.map(|t| t.deserialize()) {
-                        let key = (StateEventType::RoomThirdPartyInvite, t_id.signed.token);
+                        let key =
+                            (StateEventType::RoomThirdPartyInvite, t_id.signed.token().to_owned());
                         if !auth_types.contains(&key) {
                             auth_types.push(key);
                         }
@@ -122,7 +123,7 @@ pub fn auth_types_for_event(
 /// The `fetch_state` closure should gather state from a state snapshot. We need to know if the
 /// event passes auth against some state not a recursive collection of auth_events fields.
 pub fn auth_check<E: Event>(
-    room_version: &RoomVersion,
+    rules: &RoomVersionRules,
     incoming_event: impl Event,
     current_third_party_invite: Option<impl Event>,
     fetch_state: impl Fn(&StateEventType, &str) -> Option<E>,
@@ -179,7 +180,7 @@ pub fn auth_check<E: Event>(
             return Ok(false);
         }
 
-        if !room_version.use_room_create_sender {
+        if !rules.authorization.use_room_create_sender {
             // If content has no creator field, reject
             if content.creator.is_none() {
                 warn!("no creator field found in m.room.create content");
@@ -248,7 +249,7 @@ pub fn auth_check<E: Event>(
     }
 
     // Only in some room versions 6 and below
-    if room_version.special_case_aliases_auth {
+    if rules.authorization.special_case_room_aliases {
         // 4. If type is m.room.aliases
         if *incoming_event.event_type() == TimelineEventType::RoomAliases {
             info!("starting m.room.aliases check");
@@ -298,7 +299,7 @@ pub fn auth_check<E: Event>(
             .unwrap_or(MembershipState::Leave);
 
         if !valid_membership_change(
-            room_version,
+            rules,
             target_user,
             fetch_state(&StateEventType::RoomMember, target_user.as_str()).as_ref(),
             sender,
@@ -341,7 +342,7 @@ pub fn auth_check<E: Event>(
 
     // If type is m.room.third_party_invite
     let sender_power_level = if let Some(pl) = &power_levels_event {
-        let content = deserialize_power_levels_content_fields(pl.content().get(), room_version)?;
+        let content = deserialize_power_levels_content_fields(pl.content().get(), rules)?;
         if let Some(level) = content.users.get(sender) {
             *level
         } else {
@@ -349,7 +350,7 @@ pub fn auth_check<E: Event>(
         }
     } else {
         // If no power level event found the creator gets 100 everyone else gets 0
-        let is_creator = if room_version.use_room_create_sender {
+        let is_creator = if rules.authorization.use_room_create_sender {
             room_create_event.sender() == sender
         } else {
             #[allow(deprecated)]
@@ -369,8 +370,7 @@ pub fn auth_check<E: Event>(
     if *incoming_event.event_type() == TimelineEventType::RoomThirdPartyInvite {
         let invite_level = match &power_levels_event {
             Some(power_levels) => {
-                deserialize_power_levels_content_invite(power_levels.content().get(), room_version)?
-                    .invite
+                deserialize_power_levels_content_invite(power_levels.content().get(), rules)?.invite
             }
             None => int!(0),
         };
@@ -396,7 +396,7 @@ pub fn auth_check<E: Event>(
         info!("starting m.room.power_levels check");
 
         if let Some(required_pwr_lvl) = check_power_levels(
-            room_version,
+            rules,
             &incoming_event,
             power_levels_event.as_ref(),
             sender_power_level,
@@ -419,17 +419,15 @@ pub fn auth_check<E: Event>(
     // domains match, or the sender of the redaction has the appropriate permissions per the
     // power levels.
 
-    if room_version.extra_redaction_checks
+    if rules.authorization.special_case_room_redaction
         && *incoming_event.event_type() == TimelineEventType::RoomRedaction
     {
         let redact_level = match power_levels_event {
-            Some(pl) => {
-                deserialize_power_levels_content_redact(pl.content().get(), room_version)?.redact
-            }
+            Some(pl) => deserialize_power_levels_content_redact(pl.content().get(), rules)?.redact,
             None => int!(50),
         };
 
-        if !check_redaction(room_version, incoming_event, sender_power_level, redact_level)? {
+        if !check_redaction(rules, incoming_event, sender_power_level, redact_level)? {
             return Ok(false);
         }
     }
@@ -449,7 +447,7 @@ pub fn auth_check<E: Event>(
 /// State.
 #[allow(clippy::too_many_arguments)]
 fn valid_membership_change(
-    room_version: &RoomVersion,
+    rules: &RoomVersionRules,
     target_user: &UserId,
     target_user_membership_event: Option<impl Event>,
     sender: &UserId,
@@ -511,11 +509,9 @@ fn valid_membership_change(
         // Is the authorised user allowed to invite users into this room
         let (auth_user_pl, invite_level) = if let Some(pl) = &power_levels_event {
             // TODO Refactor all powerlevel parsing
-            let invite =
-                deserialize_power_levels_content_invite(pl.content().get(), room_version)?.invite;
+            let invite = deserialize_power_levels_content_invite(pl.content().get(), rules)?.invite;
 
-            let content =
-                deserialize_power_levels_content_fields(pl.content().get(), room_version)?;
+            let content = deserialize_power_levels_content_fields(pl.content().get(), rules)?;
             let user_pl = if let Some(level) = content.users.get(user_for_join_auth) {
                 *level
             } else {
@@ -545,7 +541,7 @@ fn valid_membership_change(
             let no_more_prev_events = prev_events.next().is_none();
 
             if prev_event_is_create_event && no_more_prev_events {
-                let is_creator = if room_version.use_room_create_sender {
+                let is_creator = if rules.authorization.use_room_create_sender {
                     let creator = create_room.sender();
 
                     creator == sender && creator == target_user
@@ -573,15 +569,14 @@ fn valid_membership_change(
                 warn!(?target_user_membership_event_id, "Banned user can't join");
                 false
             } else if (join_rules == JoinRule::Invite
-                    || room_version.allow_knocking && join_rules == JoinRule::Knock)
+                    || rules.knocking && join_rules == JoinRule::Knock)
                 // If the join_rule is invite then allow if membership state is invite or join
                     && (target_user_current_membership == MembershipState::Join
                         || target_user_current_membership == MembershipState::Invite)
             {
                 true
-            } else if room_version.restricted_join_rules
-                && matches!(join_rules, JoinRule::Restricted(_))
-                || room_version.knock_restricted_join_rule
+            } else if rules.restricted_join_rule && matches!(join_rules, JoinRule::Restricted(_))
+                || rules.knock_restricted_join_rule
                     && matches!(join_rules, JoinRule::KnockRestricted(_))
             {
                 // If the join_rule is restricted or knock_restricted
@@ -692,10 +687,10 @@ fn valid_membership_change(
                 allow
             }
         }
-        MembershipState::Knock if room_version.allow_knocking => {
+        MembershipState::Knock if rules.knocking => {
             // 1. If the `join_rule` is anything other than `knock` or `knock_restricted`, reject.
             if join_rules != JoinRule::Knock
-                || room_version.knock_restricted_join_rule
+                || rules.knock_restricted_join_rule
                     && matches!(join_rules, JoinRule::KnockRestricted(_))
             {
                 warn!("Join rule is not set to knock or knock_restricted, knocking is not allowed");
@@ -753,7 +748,7 @@ fn can_send_event(event: impl Event, ple: Option<impl Event>, user_level: Int) -
 
 /// Confirm that the event sender has the required power levels.
 fn check_power_levels(
-    room_version: &RoomVersion,
+    rules: &RoomVersionRules,
     power_event: impl Event,
     previous_power_event: Option<impl Event>,
     user_level: Int,
@@ -777,7 +772,7 @@ fn check_power_levels(
     // - If users key in content is not a dictionary with keys that are valid user IDs with values
     //   that are integers, reject.
     let user_content: RoomPowerLevelsEventContent =
-        deserialize_power_levels(power_event.content().get(), room_version)?;
+        deserialize_power_levels(power_event.content().get(), rules)?;
 
     // Validation of users is done in Ruma, synapse for loops validating user_ids and integers here
     info!("validation of power event finished");
@@ -789,7 +784,7 @@ fn check_power_levels(
     };
 
     let current_content: RoomPowerLevelsEventContent =
-        deserialize_power_levels(current_state.content().get(), room_version)?;
+        deserialize_power_levels(current_state.content().get(), rules)?;
 
     let mut user_levels_to_check = BTreeSet::new();
     let old_list = &current_content.users;
@@ -859,7 +854,7 @@ fn check_power_levels(
     }
 
     // Notifications, currently there is only @room
-    if room_version.limit_notifications_power_levels {
+    if rules.authorization.limit_notifications_power_levels {
         let old_level = old_state.notifications.room;
         let new_level = new_state.notifications.room;
         if old_level != new_level {
@@ -906,7 +901,7 @@ fn get_deserialize_levels(
 
 /// Does the event redacting come from a user with enough power to redact the given event.
 fn check_redaction(
-    _room_version: &RoomVersion,
+    _rules: &RoomVersionRules,
     redaction_event: impl Event,
     user_level: Int,
     redact_level: Int,
@@ -939,13 +934,14 @@ fn get_send_level(
         .and_then(|ple| {
             from_json_str::<RoomPowerLevelsEventContent>(ple.content().get())
                 .map(|content| {
-                    content.events.get(e_type).copied().unwrap_or_else(|| {
-                        if state_key.is_some() {
-                            content.state_default
-                        } else {
-                            content.events_default
-                        }
-                    })
+                    let power_levels = RoomPowerLevels::from(content);
+                    let e_type = e_type.to_string();
+
+                    if state_key.is_some() {
+                        power_levels.for_state(e_type.into())
+                    } else {
+                        power_levels.for_message(e_type.into())
+                    }
                 })
                 .ok()
         })
@@ -962,7 +958,7 @@ fn verify_third_party_invite(
     // checking for mxid and token keys is done by ruma when deserializing
 
     // The state key must match the invitee
-    if target_user != Some(&tp_id.signed.mxid) {
+    if target_user != Some(tp_id.signed.mxid()) {
         return false;
     }
 
@@ -973,7 +969,7 @@ fn verify_third_party_invite(
         None => return false,
     };
 
-    if current_tpid.state_key() != Some(&tp_id.signed.token) {
+    if current_tpid.state_key() != Some(tp_id.signed.token()) {
         return false;
     }
 
@@ -989,7 +985,7 @@ fn verify_third_party_invite(
             Err(_) => return false,
         };
 
-    let decoded_invite_token = match Base64::parse(&tp_id.signed.token) {
+    let decoded_invite_token = match Base64::parse(tp_id.signed.token()) {
         Ok(tok) => tok,
         // FIXME: Log a warning?
         Err(_) => return false,
@@ -1010,6 +1006,8 @@ fn verify_third_party_invite(
 mod tests {
     use std::sync::Arc;
 
+    use js_int::int;
+    use ruma_common::room_version_rules::RoomVersionRules;
     use ruma_events::{
         room::{
             join_rules::{
@@ -1019,17 +1017,52 @@ mod tests {
         },
         StateEventType, TimelineEventType,
     };
-    use serde_json::value::to_raw_value as to_raw_json_value;
+    use serde_json::{json, value::to_raw_value as to_raw_json_value};
 
     use crate::{
-        event_auth::valid_membership_change,
+        event_auth::{get_send_level, valid_membership_change},
         test_utils::{
             alice, charlie, ella, event_id, member_content_ban, member_content_join, room_id,
             to_pdu_event, PduEvent, INITIAL_EVENTS, INITIAL_EVENTS_CREATE_ROOM,
         },
-        Event, EventTypeExt, RoomVersion, StateMap,
+        Event, EventTypeExt, StateMap,
     };
 
+    #[test]
+    fn send_level() {
+        let power_levels = to_pdu_event(
+            "PL",
+            alice(),
+            TimelineEventType::RoomPowerLevels,
+            Some(""),
+            to_raw_json_value(&json!({
+                "events": { "m.room.name": 20, "m.reaction": 10 },
+                "events_default": 5,
+                "state_default": 40,
+            }))
+            .unwrap(),
+            &["CREATE"],
+            &["CREATE"],
+        );
+
+        let send_level =
+            |e_type, state_key| get_send_level(&e_type, state_key, Some(&power_levels));
+        assert_eq!(send_level(TimelineEventType::RoomName, Some("")), int!(20));
+        assert_eq!(send_level(TimelineEventType::RoomTopic, Some("")), int!(40));
+        assert_eq!(send_level(TimelineEventType::Reaction, None), int!(10));
+        assert_eq!(send_level(TimelineEventType::RoomMessage, None), int!(5));
+
+        // Without power levels, the default values of the spec are used.
+        assert_eq!(
+            get_send_level(&TimelineEventType::RoomTopic, Some(""), None::<PduEvent>),
+            int!(50)
+        );
+        assert_eq!(
+            get_send_level(&TimelineEventType::RoomMessage, None, None::<PduEvent>),
+            int!(0)
+        );
+    }
+
     #[test]
     fn test_ban_pass() {
         let _ =
@@ -1056,7 +1089,7 @@ mod tests {
         let sender = alice();
 
         assert!(valid_membership_change(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1098,7 +1131,7 @@ mod tests {
         let sender = charlie();
 
         assert!(!valid_membership_change(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1140,7 +1173,7 @@ mod tests {
         let sender = alice();
 
         assert!(valid_membership_change(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1182,7 +1215,7 @@ mod tests {
         let sender = charlie();
 
         assert!(!valid_membership_change(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1241,7 +1274,7 @@ mod tests {
         let sender = ella();
 
         assert!(valid_membership_change(
-            &RoomVersion::V9,
+            &RoomVersionRules::V9,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1257,7 +1290,7 @@ mod tests {
         .unwrap());
 
         assert!(!valid_membership_change(
-            &RoomVersion::V9,
+            &RoomVersionRules::V9,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
@@ -1308,7 +1341,7 @@ mod tests {
         let sender = ella();
 
         assert!(valid_membership_change(
-            &RoomVersion::V7,
+            &RoomVersionRules::V7,
             target_user,
             fetch_state(StateEventType::RoomMember, target_user.to_string()),
             sender,
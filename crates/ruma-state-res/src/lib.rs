@@ -7,7 +7,9 @@ use std::{
 
 use itertools::Itertools;
 use js_int::{int, Int};
-use ruma_common::{EventId, MilliSecondsSinceUnixEpoch, RoomVersionId};
+use ruma_common::{
+    room_version_rules::RoomVersionRules, EventId, MilliSecondsSinceUnixEpoch, RoomVersionId,
+};
 use ruma_events::{
     room::member::{MembershipState, RoomMemberEventContent},
     StateEventType, TimelineEventType,
@@ -18,7 +20,6 @@ use tracing::{debug, info, trace, warn};
 mod error;
 pub mod event_auth;
 mod power_levels;
-pub mod room_version;
 mod state_event;
 #[cfg(test)]
 mod test_utils;
@@ -26,7 +27,6 @@ mod test_utils;
 pub use error::{Error, Result};
 pub use event_auth::{auth_check, auth_types_for_event};
 use power_levels::PowerLevelsContentFields;
-pub use room_version::RoomVersion;
 pub use state_event::Event;
 
 /// A mapping of event type and state_key to some value `T`, usually an `EventId`.
@@ -107,10 +107,12 @@ where
     debug!("sorted control events: {}", sorted_control_levels.len());
     trace!("{sorted_control_levels:?}");
 
-    let room_version = RoomVersion::new(room_version)?;
+    let rules = room_version
+        .rules()
+        .ok_or_else(|| Error::Unsupported(format!("found version `{room_version}`")))?;
     // Sequentially auth check each control event.
     let resolved_control =
-        iterative_auth_check(&room_version, &sorted_control_levels, clean.clone(), &fetch_event)?;
+        iterative_auth_check(&rules, &sorted_control_levels, clean.clone(), &fetch_event)?;
 
     debug!("resolved control events: {}", resolved_control.len());
     trace!("{resolved_control:?}");
@@ -140,7 +142,7 @@ where
     trace!("events left, sorted: {sorted_left_events:?}");
 
     let mut resolved_state = iterative_auth_check(
-        &room_version,
+        &rules,
         &sorted_left_events,
         resolved_control, // The control events are added to the final resolved state
         &fetch_event,
@@ -382,7 +384,7 @@ fn get_power_level_for_sender<E: Event>(
 /// For each `events_to_check` event we gather the events needed to auth it from the the
 /// `fetch_event` closure and verify each event using the `event_auth::auth_check` function.
 fn iterative_auth_check<E: Event + Clone>(
-    room_version: &RoomVersion,
+    rules: &RoomVersionRules,
     events_to_check: &[E::Id],
     unconflicted_state: StateMap<E::Id>,
     fetch_event: impl Fn(&EventId) -> Option<E>,
@@ -438,7 +440,7 @@ fn iterative_auth_check<E: Event + Clone>(
             (*pdu.event_type() == TimelineEventType::RoomThirdPartyInvite).then_some(pdu)
         });
 
-        if auth_check(room_version, &event, current_third_party, |ty, key| {
+        if auth_check(rules, &event, current_third_party, |ty, key| {
             auth_events.get(&ty.with_state_key(key))
         })? {
             // add event to resolved state map
@@ -644,7 +646,10 @@ mod tests {
     use js_int::{int, uint};
     use maplit::{hashmap, hashset};
     use rand::seq::SliceRandom;
-    use ruma_common::{MilliSecondsSinceUnixEpoch, OwnedEventId, RoomVersionId};
+    use ruma_common::{
+        room_version_rules::RoomVersionRules, MilliSecondsSinceUnixEpoch, OwnedEventId,
+        RoomVersionId,
+    };
     use ruma_events::{
         room::join_rules::{JoinRule, RoomJoinRulesEventContent},
         StateEventType, TimelineEventType,
@@ -654,7 +659,6 @@ mod tests {
 
     use crate::{
         is_power_event,
-        room_version::RoomVersion,
         test_utils::{
             alice, bob, charlie, do_check, ella, event_id, member_content_ban, member_content_join,
             room_id, to_init_pdu_event, to_pdu_event, zara, PduEvent, TestStore, INITIAL_EVENTS,
@@ -687,7 +691,7 @@ mod tests {
             .unwrap();
 
         let resolved_power = crate::iterative_auth_check(
-            &RoomVersion::V6,
+            &RoomVersionRules::V6,
             &sorted_power_events,
             HashMap::new(), // unconflicted events
             |id| events.get(id).cloned(),
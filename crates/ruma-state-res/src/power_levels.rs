@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use js_int::Int;
 use ruma_common::{
     power_levels::{default_power_level, NotificationPowerLevels},
+    room_version_rules::RoomVersionRules,
     serde::{btreemap_deserialize_v1_powerlevel_values, deserialize_v1_powerlevel},
     OwnedUserId,
 };
@@ -11,8 +12,6 @@ use serde::Deserialize;
 use serde_json::{from_str as from_json_str, Error};
 use tracing::error;
 
-use crate::RoomVersion;
-
 #[derive(Deserialize)]
 struct IntRoomPowerLevelsEventContent {
     #[serde(default = "default_power_level")]
@@ -100,9 +99,9 @@ impl From<IntNotificationPowerLevels> for NotificationPowerLevels {
 
 pub(crate) fn deserialize_power_levels(
     content: &str,
-    room_version: &RoomVersion,
+    rules: &RoomVersionRules,
 ) -> Option<RoomPowerLevelsEventContent> {
-    if room_version.integer_power_levels {
+    if rules.integer_power_levels {
         match from_json_str::<IntRoomPowerLevelsEventContent>(content) {
             Ok(content) => Some(content.into()),
             Err(_) => {
@@ -150,9 +149,9 @@ impl From<IntPowerLevelsContentFields> for PowerLevelsContentFields {
 
 pub(crate) fn deserialize_power_levels_content_fields(
     content: &str,
-    room_version: &RoomVersion,
+    rules: &RoomVersionRules,
 ) -> Result<PowerLevelsContentFields, Error> {
-    if room_version.integer_power_levels {
+    if rules.integer_power_levels {
         from_json_str::<IntPowerLevelsContentFields>(content).map(|r| r.into())
     } else {
         from_json_str(content)
@@ -180,9 +179,9 @@ impl From<IntPowerLevelsContentInvite> for PowerLevelsContentInvite {
 
 pub(crate) fn deserialize_power_levels_content_invite(
     content: &str,
-    room_version: &RoomVersion,
+    rules: &RoomVersionRules,
 ) -> Result<PowerLevelsContentInvite, Error> {
-    if room_version.integer_power_levels {
+    if rules.integer_power_levels {
         from_json_str::<IntPowerLevelsContentInvite>(content).map(|r| r.into())
     } else {
         from_json_str(content)
@@ -210,9 +209,9 @@ impl From<IntPowerLevelsContentRedact> for PowerLevelsContentRedact {
 
 pub(crate) fn deserialize_power_levels_content_redact(
     content: &str,
-    room_version: &RoomVersion,
+    rules: &RoomVersionRules,
 ) -> Result<PowerLevelsContentRedact, Error> {
-    if room_version.integer_power_levels {
+    if rules.integer_power_levels {
         from_json_str::<IntPowerLevelsContentRedact>(content).map(|r| r.into())
     } else {
         from_json_str(content)